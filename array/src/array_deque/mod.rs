@@ -0,0 +1,374 @@
+use crate::ring_buffer::RingBufferIterator as ArrayDequeIterator;
+use exceptions::Exceptions;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+
+#[derive(Clone)]
+pub struct ArrayDeque<T: Clone> {
+    buffer: Box<[Option<T>]>,
+    head: usize,
+    len: usize,
+}
+
+impl<T: Clone> ArrayDeque<T> {
+    /// Crea una nueva cola doble vacía con la capacidad inicial especificada.
+    ///
+    /// # Parámetros
+    /// - `capacity`: La capacidad inicial de la cola. Cuando se llena, la capacidad se duplica automáticamente.
+    ///
+    /// # Retornos
+    /// - Devuelve una nueva instancia de `ArrayDeque` sin elementos.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::ArrayDeque;
+    /// let deque: ArrayDeque<i32> = ArrayDeque::new(4);
+    ///
+    /// assert_eq!(deque.capacity(), 4);
+    /// assert!(deque.is_empty());
+    /// ```
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        let mut vec: Vec<Option<T>> = Vec::with_capacity(capacity);
+        vec.resize_with(capacity, || None);
+
+        Self {
+            buffer: vec.into_boxed_slice(),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Crea una nueva cola doble con la capacidad inicial especificada y elementos iniciales.
+    ///
+    /// # Parámetros
+    /// - `capacity`: La capacidad inicial de la cola. Si es menor que la cantidad de valores, se utiliza la cantidad de valores.
+    /// - `values`: Un slice de valores que se utilizarán para inicializar la cola, desde el frente hasta el final.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::ArrayDeque;
+    /// let deque = ArrayDeque::with_values(2, &[1, 2, 3]);
+    ///
+    /// assert_eq!(deque.len(), 3);
+    /// assert_eq!(deque.capacity(), 3);
+    /// assert_eq!(deque.front(), Ok(&1));
+    /// ```
+    #[must_use]
+    pub fn with_values(capacity: usize, values: &[T]) -> Self {
+        let mut deque = Self::new(capacity.max(values.len()));
+        for (slot, value) in deque.buffer.iter_mut().zip(values) {
+            *slot = Some(value.clone());
+        }
+        deque.len = values.len();
+        deque
+    }
+
+    /// Obtiene una referencia inmutable al elemento en la posición especificada, contando desde el frente.
+    ///
+    /// # Parámetros
+    /// - `index`: La posición lógica del elemento. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::ArrayDeque;
+    /// let mut deque = ArrayDeque::with_values(4, &[2, 3]);
+    /// deque.push_front(1);
+    ///
+    /// assert_eq!(deque.get(0), Ok(&1));
+    /// assert_eq!(deque.get(2), Ok(&3));
+    /// assert!(deque.get(3).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - `index` es mayor o igual a `self.len`.
+    pub fn get(&self, index: usize) -> Result<&T, Exceptions> {
+        if index >= self.len {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        self.buffer[self.wrap(self.head + index)]
+            .as_ref()
+            .ok_or(Exceptions::IndexOutOfBounds)
+    }
+
+    /// Obtiene una referencia mutable al elemento en la posición especificada, contando desde el frente.
+    ///
+    /// # Parámetros
+    /// - `index`: La posición lógica del elemento. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::ArrayDeque;
+    /// let mut deque = ArrayDeque::with_values(4, &[1, 2, 3]);
+    /// if let Ok(value) = deque.get_mut(1) {
+    ///     *value = 42;
+    /// }
+    /// assert_eq!(deque.get(1), Ok(&42));
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - `index` es mayor o igual a `self.len`.
+    pub fn get_mut(&mut self, index: usize) -> Result<&mut T, Exceptions> {
+        if index >= self.len {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        let slot = self.wrap(self.head + index);
+        self.buffer[slot]
+            .as_mut()
+            .ok_or(Exceptions::IndexOutOfBounds)
+    }
+
+    /// Obtiene una referencia al primer elemento de la cola.
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - La cola está vacía.
+    pub fn front(&self) -> Result<&T, Exceptions> {
+        self.get(0)
+    }
+
+    /// Obtiene una referencia al último elemento de la cola.
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - La cola está vacía.
+    pub fn back(&self) -> Result<&T, Exceptions> {
+        match self.len {
+            0 => Err(Exceptions::IndexOutOfBounds),
+            len => self.get(len - 1),
+        }
+    }
+
+    /// Agrega un elemento al frente de la cola.
+    ///
+    /// # Parámetros
+    /// - `value`: El valor que se desea agregar.
+    ///
+    /// # Comportamiento
+    /// - Si la cola está llena, su capacidad se duplica (o pasa a ser 4 si era 0) antes de insertar.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::ArrayDeque;
+    /// let mut deque = ArrayDeque::new(0);
+    /// deque.push_front(2);
+    /// deque.push_front(1);
+    ///
+    /// assert_eq!(deque.iter().collect::<Vec<_>>(), vec![&1, &2]);
+    /// ```
+    ///
+    /// # Notas
+    /// - La operación tiene un costo constante amortizado (`O(1)`): solo el índice del frente retrocede, sin desplazar elementos.
+    pub fn push_front(&mut self, value: T) {
+        self.reserve_one();
+        self.head = self.wrap(self.head + self.capacity() - 1);
+        self.buffer[self.head] = Some(value);
+        self.len += 1;
+    }
+
+    /// Agrega un elemento al final de la cola.
+    ///
+    /// # Parámetros
+    /// - `value`: El valor que se desea agregar.
+    ///
+    /// # Comportamiento
+    /// - Si la cola está llena, su capacidad se duplica (o pasa a ser 4 si era 0) antes de insertar.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::ArrayDeque;
+    /// let mut deque = ArrayDeque::new(1);
+    /// deque.push_back(1);
+    /// deque.push_back(2);
+    ///
+    /// assert_eq!(deque.capacity(), 2);
+    /// assert_eq!(deque.iter().collect::<Vec<_>>(), vec![&1, &2]);
+    /// ```
+    ///
+    /// # Notas
+    /// - La operación tiene un costo constante amortizado (`O(1)`).
+    pub fn push_back(&mut self, value: T) {
+        self.reserve_one();
+        let slot = self.wrap(self.head + self.len);
+        self.buffer[slot] = Some(value);
+        self.len += 1;
+    }
+
+    /// Elimina y devuelve el primer elemento de la cola.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::ArrayDeque;
+    /// let mut deque = ArrayDeque::with_values(2, &[1, 2]);
+    ///
+    /// assert_eq!(deque.pop_front(), Ok(1));
+    /// assert_eq!(deque.pop_front(), Ok(2));
+    /// assert!(deque.pop_front().is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - La cola está vacía.
+    ///
+    /// # Notas
+    /// - La operación tiene un costo constante (`O(1)`) y mueve el valor fuera de la cola sin clonarlo.
+    pub fn pop_front(&mut self) -> Result<T, Exceptions> {
+        if self.is_empty() {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+
+        let value = self.buffer[self.head]
+            .take()
+            .ok_or(Exceptions::IndexOutOfBounds)?;
+        self.head = self.wrap(self.head + 1);
+        self.len -= 1;
+        Ok(value)
+    }
+
+    /// Elimina y devuelve el último elemento de la cola.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::ArrayDeque;
+    /// let mut deque = ArrayDeque::with_values(2, &[1, 2]);
+    ///
+    /// assert_eq!(deque.pop_back(), Ok(2));
+    /// assert_eq!(deque.pop_back(), Ok(1));
+    /// assert!(deque.pop_back().is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - La cola está vacía.
+    ///
+    /// # Notas
+    /// - La operación tiene un costo constante (`O(1)`) y mueve el valor fuera de la cola sin clonarlo.
+    pub fn pop_back(&mut self) -> Result<T, Exceptions> {
+        if self.is_empty() {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+
+        let slot = self.wrap(self.head + self.len - 1);
+        let value = self.buffer[slot]
+            .take()
+            .ok_or(Exceptions::IndexOutOfBounds)?;
+        self.len -= 1;
+        Ok(value)
+    }
+
+    /// Devuelve la cantidad de elementos almacenados actualmente en la cola.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Devuelve la capacidad actual de la cola.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Verifica si la cola está vacía.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Devuelve un iterador que recorre por referencia los elementos de la cola, desde el frente hasta el final.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::ArrayDeque;
+    /// let mut deque = ArrayDeque::new(4);
+    /// deque.push_back(3);
+    /// deque.push_front(2);
+    /// deque.push_front(1);
+    ///
+    /// assert_eq!(deque.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// assert_eq!(deque.iter().rev().collect::<Vec<_>>(), vec![&3, &2, &1]);
+    /// ```
+    #[must_use]
+    pub fn iter(&self) -> ArrayDequeIterator<'_, T> {
+        if self.head + self.len <= self.capacity() {
+            ArrayDequeIterator::new(&self.buffer[self.head..self.head + self.len], &[])
+        } else {
+            let tail = self.wrap(self.head + self.len);
+            ArrayDequeIterator::new(&self.buffer[self.head..], &self.buffer[..tail])
+        }
+    }
+
+    fn reserve_one(&mut self) {
+        if self.len < self.capacity() {
+            return;
+        }
+
+        let new_capacity = if self.capacity() == 0 {
+            4
+        } else {
+            self.capacity() * 2
+        };
+        let mut vec: Vec<Option<T>> = Vec::with_capacity(new_capacity);
+        for index in 0..self.len {
+            let slot = self.wrap(self.head + index);
+            vec.push(self.buffer[slot].take());
+        }
+        vec.resize_with(new_capacity, || None);
+        self.buffer = vec.into_boxed_slice();
+        self.head = 0;
+    }
+
+    const fn wrap(&self, index: usize) -> usize {
+        if index >= self.buffer.len() {
+            index - self.buffer.len()
+        } else {
+            index
+        }
+    }
+}
+
+impl<T: Clone> Default for ArrayDeque<T> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<T: Clone, const N: usize> From<&[T; N]> for ArrayDeque<T> {
+    fn from(values: &[T; N]) -> Self {
+        Self::with_values(N, values)
+    }
+}
+
+impl<T: Clone> From<&[T]> for ArrayDeque<T> {
+    fn from(values: &[T]) -> Self {
+        Self::with_values(values.len(), values)
+    }
+}
+
+impl<T: Clone> From<Vec<T>> for ArrayDeque<T> {
+    fn from(values: Vec<T>) -> Self {
+        Self::with_values(values.len(), values.as_slice())
+    }
+}
+
+impl<'a, T: Clone> IntoIterator for &'a ArrayDeque<T> {
+    type Item = &'a T;
+    type IntoIter = ArrayDequeIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Clone + Debug> Debug for ArrayDeque<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "[")?;
+        for (index, value) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{value:?}")?;
+        }
+        write!(f, "]")
+    }
+}
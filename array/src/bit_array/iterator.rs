@@ -0,0 +1,80 @@
+pub struct BitArrayIterator<'a> {
+    words: &'a [u64],
+    front: usize,
+    back: usize,
+}
+
+impl<'a> BitArrayIterator<'a> {
+    pub const fn new(words: &'a [u64], len: usize) -> Self {
+        Self {
+            words,
+            front: 0,
+            back: len,
+        }
+    }
+
+    const fn bit(&self, index: usize) -> bool {
+        (self.words[index / u64::BITS as usize] >> (index % u64::BITS as usize)) & 1 == 1
+    }
+}
+
+impl Iterator for BitArrayIterator<'_> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let bit = self.bit(self.front);
+        self.front += 1;
+        Some(bit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for BitArrayIterator<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.bit(self.back))
+    }
+}
+
+impl ExactSizeIterator for BitArrayIterator<'_> {}
+
+pub struct BitArrayOnes<'a> {
+    words: &'a [u64],
+    word_index: usize,
+    current: u64,
+}
+
+impl<'a> BitArrayOnes<'a> {
+    pub fn new(words: &'a [u64]) -> Self {
+        Self {
+            words,
+            word_index: 0,
+            current: words.first().copied().unwrap_or(0),
+        }
+    }
+}
+
+impl Iterator for BitArrayOnes<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current == 0 {
+            self.word_index += 1;
+            self.current = *self.words.get(self.word_index)?;
+        }
+        let offset = self.current.trailing_zeros() as usize;
+        // Apaga el bit menos significativo encendido.
+        self.current &= self.current - 1;
+        Some(self.word_index * u64::BITS as usize + offset)
+    }
+}
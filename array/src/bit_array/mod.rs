@@ -0,0 +1,319 @@
+mod iterator;
+
+use exceptions::Exceptions;
+use iterator::{BitArrayIterator, BitArrayOnes};
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+use std::ops::{BitAnd, BitOr, BitXor};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+#[derive(Clone, PartialEq, Eq, Default)]
+pub struct BitArray {
+    words: Box<[u64]>,
+    len: usize,
+}
+
+impl BitArray {
+    /// Crea un nuevo arreglo de bits con la longitud especificada, con todos los bits apagados.
+    ///
+    /// # Parámetros
+    /// - `len`: La cantidad de bits del arreglo.
+    ///
+    /// # Retornos
+    /// - Devuelve una nueva instancia de `BitArray` que empaqueta los bits en palabras de 64 bits.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::BitArray;
+    /// let bits = BitArray::new(100);
+    ///
+    /// assert_eq!(bits.len(), 100);
+    /// assert_eq!(bits.count_ones(), 0);
+    /// ```
+    ///
+    /// # Notas
+    /// - Un arreglo de `n` bits ocupa `n / 64` palabras (redondeado hacia arriba), frente a los `n` bytes de un arreglo de `bool`.
+    #[must_use]
+    pub fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(WORD_BITS)].into_boxed_slice(),
+            len,
+        }
+    }
+
+    /// Obtiene el valor del bit en el índice especificado.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del bit. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::BitArray;
+    /// let mut bits = BitArray::new(70);
+    /// bits.set(65).unwrap();
+    ///
+    /// assert_eq!(bits.get(65), Ok(true));
+    /// assert_eq!(bits.get(64), Ok(false));
+    /// assert!(bits.get(70).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - `index` es mayor o igual a `self.len`.
+    pub fn get(&self, index: usize) -> Result<bool, Exceptions> {
+        let (word, mask) = self.locate(index)?;
+        Ok(self.words[word] & mask != 0)
+    }
+
+    /// Enciende el bit en el índice especificado.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del bit. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - `index` es mayor o igual a `self.len`.
+    pub fn set(&mut self, index: usize) -> Result<(), Exceptions> {
+        let (word, mask) = self.locate(index)?;
+        self.words[word] |= mask;
+        Ok(())
+    }
+
+    /// Apaga el bit en el índice especificado.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del bit. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::BitArray;
+    /// let mut bits = BitArray::from(&[true, true]);
+    /// bits.clear(0).unwrap();
+    ///
+    /// assert_eq!(bits.get(0), Ok(false));
+    /// assert_eq!(bits.get(1), Ok(true));
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - `index` es mayor o igual a `self.len`.
+    pub fn clear(&mut self, index: usize) -> Result<(), Exceptions> {
+        let (word, mask) = self.locate(index)?;
+        self.words[word] &= !mask;
+        Ok(())
+    }
+
+    /// Invierte el bit en el índice especificado y devuelve su nuevo valor.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del bit. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::BitArray;
+    /// let mut bits = BitArray::new(8);
+    ///
+    /// assert_eq!(bits.toggle(3), Ok(true));
+    /// assert_eq!(bits.toggle(3), Ok(false));
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - `index` es mayor o igual a `self.len`.
+    pub fn toggle(&mut self, index: usize) -> Result<bool, Exceptions> {
+        let (word, mask) = self.locate(index)?;
+        self.words[word] ^= mask;
+        Ok(self.words[word] & mask != 0)
+    }
+
+    /// Cuenta la cantidad de bits encendidos.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::BitArray;
+    /// let bits = BitArray::from(&[true, false, true, true]);
+    ///
+    /// assert_eq!(bits.count_ones(), 3);
+    /// ```
+    ///
+    /// # Notas
+    /// - Se cuenta una palabra completa por operación (`u64::count_ones`), por lo que el costo es `O(n / 64)`.
+    #[must_use]
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Devuelve la cantidad de bits del arreglo.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Verifica si el arreglo no tiene ningún bit.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Devuelve un iterador que recorre el valor de cada bit, desde el índice `0` hasta `len - 1`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::BitArray;
+    /// let bits = BitArray::from(&[true, false, true]);
+    ///
+    /// assert_eq!(bits.iter().collect::<Vec<_>>(), vec![true, false, true]);
+    /// ```
+    #[must_use]
+    pub fn iter(&self) -> BitArrayIterator<'_> {
+        BitArrayIterator::new(&self.words, self.len)
+    }
+
+    /// Devuelve un iterador sobre los índices de los bits encendidos, en orden creciente.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::BitArray;
+    /// let mut bits = BitArray::new(200);
+    /// bits.set(3).unwrap();
+    /// bits.set(130).unwrap();
+    ///
+    /// assert_eq!(bits.iter_ones().collect::<Vec<_>>(), vec![3, 130]);
+    /// ```
+    ///
+    /// # Notas
+    /// - Las palabras sin bits encendidos se saltan completas, por lo que recorrer un arreglo casi vacío es más rápido que filtrar `iter()`.
+    #[must_use]
+    pub fn iter_ones(&self) -> BitArrayOnes<'_> {
+        BitArrayOnes::new(&self.words)
+    }
+
+    fn locate(&self, index: usize) -> Result<(usize, u64), Exceptions> {
+        if index >= self.len {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        Ok((index / WORD_BITS, 1 << (index % WORD_BITS)))
+    }
+
+    fn combine<F: Fn(u64, u64) -> u64>(&self, other: &Self, operation: F) -> Self {
+        let mut result = Self::new(self.len.max(other.len));
+        for (index, word) in result.words.iter_mut().enumerate() {
+            let left = self.words.get(index).copied().unwrap_or(0);
+            let right = other.words.get(index).copied().unwrap_or(0);
+            *word = operation(left, right);
+        }
+        result
+    }
+}
+
+impl<const N: usize> From<&[bool; N]> for BitArray {
+    fn from(values: &[bool; N]) -> Self {
+        Self::from(values.as_slice())
+    }
+}
+
+impl From<&[bool]> for BitArray {
+    fn from(values: &[bool]) -> Self {
+        let mut bits = Self::new(values.len());
+        for (index, value) in values.iter().enumerate() {
+            if *value {
+                bits.words[index / WORD_BITS] |= 1 << (index % WORD_BITS);
+            }
+        }
+        bits
+    }
+}
+
+impl From<Vec<bool>> for BitArray {
+    fn from(values: Vec<bool>) -> Self {
+        Self::from(values.as_slice())
+    }
+}
+
+impl BitAnd for &BitArray {
+    type Output = BitArray;
+
+    /// Calcula la intersección de dos arreglos de bits.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::BitArray;
+    /// let left = BitArray::from(&[true, true, false]);
+    /// let right = BitArray::from(&[true, false, true]);
+    ///
+    /// assert_eq!(&left & &right, BitArray::from(&[true, false, false]));
+    /// ```
+    ///
+    /// # Notas
+    /// - Si las longitudes difieren, el resultado tiene la longitud mayor y los bits ausentes se consideran apagados.
+    fn bitand(self, other: &BitArray) -> Self::Output {
+        self.combine(other, |left, right| left & right)
+    }
+}
+
+impl BitOr for &BitArray {
+    type Output = BitArray;
+
+    /// Calcula la unión de dos arreglos de bits.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::BitArray;
+    /// let left = BitArray::from(&[true, false]);
+    /// let right = BitArray::from(&[false, false, true]);
+    ///
+    /// assert_eq!(&left | &right, BitArray::from(&[true, false, true]));
+    /// ```
+    ///
+    /// # Notas
+    /// - Si las longitudes difieren, el resultado tiene la longitud mayor y los bits ausentes se consideran apagados.
+    fn bitor(self, other: &BitArray) -> Self::Output {
+        self.combine(other, |left, right| left | right)
+    }
+}
+
+impl BitXor for &BitArray {
+    type Output = BitArray;
+
+    /// Calcula la diferencia simétrica de dos arreglos de bits.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::BitArray;
+    /// let left = BitArray::from(&[true, true, false]);
+    /// let right = BitArray::from(&[true, false, true]);
+    ///
+    /// assert_eq!(&left ^ &right, BitArray::from(&[false, true, true]));
+    /// ```
+    ///
+    /// # Notas
+    /// - Si las longitudes difieren, el resultado tiene la longitud mayor y los bits ausentes se consideran apagados.
+    fn bitxor(self, other: &BitArray) -> Self::Output {
+        self.combine(other, |left, right| left ^ right)
+    }
+}
+
+impl<'a> IntoIterator for &'a BitArray {
+    type Item = bool;
+    type IntoIter = BitArrayIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Debug for BitArray {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "[")?;
+        for (index, bit) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", u8::from(bit))?;
+        }
+        write!(f, "]")
+    }
+}
@@ -0,0 +1,162 @@
+use std::slice::{Chunks, Iter, IterMut, Windows};
+use std::vec::IntoIter;
+
+pub struct DynamicArrayIterator<'a, T: Clone> {
+    slots: Iter<'a, T>,
+}
+
+impl<'a, T: Clone> DynamicArrayIterator<'a, T> {
+    pub fn new(slots: &'a [T]) -> Self {
+        Self {
+            slots: slots.iter(),
+        }
+    }
+}
+
+impl<'a, T: Clone> Iterator for DynamicArrayIterator<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.slots.len(), Some(self.slots.len()))
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for DynamicArrayIterator<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.slots.next_back()
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for DynamicArrayIterator<'_, T> {}
+
+pub struct DynamicArrayIterMut<'a, T: Clone> {
+    slots: IterMut<'a, T>,
+}
+
+impl<'a, T: Clone> DynamicArrayIterMut<'a, T> {
+    pub fn new(slots: &'a mut [T]) -> Self {
+        Self {
+            slots: slots.iter_mut(),
+        }
+    }
+}
+
+impl<'a, T: Clone> Iterator for DynamicArrayIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.slots.len(), Some(self.slots.len()))
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for DynamicArrayIterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.slots.next_back()
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for DynamicArrayIterMut<'_, T> {}
+
+pub struct DynamicArrayIntoIter<T: Clone> {
+    slots: IntoIter<T>,
+}
+
+impl<T: Clone> DynamicArrayIntoIter<T> {
+    pub fn new(slots: Vec<T>) -> Self {
+        Self {
+            slots: slots.into_iter(),
+        }
+    }
+}
+
+impl<T: Clone> Iterator for DynamicArrayIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.slots.len(), Some(self.slots.len()))
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for DynamicArrayIntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.slots.next_back()
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for DynamicArrayIntoIter<T> {}
+
+pub struct DynamicArrayChunks<'a, T: Clone> {
+    chunks: Chunks<'a, T>,
+}
+
+impl<'a, T: Clone> DynamicArrayChunks<'a, T> {
+    pub fn new(slots: &'a [T], size: usize) -> Self {
+        Self {
+            chunks: slots.chunks(size),
+        }
+    }
+}
+
+impl<'a, T: Clone> Iterator for DynamicArrayChunks<'a, T> {
+    type Item = DynamicArrayIterator<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunks.next().map(DynamicArrayIterator::new)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for DynamicArrayChunks<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.chunks.next_back().map(DynamicArrayIterator::new)
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for DynamicArrayChunks<'_, T> {}
+
+pub struct DynamicArrayWindows<'a, T: Clone> {
+    windows: Windows<'a, T>,
+}
+
+impl<'a, T: Clone> DynamicArrayWindows<'a, T> {
+    pub fn new(slots: &'a [T], size: usize) -> Self {
+        Self {
+            windows: slots.windows(size),
+        }
+    }
+}
+
+impl<'a, T: Clone> Iterator for DynamicArrayWindows<'a, T> {
+    type Item = DynamicArrayIterator<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.windows.next().map(DynamicArrayIterator::new)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.windows.size_hint()
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for DynamicArrayWindows<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.windows.next_back().map(DynamicArrayIterator::new)
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for DynamicArrayWindows<'_, T> {}
@@ -1,12 +1,22 @@
+mod iterator;
+
 use exceptions::Exceptions;
+use iterator::{
+    DynamicArrayChunks, DynamicArrayIntoIter, DynamicArrayIterMut, DynamicArrayIterator,
+    DynamicArrayWindows,
+};
+use std::cmp::Ordering;
 use std::fmt::{Debug, Formatter, Result as fmtResult};
+use std::hash::{Hash, Hasher};
+use std::iter::Sum;
+use std::mem::{self, ManuallyDrop, MaybeUninit};
+use std::ops::{Index, IndexMut};
+use std::{ptr, slice};
 
-#[derive(Clone)]
 pub struct DynamicArray<T: Clone> {
-    array: Box<[Option<T>]>,
+    array: Box<[MaybeUninit<T>]>,
     len: usize,
     capacity: usize,
-    current: usize,
 }
 
 impl<T: Clone> DynamicArray<T> {
@@ -30,23 +40,37 @@ impl<T: Clone> DynamicArray<T> {
     /// ```
     ///
     /// # Notas
-    /// - El arreglo dinámico se inicializa con `None` en cada posición, lo que ocupa espacio en memoria según la capacidad especificada.
+    /// - Se reserva memoria para `capacity` elementos, pero ninguna posición se inicializa hasta que se le asigna un valor.
     /// - Para agregar elementos, utiliza métodos como `push` o `unshift`.
     pub fn new(capacity: usize) -> Self {
-        let mut vec: Vec<Option<T>> = Vec::with_capacity(capacity);
-        for _ in 0..capacity {
-            vec.push(None);
-        }
-        let array: Box<[Option<T>]> = vec.into_boxed_slice();
-
         Self {
-            array,
+            array: Box::new_uninit_slice(capacity),
             len: 0,
             capacity,
-            current: 0,
         }
     }
 
+    /// Crea un nuevo arreglo dinámico con todas sus posiciones ocupadas por copias del mismo valor.
+    ///
+    /// # Parámetros
+    /// - `capacity`: La capacidad inicial del arreglo dinámico, que también será su longitud.
+    /// - `value`: El valor con el que se inicializa cada posición.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let array = DynamicArray::filled(3, 0);
+    ///
+    /// assert_eq!(array.len(), 3);
+    /// assert_eq!(array.capacity(), 3);
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&0, &0, &0]);
+    /// ```
+    pub fn filled(capacity: usize, value: T) -> Self {
+        let mut array = Self::new(capacity);
+        array.fill(value);
+        array
+    }
+
     /// Crea un nuevo arreglo dinámico con una capacidad especificada y elementos iniciales.
     ///
     /// # Parámetros
@@ -57,7 +81,7 @@ impl<T: Clone> DynamicArray<T> {
     /// - Devuelve una nueva instancia de `DynamicArray` inicializada con los valores proporcionados.
     ///
     /// # Comportamiento
-    /// - Si la longitud de `values` es menor que `capacity`, los valores restantes del arreglo se inicializan como `None`.
+    /// - Si la longitud de `values` es menor que `capacity`, las posiciones restantes quedan libres.
     /// - Si la longitud de `values` es mayor o igual a `capacity`, solo se toman los primeros `capacity` elementos del slice.
     ///
     /// # Ejemplo
@@ -91,21 +115,9 @@ impl<T: Clone> DynamicArray<T> {
             capacity
         };
 
-        let mut vec: Vec<Option<T>> = Vec::with_capacity(capacity);
-        for index in 0..capacity {
-            match values.get(index) {
-                Some(value) => vec.push(Some(value.to_owned())),
-                None => vec.push(None),
-            }
-        }
-        let array: Box<[Option<T>]> = vec.into_boxed_slice();
-
-        Self {
-            array,
-            len: size,
-            capacity,
-            current: 0,
-        }
+        let mut array = Self::new(capacity);
+        array.extend_from_slice(&values[..size]);
+        array
     }
 
     /// Obtiene una referencia inmutable al elemento en el índice especificado.
@@ -129,13 +141,10 @@ impl<T: Clone> DynamicArray<T> {
     /// # Errors
     /// Este método retornará `Exceptions::IndexOutOfBounds` si:
     /// - `index` es mayor o igual a `self.len`.
-    /// - No hay un valor presente en el índice especificado.
     pub fn get(&self, index: usize) -> Result<&T, Exceptions> {
-        match (index, self.array.get(index)) {
-            (i, _) if i >= self.len => Err(Exceptions::IndexOutOfBounds),
-            (_, None) => Err(Exceptions::IndexOutOfBounds),
-            (_, Some(value)) => value.as_ref().ok_or(Exceptions::IndexOutOfBounds),
-        }
+        self.as_slice()
+            .get(index)
+            .ok_or(Exceptions::IndexOutOfBounds)
     }
 
     /// Obtiene una referencia mutable al elemento en el índice especificado.
@@ -161,13 +170,10 @@ impl<T: Clone> DynamicArray<T> {
     /// # Errors
     /// Este método retornará `Exceptions::IndexOutOfBounds` si:
     /// - `index` es mayor o igual a `self.len`.
-    /// - No hay un valor presente en el índice especificado.
     pub fn get_mut(&mut self, index: usize) -> Result<&mut T, Exceptions> {
-        match (index, self.array.get_mut(index)) {
-            (i, _) if i >= self.len => Err(Exceptions::IndexOutOfBounds),
-            (_, None) => Err(Exceptions::IndexOutOfBounds),
-            (_, Some(value)) => value.as_mut().ok_or(Exceptions::IndexOutOfBounds),
-        }
+        self.as_mut_slice()
+            .get_mut(index)
+            .ok_or(Exceptions::IndexOutOfBounds)
     }
 
     /// Establece un valor en el índice especificado del arreglo dinámico.
@@ -200,10 +206,7 @@ impl<T: Clone> DynamicArray<T> {
     /// Esta función no modifica la capacidad del arreglo. Para añadir nuevos valores
     /// fuera del rango actual, utiliza métodos como `unshift` o `push`.
     pub fn set(&mut self, index: usize, value: T) -> Result<(), Exceptions> {
-        if index >= self.len {
-            return Err(Exceptions::IndexOutOfBounds);
-        }
-        self.array[index] = Some(value);
+        *self.get_mut(index)? = value;
         Ok(())
     }
 
@@ -252,11 +255,10 @@ impl<T: Clone> DynamicArray<T> {
             self.resize(self.capacity * 2);
         }
 
-        let arr = &self.array.clone();
-        for i in (1..=self.len).rev() {
-            self.array[i].clone_from(&arr[i - 1]);
-        }
-        self.array[0] = Some(value);
+        let base = self.array.as_mut_ptr();
+        // SAFETY: hay al menos una posición libre después de `self.len`, por lo que `0..self.len` cabe desplazado una posición a la derecha.
+        unsafe { ptr::copy(base, base.add(1), self.len) };
+        self.array[0].write(value);
         self.len += 1;
     }
 
@@ -301,7 +303,7 @@ impl<T: Clone> DynamicArray<T> {
         } else if self.len == self.capacity {
             self.resize(self.capacity * 2);
         }
-        self.array[self.len] = Some(value);
+        self.array[self.len].write(value);
         self.len += 1;
     }
 
@@ -351,11 +353,10 @@ impl<T: Clone> DynamicArray<T> {
             self.resize(self.capacity * 2);
         }
 
-        let arr = &self.array.clone();
-        for i in ((index + 1)..=self.len).rev() {
-            self.array[i].clone_from(&arr[i - 1]);
-        }
-        self.array[index] = Some(value);
+        let base = self.array.as_mut_ptr();
+        // SAFETY: hay al menos una posición libre después de `self.len`, por lo que `index..self.len` cabe desplazado una posición a la derecha.
+        unsafe { ptr::copy(base.add(index), base.add(index + 1), self.len - index) };
+        self.array[index].write(value);
         self.len += 1;
         Ok(())
     }
@@ -396,23 +397,7 @@ impl<T: Clone> DynamicArray<T> {
     /// - Este método puede modificar la capacidad del arreglo dinámico si, después de la eliminación, su longitud es menor que la mitad de su capacidad.
     /// - El desplazamiento de elementos tiene un costo proporcional a la longitud actual del arreglo (`O(n)`), por lo que no es eficiente para usos repetidos en arreglos grandes.
     pub fn shift(&mut self) -> Result<T, Exceptions> {
-        if self.is_empty() {
-            return Err(Exceptions::IndexOutOfBounds);
-        }
-
-        let Some(value) = self.array[0].clone() else {
-            return Err(Exceptions::IndexOutOfBounds);
-        };
-        let slice = &self.array.clone()[1..self.len];
-        for (i, v) in slice.iter().enumerate() {
-            self.array[i].clone_from(v);
-        }
-        self.array[self.len - 1] = None;
-        self.len -= 1;
-        if self.len < self.capacity / 2 && self.capacity > 1 {
-            self.resize(self.capacity / 2);
-        }
-        Ok(value)
+        self.remove(0)
     }
 
     /// Elimina y devuelve el último elemento del arreglo dinámico.
@@ -423,7 +408,7 @@ impl<T: Clone> DynamicArray<T> {
     ///
     /// # Comportamiento
     /// - El último elemento del arreglo dinámico se elimina y se devuelve.
-    /// - La posición donde estaba el elemento eliminado se establece como `None`.
+    /// - La posición donde estaba el elemento eliminado queda libre.
     /// - Si, después de la eliminación, la longitud del arreglo es menor que la mitad de su capacidad y la capacidad es mayor que `1`, el arreglo se redimensiona automáticamente para reducir su capacidad a la mitad.
     ///
     /// # Ejemplo
@@ -455,11 +440,9 @@ impl<T: Clone> DynamicArray<T> {
             return Err(Exceptions::IndexOutOfBounds);
         }
 
-        let Some(value) = self.array[self.len - 1].clone() else {
-            return Err(Exceptions::IndexOutOfBounds);
-        };
-        self.array[self.len - 1] = None;
         self.len -= 1;
+        // SAFETY: la posición `self.len` estaba inicializada y, al reducir la longitud, deja de considerarse ocupada.
+        let value = unsafe { self.array[self.len].assume_init_read() };
         if self.len < self.capacity / 2 && self.capacity > 1 {
             self.resize(self.capacity / 2);
         }
@@ -504,20 +487,17 @@ impl<T: Clone> DynamicArray<T> {
     /// - Este método puede modificar la capacidad del arreglo dinámico si, después de la eliminación, su longitud es menor que la mitad de su capacidad.
     /// - Si necesitas eliminar elementos sin redimensionar automáticamente, deberías implementar un método alternativo.
     pub fn remove(&mut self, index: usize) -> Result<T, Exceptions> {
-        let value = match (index, self.array.get(index)) {
-            (i, _) if i >= self.len => return Err(Exceptions::IndexOutOfBounds),
-            (_, None) => return Err(Exceptions::IndexOutOfBounds),
-            (_, Some(value)) => match value {
-                Some(v) => v.clone(),
-                None => return Err(Exceptions::IndexOutOfBounds),
-            },
-        };
-
-        let slice = &self.array.clone()[(index + 1)..self.len];
-        for (i, v) in slice.iter().enumerate() {
-            self.array[index + i].clone_from(v);
+        if index >= self.len {
+            return Err(Exceptions::IndexOutOfBounds);
         }
-        self.array[self.len - 1] = None;
+
+        let base = self.array.as_mut_ptr();
+        // SAFETY: `index < self.len`, así que la posición está inicializada; tras leerla, el desplazamiento de `index + 1..self.len` una posición a la izquierda la cubre.
+        let value = unsafe {
+            let value = (*base.add(index)).assume_init_read();
+            ptr::copy(base.add(index + 1), base.add(index), self.len - index - 1);
+            value
+        };
         self.len -= 1;
         if self.len < self.capacity / 2 && self.capacity > 1 {
             self.resize(self.capacity / 2);
@@ -525,6 +505,67 @@ impl<T: Clone> DynamicArray<T> {
         Ok(value)
     }
 
+    /// Elimina el elemento en el índice especificado y devuelve su valor, ocupando su posición con el último elemento.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del elemento que se desea eliminar. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Retornos
+    /// - `Ok(T)`: Si el índice es válido, devuelve el valor del elemento eliminado.
+    /// - `Err(Exceptions::IndexOutOfBounds)`: Si el índice está fuera de los límites del arreglo.
+    ///
+    /// # Comportamiento
+    /// - El último elemento se mueve a la posición eliminada, por lo que el orden de los elementos no se conserva.
+    /// - Si, después de la eliminación, la longitud del arreglo es menor que la mitad de su capacidad y la capacidad es mayor que `1`, el arreglo se redimensiona automáticamente para reducir su capacidad a la mitad, igual que en `pop`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let mut array = DynamicArray::from(&[1, 2, 3, 4]);
+    ///
+    /// assert_eq!(array.swap_remove(0), Ok(1));
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&4, &2, &3]);
+    ///
+    /// assert!(array.swap_remove(3).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - `index` es mayor o igual a `self.len`.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante (`O(1)`), salvo cuando se reduce la capacidad. Si necesitas conservar el orden, utiliza `remove`.
+    pub fn swap_remove(&mut self, index: usize) -> Result<T, Exceptions> {
+        if index >= self.len {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        let last = self.len - 1;
+        self.as_mut_slice().swap(index, last);
+        self.pop()
+    }
+
+    /// Invierte el orden de los elementos del arreglo dinámico en el mismo lugar.
+    ///
+    /// # Comportamiento
+    /// - Intercambia las posiciones de ambos extremos, avanzando hacia el centro, hasta recorrer la mitad de los elementos.
+    /// - La capacidad libre no se modifica.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let mut array = DynamicArray::with_values(5, &[1, 2, 3, 4]);
+    ///
+    /// array.reverse();
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&4, &3, &2, &1]);
+    /// assert_eq!(array.capacity(), 5);
+    /// ```
+    ///
+    /// # Notas
+    /// - La operación tiene un costo lineal (`O(n)`) y no clona ni reserva memoria: los valores solo se mueven de posición.
+    pub fn reverse(&mut self) {
+        self.as_mut_slice().reverse();
+    }
+
     /// Devuelve la cantidad de elementos almacenados actualmente en el arreglo dinámico.
     ///
     /// # Retornos
@@ -532,7 +573,7 @@ impl<T: Clone> DynamicArray<T> {
     ///
     /// # Comportamiento
     /// - La longitud (`len`) representa el número de elementos válidos almacenados en el arreglo, no la capacidad total.
-    /// - Los espacios vacíos (inicializados como `None`) no se cuentan como parte de la longitud.
+    /// - Las posiciones libres de la capacidad, que no están inicializadas, no se cuentan como parte de la longitud.
     ///
     /// # Ejemplo
     /// ```
@@ -625,91 +666,989 @@ impl<T: Clone> DynamicArray<T> {
         self.len == 0
     }
 
-    /// Redimensiona la capacidad del arreglo dinámico.
+    /// Devuelve un iterador que recorre por referencia los elementos del arreglo dinámico, desde el índice `0` hasta `len - 1`.
     ///
-    /// # Parámetros
-    /// - `new_capacity`: La nueva capacidad del arreglo dinámico. Define el número máximo de elementos que puede contener después de redimensionarse.
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let array = DynamicArray::with_values(5, &[1, 2, 3]);
     ///
-    /// # Comportamiento
-    /// - Si `new_capacity` es mayor que la capacidad actual, se crea un nuevo arreglo con la capacidad especificada y los elementos existentes se copian en él.
-    /// - Si `new_capacity` es menor que la capacidad actual, se truncan los elementos que excedan la nueva capacidad.
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// assert_eq!(array.iter().rev().next(), Some(&3));
+    ///
+    /// // Recorrer el arreglo no lo modifica ni lo consume.
+    /// for value in &array {
+    ///     assert!(*value > 0);
+    /// }
+    /// assert_eq!(array.len(), 3);
+    /// ```
+    ///
+    /// # Notas
+    /// - Solo se recorren las posiciones inicializadas; la capacidad libre no se visita.
+    #[must_use]
+    pub fn iter(&self) -> DynamicArrayIterator<'_, T> {
+        DynamicArrayIterator::new(self.as_slice())
+    }
+
+    /// Devuelve un iterador que recorre los elementos del arreglo dinámico por referencia mutable, desde el índice `0` hasta `len - 1`.
     ///
     /// # Ejemplo
     /// ```
     /// # use array::DynamicArray;
-    /// let mut array = DynamicArray::with_values(3, &[1, 2, 3]);
+    /// let mut array = DynamicArray::with_values(5, &[1, 2, 3]);
     ///
-    /// // Redimensiona para aumentar la capacidad.
-    /// array.resize(5);
-    /// assert_eq!(array.len(), 3);
-    /// assert_eq!(array.capacity(), 5);
-    /// assert!(array.get(4).is_err()); // Las nuevas posiciones están vacías.
+    /// // Duplicar cada valor sin llamar a `get_mut` por índice.
+    /// for value in array.iter_mut() {
+    ///     *value *= 2;
+    /// }
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&2, &4, &6]);
     ///
-    /// // Redimensiona para reducir la capacidad.
-    /// array.resize(2);
-    /// assert_eq!(array.len(), array.capacity());
-    /// assert_eq!(array.capacity(), 2);
-    /// assert!(array.get(2).is_err());
+    /// // `&mut array` también puede recorrerse directamente.
+    /// for value in &mut array {
+    ///     *value += 1;
+    /// }
+    /// assert_eq!(array.get(0), Ok(&3));
+    /// ```
     ///
-    /// // Redimensionar a capacidad 0.
-    /// array.resize(0);
-    /// assert_eq!(array.len(), 0);
+    /// # Notas
+    /// - Solo se recorren las posiciones inicializadas; la longitud y la capacidad no cambian.
+    pub fn iter_mut(&mut self) -> DynamicArrayIterMut<'_, T> {
+        DynamicArrayIterMut::new(self.as_mut_slice())
+    }
+
+    /// Devuelve un iterador que recorre los elementos del arreglo dinámico en grupos consecutivos de `size` elementos.
+    ///
+    /// # Parámetros
+    /// - `size`: La cantidad de elementos de cada grupo. Debe ser mayor que `0`.
+    ///
+    /// # Retornos
+    /// - Un iterador cuyos elementos son, a su vez, iteradores por referencia sobre cada grupo. El último grupo puede tener menos de `size` elementos.
+    ///
+    /// # Ejemplo
     /// ```
+    /// # use array::DynamicArray;
+    /// let array = DynamicArray::from(&[1, 2, 3, 4, 5]);
+    ///
+    /// let sums: Vec<i32> = array.chunks(2).map(|chunk| chunk.sum()).collect();
+    /// assert_eq!(sums, vec![3, 7, 5]);
+    /// assert_eq!(array.chunks(2).len(), 3);
+    /// ```
+    ///
+    /// # Panics
+    /// Entra en pánico si `size` es `0`.
     ///
     /// # Notas
-    /// - Redimensionar a una capacidad menor que la longitud actual (`len`) eliminará elementos desde el final hasta ajustarse a la nueva capacidad.
-    pub fn resize(&mut self, new_capacity: usize) {
-        let mut new_array: Box<[Option<T>]> = vec![None; new_capacity].into_boxed_slice();
+    /// - Los grupos no se solapan. Solo se recorren las posiciones inicializadas; la capacidad libre no forma parte de ningún grupo.
+    #[must_use]
+    pub fn chunks(&self, size: usize) -> DynamicArrayChunks<'_, T> {
+        assert!(size != 0, "chunk size must be non-zero");
+        DynamicArrayChunks::new(self.as_slice(), size)
+    }
 
-        for (index, value) in self.array.iter().enumerate() {
-            if let Some(v) = new_array.get_mut(index) {
-                v.clone_from(value);
-            }
-        }
+    /// Devuelve un iterador que recorre todas las ventanas deslizantes de `size` elementos consecutivos del arreglo dinámico.
+    ///
+    /// # Parámetros
+    /// - `size`: La cantidad de elementos de cada ventana. Debe ser mayor que `0`.
+    ///
+    /// # Retornos
+    /// - Un iterador cuyos elementos son iteradores por referencia sobre cada ventana. Si el arreglo tiene menos de `size` elementos, no se produce ninguna ventana.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let temperatures = DynamicArray::from(&[20, 22, 25, 21, 19]);
+    ///
+    /// // Suma de cada ventana de tres días consecutivos.
+    /// let sums: Vec<i32> = temperatures.windows(3).map(|window| window.sum()).collect();
+    /// assert_eq!(sums, vec![67, 68, 65]);
+    /// assert_eq!(temperatures.windows(6).count(), 0);
+    /// ```
+    ///
+    /// # Panics
+    /// Entra en pánico si `size` es `0`.
+    ///
+    /// # Notas
+    /// - A diferencia de `chunks`, las ventanas se solapan: cada una avanza un solo elemento respecto a la anterior.
+    #[must_use]
+    pub fn windows(&self, size: usize) -> DynamicArrayWindows<'_, T> {
+        assert!(size != 0, "window size must be non-zero");
+        DynamicArrayWindows::new(self.as_slice(), size)
+    }
 
-        self.array = new_array;
-        self.capacity = new_capacity;
-        if self.len > self.capacity {
-            self.len = self.capacity;
-        }
+    /// Devuelve una referencia al primer elemento que cumple el predicado.
+    ///
+    /// # Parámetros
+    /// - `predicate`: La condición que debe cumplir el elemento buscado.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let array = DynamicArray::from(&[3, 8, 5, 10]);
+    ///
+    /// assert_eq!(array.find(|value| value % 2 == 0), Some(&8));
+    /// assert_eq!(array.find(|value| *value > 10), None);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`) y se detiene en la primera coincidencia.
+    pub fn find<F: FnMut(&T) -> bool>(&self, mut predicate: F) -> Option<&T> {
+        self.iter().find(|value| predicate(value))
     }
-}
 
-impl<T: Clone, const N: usize> From<&[T; N]> for DynamicArray<T> {
-    fn from(values: &[T; N]) -> Self {
-        Self::with_values(N, values)
+    /// Devuelve el índice del primer elemento que cumple el predicado.
+    ///
+    /// # Parámetros
+    /// - `predicate`: La condición que debe cumplir el elemento buscado.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let array = DynamicArray::from(&["Venus", "Tierra", "Marte"]);
+    ///
+    /// assert_eq!(array.position(|planet| planet.starts_with('T')), Some(1));
+    /// assert_eq!(array.position(|planet| planet.is_empty()), None);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`) y se detiene en la primera coincidencia.
+    pub fn position<F: FnMut(&T) -> bool>(&self, predicate: F) -> Option<usize> {
+        self.iter().position(predicate)
     }
-}
 
-impl<T: Clone> From<&[T]> for DynamicArray<T> {
-    fn from(values: &[T]) -> Self {
-        Self::with_values(values.len(), values)
+    /// Combina todos los elementos del arreglo dinámico en un único valor, aplicando una función acumuladora de izquierda a derecha.
+    ///
+    /// # Parámetros
+    /// - `init`: El valor inicial del acumulador.
+    /// - `f`: Una función que recibe el acumulador y una referencia a cada elemento, y devuelve el nuevo acumulador.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let array = DynamicArray::with_values(5, &[3, 1, 4]);
+    ///
+    /// let digits = array.fold(String::new(), |text, value| text + &value.to_string());
+    /// assert_eq!(digits, "314");
+    /// ```
+    ///
+    /// # Notas
+    /// - Solo se recorren las posiciones inicializadas. Un arreglo vacío devuelve `init`.
+    pub fn fold<B, F: FnMut(B, &T) -> B>(&self, init: B, f: F) -> B {
+        self.iter().fold(init, f)
     }
-}
 
-impl<T: Clone> From<Vec<T>> for DynamicArray<T> {
-    fn from(values: Vec<T>) -> Self {
-        Self::with_values(values.len(), values.as_slice())
+    /// Conserva únicamente los elementos que cumplen el predicado, en su orden original.
+    ///
+    /// # Parámetros
+    /// - `keep`: La condición que deben cumplir los elementos para permanecer en el arreglo.
+    ///
+    /// # Comportamiento
+    /// - Recorre el arreglo una sola vez, moviendo cada elemento conservado a la siguiente posición libre del inicio, sin clonar valores.
+    /// - Actualiza la longitud (`len`) con la cantidad de elementos conservados.
+    /// - Si, después de filtrar, la longitud es menor que la mitad de la capacidad, la capacidad se reduce a la mitad tantas veces como sea necesario, con un único redimensionamiento.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let mut array = DynamicArray::from(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    ///
+    /// array.retain(|value| value % 4 == 0);
+    ///
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&4, &8]);
+    /// assert_eq!(array.capacity(), 4);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`), frente al `O(n²)` de llamar a `remove` por cada elemento descartado.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut keep: F) {
+        let len = self.len;
+        // Mientras se compacta, los elementos no cuentan como inicializados: si `keep` entra en pánico se pierden, pero nunca se liberan dos veces.
+        self.len = 0;
+        let mut kept: usize = 0;
+        for index in 0..len {
+            // SAFETY: la posición `index` sigue inicializada, porque `kept <= index` y solo se escribe en posiciones ya leídas.
+            let value = unsafe { self.array[index].assume_init_read() };
+            if keep(&value) {
+                self.array[kept].write(value);
+                kept += 1;
+            }
+        }
+        self.len = kept;
+        let mut capacity = self.capacity;
+        while self.len < capacity / 2 && capacity > 1 {
+            capacity /= 2;
+        }
+        if capacity != self.capacity {
+            self.resize(capacity);
+        }
     }
-}
 
-impl<T: Clone> Iterator for DynamicArray<T> {
-    type Item = T;
+    /// Agrega al final del arreglo dinámico una copia de todos los valores del slice, en el mismo orden.
+    ///
+    /// # Parámetros
+    /// - `values`: Los valores que se desean agregar.
+    ///
+    /// # Comportamiento
+    /// - Si la capacidad libre no alcanza, el arreglo se redimensiona una sola vez a la capacidad exacta necesaria antes de copiar los valores.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let mut array = DynamicArray::with_values(3, &[1, 2]);
+    ///
+    /// array.extend_from_slice(&[3, 4, 5]);
+    ///
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4, &5]);
+    /// assert_eq!(array.capacity(), 5);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal en la cantidad de valores agregados (más la copia de los existentes si hay que redimensionar), en lugar de los redimensionamientos sucesivos de llamar a `push` en un ciclo.
+    pub fn extend_from_slice(&mut self, values: &[T]) {
+        let required = self.len + values.len();
+        if required > self.capacity {
+            self.resize(required);
+        }
+        for value in values {
+            self.array[self.len].write(value.clone());
+            self.len += 1;
+        }
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.current < self.len {
-            self.current += 1;
-            self.array[self.current - 1].clone()
-        } else {
-            None
+    /// Mueve al final del arreglo dinámico todos los elementos de `other`, en el mismo orden, dejando `other` vacío.
+    ///
+    /// # Parámetros
+    /// - `other`: El arreglo cuyos elementos se desean mover.
+    ///
+    /// # Comportamiento
+    /// - Si la capacidad libre no alcanza, el arreglo se redimensiona una sola vez a la capacidad exacta necesaria antes de mover los elementos.
+    /// - `other` conserva su capacidad, pero su longitud pasa a ser `0`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let mut array = DynamicArray::from(&[1, 2]);
+    /// let mut other = DynamicArray::from(&[3, 4, 5]);
+    ///
+    /// array.append(&mut other);
+    ///
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4, &5]);
+    /// assert_eq!(array.capacity(), 5);
+    /// assert!(other.is_empty());
+    /// assert_eq!(other.capacity(), 3);
+    /// ```
+    ///
+    /// # Notas
+    /// - A diferencia de `extend_from_slice`, los valores se mueven sin clonarse.
+    pub fn append(&mut self, other: &mut Self) {
+        let required = self.len + other.len;
+        if required > self.capacity {
+            self.resize(required);
+        }
+        // SAFETY: los elementos de `other` se mueven a las posiciones libres `self.len..required`, y al dejar `other.len` en `0` se liberan una sola vez.
+        unsafe {
+            ptr::copy_nonoverlapping(
+                other.array.as_ptr(),
+                self.array.as_mut_ptr().add(self.len),
+                other.len,
+            );
         }
+        self.len = required;
+        other.len = 0;
     }
-}
 
-impl<T: Clone + Debug> Debug for DynamicArray<T> {
+    /// Divide el arreglo dinámico en dos en el índice especificado, devolviendo los elementos desde `index` hasta el final en un nuevo arreglo.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice donde comienza la parte que se separa. Debe estar en el rango `0..=self.len`.
+    ///
+    /// # Retornos
+    /// - `Ok(DynamicArray<T>)`: Un nuevo arreglo con los elementos `index..len`, cuya capacidad es exactamente su longitud.
+    /// - `Err(Exceptions::IndexOutOfBounds)`: Si `index` es mayor que la longitud del arreglo.
+    ///
+    /// # Comportamiento
+    /// - El arreglo original queda con los elementos `0..index` y conserva su capacidad.
+    /// - Los elementos separados se mueven sin clonarse.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let mut array = DynamicArray::from(&[1, 2, 3, 4, 5]);
+    ///
+    /// let tail = array.split_off(3).unwrap();
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// assert_eq!(tail.iter().collect::<Vec<_>>(), vec![&4, &5]);
+    ///
+    /// assert!(array.split_off(4).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - `index` es mayor que `self.len`.
+    pub fn split_off(&mut self, index: usize) -> Result<Self, Exceptions> {
+        if index > self.len {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        let mut tail = Self::new(self.len - index);
+        // SAFETY: los elementos `index..self.len` se mueven a la memoria libre de `tail` y dejan de contarse en `self`, por lo que cada uno se libera una sola vez.
+        unsafe {
+            ptr::copy_nonoverlapping(
+                self.array.as_ptr().add(index),
+                tail.array.as_mut_ptr(),
+                self.len - index,
+            );
+        }
+        tail.len = self.len - index;
+        self.len = index;
+        Ok(tail)
+    }
+
+    /// Divide el arreglo dinámico en dos vistas prestadas en el índice especificado, sin modificarlo.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice donde comienza la segunda vista. Debe estar en el rango `0..=self.len`.
+    ///
+    /// # Retornos
+    /// - `Ok((izquierda, derecha))`: Dos rebanadas prestadas; la primera contiene los elementos `0..index` y la segunda los elementos `index..len`.
+    /// - `Err(Exceptions::IndexOutOfBounds)`: Si `index` es mayor que la longitud del arreglo.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let array = DynamicArray::from(&[1, 2, 3, 4, 5]);
+    ///
+    /// let (left, right) = array.split_at(2).unwrap();
+    /// assert_eq!(left, &[1, 2]);
+    /// assert_eq!(right, &[3, 4, 5]);
+    ///
+    /// assert!(array.split_at(6).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - `index` es mayor que `self.len`.
+    ///
+    /// # Notas
+    /// - Ambas vistas apuntan directamente al almacenamiento del arreglo, sin copiar elementos.
+    pub fn split_at(&self, index: usize) -> Result<(&[T], &[T]), Exceptions> {
+        if index > self.len {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        Ok(self.as_slice().split_at(index))
+    }
+
+    /// Sobrescribe todas las posiciones del arreglo dinámico con copias del valor especificado.
+    ///
+    /// # Parámetros
+    /// - `value`: El valor que se asigna a cada posición.
+    ///
+    /// # Comportamiento
+    /// - Se ocupan todas las posiciones de la capacidad, no solo las que ya tenían elementos, por lo que la longitud pasa a ser igual a la capacidad.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let mut array = DynamicArray::with_values(4, &[1, 2]);
+    ///
+    /// array.fill(7);
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&7, &7, &7, &7]);
+    /// ```
+    pub fn fill(&mut self, value: T) {
+        self.fill_with(|| value.clone());
+    }
+
+    /// Sobrescribe todas las posiciones del arreglo dinámico con los valores que devuelve un generador.
+    ///
+    /// # Parámetros
+    /// - `generator`: Una función que se invoca una vez por posición, en orden, y devuelve el valor para esa posición.
+    ///
+    /// # Comportamiento
+    /// - Se ocupan todas las posiciones de la capacidad, por lo que la longitud pasa a ser igual a la capacidad.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let mut array: DynamicArray<u32> = DynamicArray::new(5);
+    /// let mut next = 1;
+    ///
+    /// array.fill_with(|| {
+    ///     next *= 2;
+    ///     next
+    /// });
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&2, &4, &8, &16, &32]);
+    /// ```
+    ///
+    /// # Notas
+    /// - A diferencia de `fill`, los valores no se clonan: cada posición recibe el valor que produce el generador.
+    pub fn fill_with<F: FnMut() -> T>(&mut self, mut generator: F) {
+        self.truncate_to(0);
+        while self.len < self.capacity {
+            self.array[self.len].write(generator());
+            self.len += 1;
+        }
+    }
+
+    /// Redimensiona la capacidad del arreglo dinámico.
+    ///
+    /// # Parámetros
+    /// - `new_capacity`: La nueva capacidad del arreglo dinámico. Define el número máximo de elementos que puede contener después de redimensionarse.
+    ///
+    /// # Comportamiento
+    /// - Si `new_capacity` es mayor que la capacidad actual, se crea un nuevo arreglo con la capacidad especificada y los elementos existentes se mueven a él, sin clonarse.
+    /// - Si `new_capacity` es menor que la capacidad actual, se truncan los elementos que excedan la nueva capacidad.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let mut array = DynamicArray::with_values(3, &[1, 2, 3]);
+    ///
+    /// // Redimensiona para aumentar la capacidad.
+    /// array.resize(5);
+    /// assert_eq!(array.len(), 3);
+    /// assert_eq!(array.capacity(), 5);
+    /// assert!(array.get(4).is_err()); // Las nuevas posiciones están vacías.
+    ///
+    /// // Redimensiona para reducir la capacidad.
+    /// array.resize(2);
+    /// assert_eq!(array.len(), array.capacity());
+    /// assert_eq!(array.capacity(), 2);
+    /// assert!(array.get(2).is_err());
+    ///
+    /// // Redimensionar a capacidad 0.
+    /// array.resize(0);
+    /// assert_eq!(array.len(), 0);
+    /// ```
+    ///
+    /// # Notas
+    /// - Redimensionar a una capacidad menor que la longitud actual (`len`) eliminará elementos desde el final hasta ajustarse a la nueva capacidad.
+    pub fn resize(&mut self, new_capacity: usize) {
+        self.truncate_to(new_capacity);
+        let mut new_array = Box::new_uninit_slice(new_capacity);
+        // SAFETY: los `self.len` elementos inicializados caben en la nueva memoria gracias a `truncate_to`, y se mueven sin duplicarse porque la memoria anterior se libera sin soltar su contenido.
+        unsafe {
+            ptr::copy_nonoverlapping(self.array.as_ptr(), new_array.as_mut_ptr(), self.len);
+        }
+
+        self.array = new_array;
+        self.capacity = new_capacity;
+    }
+
+    /// Cambia la longitud del arreglo dinámico, agregando copias de `value` al final o eliminando los últimos elementos.
+    ///
+    /// # Parámetros
+    /// - `new_len`: La nueva longitud del arreglo.
+    /// - `value`: El valor con el que se ocupan las posiciones nuevas cuando el arreglo crece.
+    ///
+    /// # Comportamiento
+    /// - Si `new_len` es mayor que la longitud actual, se agregan `new_len - len` copias de `value`. Si no alcanza la capacidad, el arreglo se redimensiona una sola vez a exactamente `new_len`.
+    /// - Si `new_len` es menor que la longitud actual, los elementos sobrantes se eliminan y la capacidad no cambia.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let mut array = DynamicArray::from(&[1, 2]);
+    ///
+    /// array.resize_len(4, 0);
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&1, &2, &0, &0]);
+    ///
+    /// array.resize_len(1, 0);
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&1]);
+    /// assert_eq!(array.capacity(), 4);
+    /// ```
+    ///
+    /// # Notas
+    /// - A diferencia de `resize`, que solo cambia la capacidad, este método cambia la cantidad de elementos, igual que `Vec::resize`.
+    pub fn resize_len(&mut self, new_len: usize, value: T) {
+        self.resize_len_with(new_len, || value.clone());
+    }
+
+    /// Cambia la longitud del arreglo dinámico, ocupando las posiciones nuevas con los valores que devuelve un generador.
+    ///
+    /// # Parámetros
+    /// - `new_len`: La nueva longitud del arreglo.
+    /// - `generator`: Una función que se invoca una vez por cada posición nueva, en orden.
+    ///
+    /// # Comportamiento
+    /// - Igual que `resize_len`, pero los valores nuevos se obtienen del generador en lugar de clonarse.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let mut array: DynamicArray<usize> = DynamicArray::new(0);
+    /// let mut counter = 0;
+    ///
+    /// array.resize_len_with(3, || {
+    ///     counter += 1;
+    ///     counter * 10
+    /// });
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&10, &20, &30]);
+    /// ```
+    pub fn resize_len_with<F: FnMut() -> T>(&mut self, new_len: usize, mut generator: F) {
+        if new_len > self.capacity {
+            self.resize(new_len);
+        }
+        while self.len < new_len {
+            self.array[self.len].write(generator());
+            self.len += 1;
+        }
+        self.truncate_to(new_len);
+    }
+
+    /// Busca un elemento en un arreglo ordenado mediante búsqueda binaria, usando una función de comparación.
+    ///
+    /// # Parámetros
+    /// - `compare`: Una función que compara cada elemento con el objetivo buscado y devuelve `Less` si el elemento es menor, `Greater` si es mayor y `Equal` si coincide.
+    ///
+    /// # Retornos
+    /// - `Ok(usize)`: El índice de un elemento para el que `compare` devuelve `Equal`.
+    /// - `Err(usize)`: El índice donde podría insertarse el objetivo manteniendo el orden.
+    ///
+    /// # Comportamiento
+    /// - Mantiene un intervalo `[low, high)` de candidatos y en cada paso compara el elemento central, descartando la mitad que no puede contener el objetivo.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let array = DynamicArray::from(&[(1, "uno"), (3, "tres"), (5, "cinco")]);
+    ///
+    /// assert_eq!(array.binary_search_by(|(key, _)| key.cmp(&3)), Ok(1));
+    /// assert_eq!(array.binary_search_by(|(key, _)| key.cmp(&4)), Err(2));
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Err(usize)` con el punto de inserción si ningún elemento coincide con el objetivo.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo logarítmico (`O(log n)`).
+    /// - Si el arreglo no está ordenado de forma consistente con `compare`, el resultado no está especificado.
+    /// - Si varios elementos coinciden, puede devolverse cualquiera de ellos.
+    pub fn binary_search_by<F: FnMut(&T) -> Ordering>(
+        &self,
+        mut compare: F,
+    ) -> Result<usize, usize> {
+        let mut low: usize = 0;
+        let mut high: usize = self.len;
+        while low < high {
+            let middle = low + (high - low) / 2;
+            match compare(&self.as_slice()[middle]) {
+                Ordering::Less => low = middle + 1,
+                Ordering::Greater => high = middle,
+                Ordering::Equal => return Ok(middle),
+            }
+        }
+        Err(low)
+    }
+
+    fn as_slice(&self) -> &[T] {
+        // SAFETY: las posiciones `0..self.len` están inicializadas y `MaybeUninit<T>` tiene la misma representación en memoria que `T`.
+        unsafe { slice::from_raw_parts(self.array.as_ptr().cast::<T>(), self.len) }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: igual que en `as_slice`; el préstamo mutable de `self` garantiza el acceso exclusivo.
+        unsafe { slice::from_raw_parts_mut(self.array.as_mut_ptr().cast::<T>(), self.len) }
+    }
+
+    fn truncate_to(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        let tail = ptr::from_mut(&mut self.as_mut_slice()[len..]);
+        // La longitud se reduce antes de liberar los elementos, para que un `drop` que entre en pánico no provoque una doble liberación.
+        self.len = len;
+        // SAFETY: los elementos de `tail` están inicializados y ya quedaron fuera de `0..self.len`.
+        unsafe { ptr::drop_in_place(tail) };
+    }
+
+    fn into_vec(mut self) -> Vec<T> {
+        let len = self.len;
+        // Con la longitud en `0`, `Drop` no libera los elementos que pasan al vector.
+        self.len = 0;
+        let mut slots = ManuallyDrop::new(mem::take(&mut self.array).into_vec());
+        // SAFETY: `MaybeUninit<T>` tiene la misma representación que `T`, las primeras `len` posiciones están inicializadas y la memoria se cede al vector sin liberarse.
+        unsafe { Vec::from_raw_parts(slots.as_mut_ptr().cast::<T>(), len, slots.capacity()) }
+    }
+}
+
+impl<T: Clone + PartialEq> DynamicArray<T> {
+    /// Verifica si el arreglo dinámico contiene un elemento igual al valor especificado.
+    ///
+    /// # Parámetros
+    /// - `value`: Una referencia al valor que se desea buscar.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let array = DynamicArray::with_values(5, &[10, 20, 30]);
+    ///
+    /// assert!(array.contains(&20));
+    /// assert!(!array.contains(&40));
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`) y solo recorre las posiciones inicializadas.
+    #[must_use]
+    pub fn contains(&self, value: &T) -> bool {
+        self.iter().any(|current| current == value)
+    }
+}
+
+impl<T: Clone + Ord> DynamicArray<T> {
+    /// Devuelve una referencia al menor elemento del arreglo dinámico, o `None` si está vacío.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let array = DynamicArray::with_values(5, &[3, 1, 4]);
+    ///
+    /// assert_eq!(array.min(), Some(&1));
+    /// assert_eq!(DynamicArray::<i32>::new(2).min(), None);
+    /// ```
+    ///
+    /// # Notas
+    /// - Si hay varios elementos mínimos, se devuelve el primero.
+    #[must_use]
+    pub fn min(&self) -> Option<&T> {
+        self.iter().min()
+    }
+
+    /// Devuelve una referencia al mayor elemento del arreglo dinámico, o `None` si está vacío.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let array = DynamicArray::with_values(5, &[3, 1, 4]);
+    ///
+    /// assert_eq!(array.max(), Some(&4));
+    /// ```
+    ///
+    /// # Notas
+    /// - Si hay varios elementos máximos, se devuelve el último.
+    #[must_use]
+    pub fn max(&self) -> Option<&T> {
+        self.iter().max()
+    }
+
+    /// Busca un valor en un arreglo ordenado de forma ascendente mediante búsqueda binaria.
+    ///
+    /// # Parámetros
+    /// - `target`: Una referencia al valor buscado.
+    ///
+    /// # Retornos
+    /// - `Ok(usize)`: El índice de un elemento igual a `target`.
+    /// - `Err(usize)`: El índice donde podría insertarse `target` manteniendo el orden.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let mut array = DynamicArray::from(&[1, 3, 5, 7]);
+    ///
+    /// assert_eq!(array.binary_search(&5), Ok(2));
+    /// assert_eq!(array.binary_search(&0), Err(0));
+    /// assert_eq!(array.binary_search(&8), Err(4));
+    ///
+    /// // El punto de inserción mantiene el arreglo ordenado.
+    /// if let Err(index) = array.binary_search(&4) {
+    ///     array.insert(index, 4).unwrap();
+    /// }
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&1, &3, &4, &5, &7]);
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Err(usize)` con el punto de inserción si ningún elemento es igual a `target`.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo logarítmico (`O(log n)`). Equivalente a `binary_search_by(|value| value.cmp(target))`.
+    pub fn binary_search(&self, target: &T) -> Result<usize, usize> {
+        self.binary_search_by(|value| value.cmp(target))
+    }
+}
+
+impl<T: Clone + Sum> DynamicArray<T> {
+    /// Suma todos los elementos del arreglo dinámico.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let array = DynamicArray::with_values(5, &[1.5, 2.0, 0.5]);
+    ///
+    /// assert_eq!(array.sum(), 4.0);
+    /// assert_eq!(DynamicArray::<i32>::new(2).sum(), 0);
+    /// ```
+    ///
+    /// # Notas
+    /// - Cada elemento se clona antes de sumarse, lo que no tiene costo para los tipos numéricos. Un arreglo vacío devuelve el neutro de la suma (`0`).
+    #[must_use]
+    pub fn sum(&self) -> T {
+        self.iter().cloned().sum()
+    }
+}
+
+/// Une varios arreglos dinámicos en uno nuevo, copiando sus elementos en orden.
+///
+/// # Parámetros
+/// - `arrays`: Los arreglos que se desean unir.
+///
+/// # Retornos
+/// - Un nuevo `DynamicArray` cuya capacidad es exactamente la suma de las longitudes de `arrays`.
+///
+/// # Ejemplo
+/// ```
+/// # use array::{concat, DynamicArray};
+/// let first = DynamicArray::from(&[1, 2]);
+/// let second = DynamicArray::new(4);
+/// let third = DynamicArray::from(&[3]);
+///
+/// let joined = concat(&[first, second, third]);
+///
+/// assert_eq!(joined.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+/// assert_eq!(joined.capacity(), 3);
+/// ```
+///
+/// # Notas
+/// - Los arreglos originales no se modifican. Para mover los elementos sin clonarlos, utiliza `DynamicArray::append`.
+#[must_use]
+pub fn concat<T: Clone>(arrays: &[DynamicArray<T>]) -> DynamicArray<T> {
+    let mut joined = DynamicArray::new(arrays.iter().map(DynamicArray::len).sum());
+    for array in arrays {
+        for value in array {
+            joined.push(value.clone());
+        }
+    }
+    joined
+}
+
+impl<T: Clone, const N: usize> From<&[T; N]> for DynamicArray<T> {
+    fn from(values: &[T; N]) -> Self {
+        Self::with_values(N, values)
+    }
+}
+
+impl<T: Clone> From<&[T]> for DynamicArray<T> {
+    fn from(values: &[T]) -> Self {
+        Self::with_values(values.len(), values)
+    }
+}
+
+impl<T: Clone> Clone for DynamicArray<T> {
+    fn clone(&self) -> Self {
+        let mut array = Self::new(self.capacity);
+        array.extend_from_slice(self.as_slice());
+        array
+    }
+}
+
+impl<T: Clone> Drop for DynamicArray<T> {
+    fn drop(&mut self) {
+        self.truncate_to(0);
+    }
+}
+
+impl<T: Clone> From<Vec<T>> for DynamicArray<T> {
+    /// Convierte un `Vec<T>` en un arreglo dinámico, moviendo sus elementos sin clonarlos.
+    ///
+    /// # Comportamiento
+    /// - La capacidad del arreglo es la capacidad del vector, y la longitud es la longitud del vector.
+    /// - Se reutiliza la memoria del vector en lugar de reservar una nueva.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let mut values = Vec::with_capacity(4);
+    /// values.push(String::from("a"));
+    /// values.push(String::from("b"));
+    ///
+    /// let array = DynamicArray::from(values);
+    /// assert_eq!(array.len(), 2);
+    /// assert_eq!(array.capacity(), 4);
+    /// ```
+    fn from(values: Vec<T>) -> Self {
+        let mut values = ManuallyDrop::new(values);
+        let len = values.len();
+        // Los vectores de tipos de tamaño cero informan una capacidad de `usize::MAX`.
+        let capacity = if mem::size_of::<T>() == 0 {
+            len
+        } else {
+            values.capacity()
+        };
+        // SAFETY: `MaybeUninit<T>` tiene la misma representación que `T`, cualquier contenido es válido para `MaybeUninit<T>`, y la memoria del vector se cede al arreglo sin liberarse.
+        let slots = unsafe {
+            Vec::from_raw_parts(
+                values.as_mut_ptr().cast::<MaybeUninit<T>>(),
+                capacity,
+                capacity,
+            )
+        };
+
+        Self {
+            array: slots.into_boxed_slice(),
+            len,
+            capacity,
+        }
+    }
+}
+
+impl<T: Clone> From<DynamicArray<T>> for Vec<T> {
+    /// Convierte un arreglo dinámico en un `Vec<T>`, moviendo sus elementos sin clonarlos.
+    ///
+    /// # Comportamiento
+    /// - Se reutiliza la memoria del arreglo en lugar de reservar una nueva: las posiciones libres pasan a ser la capacidad sobrante del vector.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let array = DynamicArray::with_values(5, &[1, 2, 3]);
+    ///
+    /// let values: Vec<i32> = array.into();
+    /// assert_eq!(values, vec![1, 2, 3]);
+    /// ```
+    fn from(array: DynamicArray<T>) -> Self {
+        array.into_vec()
+    }
+}
+
+impl<T: Clone> FromIterator<T> for DynamicArray<T> {
+    /// Construye un arreglo dinámico a partir de los valores de un iterador, en el mismo orden.
+    ///
+    /// # Comportamiento
+    /// - La capacidad inicial se toma de la cota inferior de `size_hint`, por lo que los iteradores de tamaño conocido no provocan redimensionamientos.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let array: DynamicArray<i32> = (1..=4).map(|value| value * 10).collect();
+    ///
+    /// assert_eq!(array.len(), 4);
+    /// assert_eq!(array.capacity(), 4);
+    /// assert_eq!(array.get(3), Ok(&40));
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut array = Self::new(iter.size_hint().0);
+        array.extend(iter);
+        array
+    }
+}
+
+impl<T: Clone> Extend<T> for DynamicArray<T> {
+    /// Agrega al final del arreglo dinámico todos los valores de un iterador.
+    ///
+    /// # Comportamiento
+    /// - Antes de agregar, reserva de una sola vez la capacidad indicada por la cota inferior de `size_hint`; el resto de los valores se agrega con `push`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let mut array = DynamicArray::from(&[1, 2]);
+    /// array.extend(vec![3, 4, 5]);
+    ///
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4, &5]);
+    /// assert_eq!(array.capacity(), 5);
+    /// ```
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let required = self.len + iter.size_hint().0;
+        if required > self.capacity {
+            self.resize(required);
+        }
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T: Clone> Index<usize> for DynamicArray<T> {
+    type Output = T;
+
+    /// Obtiene una referencia inmutable al elemento en el índice especificado mediante la sintaxis `array[index]`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let array = DynamicArray::from(&[10, 20, 30]);
+    ///
+    /// assert_eq!(array[1], 20);
+    /// ```
+    ///
+    /// # Panics
+    /// Entra en pánico si `index` es mayor o igual a la longitud del arreglo (`len`). Para obtener un `Result` en lugar de un pánico, utiliza `get`.
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Ok(value) => value,
+            Err(_) => panic!(
+                "index out of bounds: the len is {} but the index is {index}",
+                self.len
+            ),
+        }
+    }
+}
+
+impl<T: Clone> IndexMut<usize> for DynamicArray<T> {
+    /// Obtiene una referencia mutable al elemento en el índice especificado mediante la sintaxis `array[index]`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let mut array = DynamicArray::from(&[10, 20, 30]);
+    ///
+    /// array[1] = 25;
+    /// assert_eq!(array.get(1), Ok(&25));
+    /// ```
+    ///
+    /// # Panics
+    /// Entra en pánico si `index` es mayor o igual a la longitud del arreglo (`len`). Para obtener un `Result` en lugar de un pánico, utiliza `get_mut` o `set`.
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len;
+        match self.get_mut(index) {
+            Ok(value) => value,
+            Err(_) => panic!("index out of bounds: the len is {len} but the index is {index}"),
+        }
+    }
+}
+
+impl<T: Clone> IntoIterator for DynamicArray<T> {
+    type Item = T;
+    type IntoIter = DynamicArrayIntoIter<T>;
+
+    /// Consume el arreglo dinámico y devuelve un iterador que entrega sus elementos por propiedad.
+    ///
+    /// # Comportamiento
+    /// - Los valores se mueven fuera de sus posiciones sin clonarse.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let array = DynamicArray::with_values(5, &[String::from("a"), String::from("b")]);
+    ///
+    /// let upper: Vec<String> = array.into_iter().map(|value| value.to_uppercase()).collect();
+    /// assert_eq!(upper, vec![String::from("A"), String::from("B")]);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        DynamicArrayIntoIter::new(self.into_vec())
+    }
+}
+
+impl<'a, T: Clone> IntoIterator for &'a DynamicArray<T> {
+    type Item = &'a T;
+    type IntoIter = DynamicArrayIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: Clone> IntoIterator for &'a mut DynamicArray<T> {
+    type Item = &'a mut T;
+    type IntoIter = DynamicArrayIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: Clone + Debug> Debug for DynamicArray<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
         write!(f, "[")?;
-        for (index, value) in self.clone().enumerate() {
+        for (index, value) in self.iter().enumerate() {
             if index > 0 {
                 write!(f, ", ")?;
             }
@@ -718,3 +1657,63 @@ impl<T: Clone + Debug> Debug for DynamicArray<T> {
         write!(f, "]")
     }
 }
+
+impl<T: Clone + PartialEq> PartialEq for DynamicArray<T> {
+    /// Compara dos arreglos dinámicos elemento por elemento, considerando solo las posiciones inicializadas.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// let left = DynamicArray::with_values(5, &[1, 2, 3]);
+    /// let right = DynamicArray::with_values(3, &[1, 2, 3]);
+    ///
+    /// // La capacidad no forma parte de la comparación.
+    /// assert_eq!(left, right);
+    /// assert_eq!(left, vec![1, 2, 3]);
+    /// assert_eq!(left, [1, 2, 3][..]);
+    /// assert_ne!(left, DynamicArray::from(&[1, 2]));
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Clone + Eq> Eq for DynamicArray<T> {}
+
+impl<T: Clone + PartialEq> PartialEq<[T]> for DynamicArray<T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.len == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Clone + PartialEq> PartialEq<&[T]> for DynamicArray<T> {
+    fn eq(&self, other: &&[T]) -> bool {
+        *self == **other
+    }
+}
+
+impl<T: Clone + PartialEq> PartialEq<Vec<T>> for DynamicArray<T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        *self == *other.as_slice()
+    }
+}
+
+impl<T: Clone + Hash> Hash for DynamicArray<T> {
+    /// Calcula el hash a partir de la longitud y de los elementos inicializados, en orden, de modo que dos arreglos iguales producen el mismo hash sin importar su capacidad.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::DynamicArray;
+    /// # use std::collections::HashSet;
+    /// let mut set = HashSet::new();
+    ///
+    /// set.insert(DynamicArray::with_values(5, &[1, 2]));
+    /// assert!(set.contains(&DynamicArray::from(&[1, 2])));
+    /// ```
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for value in self {
+            value.hash(state);
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use std::slice::Iter;
+
+pub struct FixedArrayIterator<'a, T: Clone> {
+    slots: Iter<'a, Option<T>>,
+}
+
+impl<'a, T: Clone> FixedArrayIterator<'a, T> {
+    pub fn new(slots: &'a [Option<T>]) -> Self {
+        Self {
+            slots: slots.iter(),
+        }
+    }
+}
+
+impl<'a, T: Clone> Iterator for FixedArrayIterator<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots.find_map(Option::as_ref)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.slots.len(), Some(self.slots.len()))
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for FixedArrayIterator<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.slots.by_ref().rev().find_map(Option::as_ref)
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for FixedArrayIterator<'_, T> {}
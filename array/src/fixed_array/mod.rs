@@ -0,0 +1,408 @@
+mod iterator;
+
+use crate::StaticArray;
+use exceptions::Exceptions;
+use iterator::FixedArrayIterator;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+use std::ops::{Index, IndexMut};
+
+#[derive(Clone)]
+pub struct FixedArray<T: Clone, const N: usize> {
+    array: [Option<T>; N],
+    len: usize,
+}
+
+impl<T: Clone, const N: usize> FixedArray<T, N> {
+    /// Crea un nuevo arreglo de tamaño fijo vacío, con capacidad `N` definida en tiempo de compilación.
+    ///
+    /// # Retornos
+    /// - Devuelve una nueva instancia de `FixedArray` sin elementos, cuyas `N` posiciones se almacenan en línea, sin reservar memoria en el heap.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::FixedArray;
+    /// let array: FixedArray<i32, 5> = FixedArray::new();
+    ///
+    /// assert_eq!(array.capacity(), 5);
+    /// assert!(array.is_empty());
+    /// ```
+    ///
+    /// # Notas
+    /// - A diferencia de `StaticArray`, la capacidad forma parte del tipo: `FixedArray<i32, 4>` y `FixedArray<i32, 5>` son tipos distintos.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            array: [const { None }; N],
+            len: 0,
+        }
+    }
+
+    /// Crea un nuevo arreglo de tamaño fijo con los valores iniciales del slice.
+    ///
+    /// # Parámetros
+    /// - `values`: Un slice de valores que se utilizarán para inicializar el arreglo.
+    ///
+    /// # Comportamiento
+    /// - Si `values` tiene más de `N` elementos, solo se toman los primeros `N`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::FixedArray;
+    /// let array: FixedArray<i32, 2> = FixedArray::with_values(&[10, 20, 30]);
+    ///
+    /// assert_eq!(array.len(), 2);
+    /// assert_eq!(array.get(1), Ok(&20));
+    /// assert!(array.get(2).is_err());
+    /// ```
+    #[must_use]
+    pub fn with_values(values: &[T]) -> Self {
+        let mut array = Self::new();
+        for (slot, value) in array.array.iter_mut().zip(values) {
+            *slot = Some(value.clone());
+        }
+        array.len = values.len().min(N);
+        array
+    }
+
+    /// Obtiene una referencia inmutable al elemento en el índice especificado.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del elemento que se desea obtener. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::FixedArray;
+    /// let array: FixedArray<i32, 4> = FixedArray::with_values(&[1, 2, 3]);
+    ///
+    /// assert_eq!(array.get(1), Ok(&2));
+    /// assert!(array.get(3).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - `index` es mayor o igual a `self.len`.
+    pub fn get(&self, index: usize) -> Result<&T, Exceptions> {
+        if index >= self.len {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        self.array[index]
+            .as_ref()
+            .ok_or(Exceptions::IndexOutOfBounds)
+    }
+
+    /// Obtiene una referencia mutable al elemento en el índice especificado.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del elemento que se desea obtener. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::FixedArray;
+    /// let mut array: FixedArray<i32, 4> = FixedArray::with_values(&[1, 2, 3]);
+    /// if let Ok(value) = array.get_mut(1) {
+    ///     *value = 42;
+    /// }
+    /// assert_eq!(array.get(1), Ok(&42));
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - `index` es mayor o igual a `self.len`.
+    pub fn get_mut(&mut self, index: usize) -> Result<&mut T, Exceptions> {
+        if index >= self.len {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        self.array[index]
+            .as_mut()
+            .ok_or(Exceptions::IndexOutOfBounds)
+    }
+
+    /// Establece un valor en el índice especificado.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice en el que se desea establecer el valor. Debe estar en el rango `0..self.len`.
+    /// - `value`: El valor que se desea asignar.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::FixedArray;
+    /// let mut array: FixedArray<i32, 3> = FixedArray::with_values(&[1, 2]);
+    ///
+    /// assert_eq!(array.set(1, 42), Ok(()));
+    /// assert_eq!(array.get(1), Ok(&42));
+    /// assert!(array.set(2, 10).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - `index` es mayor o igual a `self.len`.
+    pub fn set(&mut self, index: usize, value: T) -> Result<(), Exceptions> {
+        *self.get_mut(index)? = value;
+        Ok(())
+    }
+
+    /// Agrega un elemento al final del arreglo.
+    ///
+    /// # Parámetros
+    /// - `value`: El valor que se desea agregar.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::FixedArray;
+    /// let mut array: FixedArray<i32, 2> = FixedArray::new();
+    ///
+    /// assert_eq!(array.push(10), Ok(()));
+    /// assert_eq!(array.push(20), Ok(()));
+    /// assert!(array.push(30).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - El arreglo está lleno (`len == N`).
+    pub fn push(&mut self, value: T) -> Result<(), Exceptions> {
+        if self.len == N {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        self.array[self.len] = Some(value);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Inserta un valor en el índice especificado, desplazando los elementos posteriores hacia la derecha.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice en el que se desea insertar el valor. Debe estar en el rango `0..self.len`.
+    /// - `value`: El valor que se desea insertar.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::FixedArray;
+    /// let mut array: FixedArray<i32, 4> = FixedArray::with_values(&[1, 3]);
+    ///
+    /// // Igual que en los demás arreglos, insertar en `len` retorna un error: para agregar al final se usa `push`.
+    /// assert!(array.insert(2, 4).is_err());
+    /// assert_eq!(array.push(4), Ok(()));
+    ///
+    /// assert_eq!(array.insert(1, 2), Ok(()));
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4]);
+    ///
+    /// // Un arreglo lleno no descarta elementos: retorna un error.
+    /// assert!(array.insert(0, 0).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - `index` es mayor o igual a `self.len`.
+    /// - El arreglo está lleno (`len == N`).
+    ///
+    /// # Notas
+    /// - A diferencia de `StaticArray::insert`, un arreglo lleno no descarta su último elemento.
+    /// - Los elementos se desplazan rotando las posiciones, sin clonar valores.
+    pub fn insert(&mut self, index: usize, value: T) -> Result<(), Exceptions> {
+        if index >= self.len || self.len == N {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        self.array[index..=self.len].rotate_right(1);
+        self.array[index] = Some(value);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Elimina y devuelve el último elemento del arreglo.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::FixedArray;
+    /// let mut array: FixedArray<i32, 3> = FixedArray::with_values(&[1, 2]);
+    ///
+    /// assert_eq!(array.pop(), Ok(2));
+    /// assert_eq!(array.pop(), Ok(1));
+    /// assert!(array.pop().is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - El arreglo está vacío.
+    pub fn pop(&mut self) -> Result<T, Exceptions> {
+        if self.is_empty() {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        self.remove(self.len - 1)
+    }
+
+    /// Elimina el elemento en el índice especificado y devuelve su valor, desplazando los elementos posteriores hacia la izquierda.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del elemento que se desea eliminar. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::FixedArray;
+    /// let mut array: FixedArray<i32, 4> = FixedArray::with_values(&[1, 2, 3]);
+    ///
+    /// assert_eq!(array.remove(0), Ok(1));
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&2, &3]);
+    /// assert!(array.remove(2).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - `index` es mayor o igual a `self.len`.
+    pub fn remove(&mut self, index: usize) -> Result<T, Exceptions> {
+        if index >= self.len {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        let value = self.array[index]
+            .take()
+            .ok_or(Exceptions::IndexOutOfBounds)?;
+        self.array[index..self.len].rotate_left(1);
+        self.len -= 1;
+        Ok(value)
+    }
+
+    /// Devuelve la cantidad de elementos almacenados actualmente en el arreglo.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Devuelve la capacidad del arreglo, que es siempre `N`.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Verifica si el arreglo está vacío.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Devuelve un iterador que recorre por referencia los elementos del arreglo, desde el índice `0` hasta `len - 1`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::FixedArray;
+    /// let array: FixedArray<i32, 5> = FixedArray::with_values(&[1, 2, 3]);
+    ///
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// ```
+    #[must_use]
+    pub fn iter(&self) -> FixedArrayIterator<'_, T> {
+        FixedArrayIterator::new(&self.array[..self.len])
+    }
+}
+
+impl<T: Clone, const N: usize> Default for FixedArray<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const N: usize> From<&[T; N]> for FixedArray<T, N> {
+    fn from(values: &[T; N]) -> Self {
+        Self::with_values(values)
+    }
+}
+
+impl<T: Clone, const N: usize> From<FixedArray<T, N>> for StaticArray<T> {
+    /// Convierte un arreglo de tamaño fijo en un `StaticArray` con la misma capacidad `N`, almacenado en el heap.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::{FixedArray, StaticArray};
+    /// let fixed: FixedArray<i32, 4> = FixedArray::with_values(&[1, 2]);
+    /// let array = StaticArray::from(fixed);
+    ///
+    /// assert_eq!(array.capacity(), 4);
+    /// assert_eq!(array.len(), 2);
+    /// ```
+    fn from(values: FixedArray<T, N>) -> Self {
+        let mut array = Self::new(N);
+        for value in values.array.into_iter().take(values.len).flatten() {
+            array.push(value).ok();
+        }
+        array
+    }
+}
+
+impl<T: Clone, const N: usize> TryFrom<StaticArray<T>> for FixedArray<T, N> {
+    type Error = Exceptions;
+
+    /// Convierte un `StaticArray` en un arreglo de tamaño fijo, si sus elementos caben en `N` posiciones.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::{FixedArray, StaticArray};
+    /// let array = StaticArray::with_values(10, &[1, 2, 3]);
+    ///
+    /// let fixed: FixedArray<i32, 3> = FixedArray::try_from(array.clone()).unwrap();
+    /// assert_eq!(fixed.len(), 3);
+    ///
+    /// // Tres elementos no caben en dos posiciones.
+    /// assert!(FixedArray::<i32, 2>::try_from(array).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Retorna `Exceptions::IndexOutOfBounds` si la longitud del `StaticArray` es mayor que `N`.
+    fn try_from(values: StaticArray<T>) -> Result<Self, Self::Error> {
+        if values.len() > N {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        let mut array = Self::new();
+        for value in values {
+            array.push(value)?;
+        }
+        Ok(array)
+    }
+}
+
+impl<T: Clone, const N: usize> Index<usize> for FixedArray<T, N> {
+    type Output = T;
+
+    /// # Panics
+    /// Entra en pánico si `index` es mayor o igual a la longitud del arreglo (`len`). Para obtener un `Result` en lugar de un pánico, utiliza `get`.
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Ok(value) => value,
+            Err(_) => panic!(
+                "index out of bounds: the len is {} but the index is {index}",
+                self.len
+            ),
+        }
+    }
+}
+
+impl<T: Clone, const N: usize> IndexMut<usize> for FixedArray<T, N> {
+    /// # Panics
+    /// Entra en pánico si `index` es mayor o igual a la longitud del arreglo (`len`). Para obtener un `Result` en lugar de un pánico, utiliza `get_mut` o `set`.
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len;
+        match self.get_mut(index) {
+            Ok(value) => value,
+            Err(_) => panic!("index out of bounds: the len is {len} but the index is {index}"),
+        }
+    }
+}
+
+impl<'a, T: Clone, const N: usize> IntoIterator for &'a FixedArray<T, N> {
+    type Item = &'a T;
+    type IntoIter = FixedArrayIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Clone + Debug, const N: usize> Debug for FixedArray<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "[")?;
+        for (index, value) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{value:?}")?;
+        }
+        write!(f, "]")
+    }
+}
@@ -0,0 +1,366 @@
+mod iterator;
+
+use exceptions::Exceptions;
+use iterator::GapBufferIterator;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+
+#[derive(Clone)]
+pub struct GapBuffer<T: Clone> {
+    buffer: Box<[Option<T>]>,
+    gap_start: usize,
+    gap_end: usize,
+}
+
+impl<T: Clone> GapBuffer<T> {
+    /// Crea un nuevo buffer con hueco vacío, cuyo hueco ocupa toda la capacidad especificada.
+    ///
+    /// # Parámetros
+    /// - `capacity`: La capacidad inicial del buffer. Cuando el hueco se agota, la capacidad se duplica automáticamente.
+    ///
+    /// # Retornos
+    /// - Devuelve una nueva instancia de `GapBuffer` sin elementos y con el cursor en la posición `0`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::GapBuffer;
+    /// let buffer: GapBuffer<char> = GapBuffer::new(8);
+    ///
+    /// assert_eq!(buffer.capacity(), 8);
+    /// assert_eq!(buffer.cursor(), 0);
+    /// assert!(buffer.is_empty());
+    /// ```
+    ///
+    /// # Notas
+    /// - El hueco es el rango de posiciones libres del almacenamiento. Su inicio coincide siempre con el cursor, de modo que insertar o borrar junto al cursor no desplaza ningún elemento.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        let mut vec: Vec<Option<T>> = Vec::with_capacity(capacity);
+        vec.resize_with(capacity, || None);
+
+        Self {
+            buffer: vec.into_boxed_slice(),
+            gap_start: 0,
+            gap_end: capacity,
+        }
+    }
+
+    /// Crea un nuevo buffer con hueco con los valores iniciales del slice, con el cursor al final.
+    ///
+    /// # Parámetros
+    /// - `capacity`: La capacidad inicial del buffer. Si es menor que la cantidad de valores, se utiliza la cantidad de valores.
+    /// - `values`: Un slice de valores que se utilizarán para inicializar el buffer.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::GapBuffer;
+    /// let buffer = GapBuffer::with_values(8, &['h', 'o', 'l', 'a']);
+    ///
+    /// assert_eq!(buffer.len(), 4);
+    /// assert_eq!(buffer.cursor(), 4);
+    /// ```
+    #[must_use]
+    pub fn with_values(capacity: usize, values: &[T]) -> Self {
+        let mut buffer = Self::new(capacity.max(values.len()));
+        for (slot, value) in buffer.buffer.iter_mut().zip(values) {
+            *slot = Some(value.clone());
+        }
+        buffer.gap_start = values.len();
+        buffer
+    }
+
+    /// Obtiene una referencia inmutable al elemento en la posición lógica especificada, sin contar el hueco.
+    ///
+    /// # Parámetros
+    /// - `index`: La posición del elemento. Debe estar en el rango `0..self.len()`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::GapBuffer;
+    /// let mut buffer = GapBuffer::with_values(8, &['a', 'b', 'c']);
+    /// buffer.move_gap(1).unwrap();
+    ///
+    /// // El hueco está entre 'a' y 'b', pero los índices no cambian.
+    /// assert_eq!(buffer.get(1), Ok(&'b'));
+    /// assert!(buffer.get(3).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - `index` es mayor o igual a `self.len()`.
+    pub fn get(&self, index: usize) -> Result<&T, Exceptions> {
+        if index >= self.len() {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        let slot = if index < self.gap_start {
+            index
+        } else {
+            index + self.gap_len()
+        };
+        self.buffer[slot]
+            .as_ref()
+            .ok_or(Exceptions::IndexOutOfBounds)
+    }
+
+    /// Mueve el hueco (y con él, el cursor) a la posición especificada.
+    ///
+    /// # Parámetros
+    /// - `position`: La nueva posición del cursor. Debe estar en el rango `0..=self.len()`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::GapBuffer;
+    /// let mut buffer = GapBuffer::with_values(8, &['a', 'c']);
+    ///
+    /// buffer.move_gap(1).unwrap();
+    /// buffer.insert('b');
+    /// assert_eq!(buffer.iter().collect::<String>(), "abc");
+    ///
+    /// assert!(buffer.move_gap(4).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - `position` es mayor que `self.len()`.
+    ///
+    /// # Notas
+    /// - Solo se mueven los elementos entre la posición actual del cursor y la nueva, por lo que el costo es proporcional a la distancia recorrida y no a la longitud total.
+    pub fn move_gap(&mut self, position: usize) -> Result<(), Exceptions> {
+        if position > self.len() {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        while self.gap_start > position {
+            self.gap_start -= 1;
+            self.gap_end -= 1;
+            self.buffer[self.gap_end] = self.buffer[self.gap_start].take();
+        }
+        while self.gap_start < position {
+            self.buffer[self.gap_start] = self.buffer[self.gap_end].take();
+            self.gap_start += 1;
+            self.gap_end += 1;
+        }
+        Ok(())
+    }
+
+    /// Inserta un valor en la posición del cursor y avanza el cursor detrás del valor insertado.
+    ///
+    /// # Parámetros
+    /// - `value`: El valor que se desea insertar.
+    ///
+    /// # Comportamiento
+    /// - Si el hueco está vacío, la capacidad se duplica (o pasa a ser 4 si era 0) antes de insertar, y el nuevo hueco queda en la posición del cursor.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::GapBuffer;
+    /// let mut buffer = GapBuffer::new(0);
+    /// for letter in "hola".chars() {
+    ///     buffer.insert(letter);
+    /// }
+    ///
+    /// assert_eq!(buffer.iter().collect::<String>(), "hola");
+    /// assert_eq!(buffer.cursor(), 4);
+    /// ```
+    ///
+    /// # Notas
+    /// - La operación tiene un costo constante amortizado (`O(1)`): el valor ocupa la primera posición del hueco.
+    pub fn insert(&mut self, value: T) {
+        if self.gap_len() == 0 {
+            self.grow();
+        }
+        self.buffer[self.gap_start] = Some(value);
+        self.gap_start += 1;
+    }
+
+    /// Elimina y devuelve el elemento inmediatamente posterior al cursor, como la tecla «Suprimir» de un editor.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::GapBuffer;
+    /// let mut buffer = GapBuffer::with_values(8, &['a', 'b', 'c']);
+    /// buffer.move_gap(1).unwrap();
+    ///
+    /// assert_eq!(buffer.delete(), Ok('b'));
+    /// assert_eq!(buffer.iter().collect::<String>(), "ac");
+    /// assert_eq!(buffer.cursor(), 1);
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - El cursor está al final del buffer.
+    ///
+    /// # Notas
+    /// - La operación tiene un costo constante (`O(1)`): el hueco simplemente crece hacia la derecha.
+    pub fn delete(&mut self) -> Result<T, Exceptions> {
+        if self.gap_end == self.buffer.len() {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        let value = self.buffer[self.gap_end]
+            .take()
+            .ok_or(Exceptions::IndexOutOfBounds)?;
+        self.gap_end += 1;
+        Ok(value)
+    }
+
+    /// Elimina y devuelve el elemento inmediatamente anterior al cursor, como la tecla «Retroceso» de un editor.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::GapBuffer;
+    /// let mut buffer = GapBuffer::with_values(8, &['a', 'b']);
+    ///
+    /// assert_eq!(buffer.backspace(), Ok('b'));
+    /// assert_eq!(buffer.cursor(), 1);
+    /// assert_eq!(buffer.backspace(), Ok('a'));
+    /// assert!(buffer.backspace().is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - El cursor está al inicio del buffer.
+    ///
+    /// # Notas
+    /// - La operación tiene un costo constante (`O(1)`): el hueco simplemente crece hacia la izquierda.
+    pub fn backspace(&mut self) -> Result<T, Exceptions> {
+        if self.gap_start == 0 {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        let value = self.buffer[self.gap_start - 1]
+            .take()
+            .ok_or(Exceptions::IndexOutOfBounds)?;
+        self.gap_start -= 1;
+        Ok(value)
+    }
+
+    /// Devuelve la posición del cursor, que coincide con el inicio del hueco.
+    #[must_use]
+    pub const fn cursor(&self) -> usize {
+        self.gap_start
+    }
+
+    /// Devuelve la cantidad de elementos almacenados, sin contar el hueco.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.buffer.len() - self.gap_len()
+    }
+
+    /// Devuelve la capacidad actual del buffer, incluyendo el hueco.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Verifica si el buffer está vacío.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Devuelve un iterador por referencia sobre los elementos anteriores al cursor.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::GapBuffer;
+    /// let mut buffer = GapBuffer::with_values(8, &['a', 'b', 'c']);
+    /// buffer.move_gap(2).unwrap();
+    ///
+    /// assert_eq!(buffer.before_gap().collect::<String>(), "ab");
+    /// assert_eq!(buffer.after_gap().collect::<String>(), "c");
+    /// ```
+    #[must_use]
+    pub fn before_gap(&self) -> GapBufferIterator<'_, T> {
+        GapBufferIterator::new(&self.buffer[..self.gap_start], &[])
+    }
+
+    /// Devuelve un iterador por referencia sobre los elementos posteriores al cursor.
+    #[must_use]
+    pub fn after_gap(&self) -> GapBufferIterator<'_, T> {
+        GapBufferIterator::new(&self.buffer[self.gap_end..], &[])
+    }
+
+    /// Devuelve un iterador que recorre por referencia todos los elementos del buffer en orden, saltando el hueco.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::GapBuffer;
+    /// let mut buffer = GapBuffer::with_values(8, &[1, 2, 3]);
+    /// buffer.move_gap(1).unwrap();
+    ///
+    /// assert_eq!(buffer.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// assert_eq!(buffer.iter().rev().collect::<Vec<_>>(), vec![&3, &2, &1]);
+    /// ```
+    #[must_use]
+    pub fn iter(&self) -> GapBufferIterator<'_, T> {
+        GapBufferIterator::new(&self.buffer[..self.gap_start], &self.buffer[self.gap_end..])
+    }
+
+    const fn gap_len(&self) -> usize {
+        self.gap_end - self.gap_start
+    }
+
+    fn grow(&mut self) {
+        let new_capacity = if self.buffer.is_empty() {
+            4
+        } else {
+            self.buffer.len() * 2
+        };
+        let after_len = self.buffer.len() - self.gap_end;
+        let mut vec: Vec<Option<T>> = Vec::with_capacity(new_capacity);
+        vec.extend(self.buffer[..self.gap_start].iter_mut().map(Option::take));
+        vec.resize_with(new_capacity - after_len, || None);
+        vec.extend(self.buffer[self.gap_end..].iter_mut().map(Option::take));
+        self.buffer = vec.into_boxed_slice();
+        self.gap_end = new_capacity - after_len;
+    }
+}
+
+impl<T: Clone> Default for GapBuffer<T> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<T: Clone, const N: usize> From<&[T; N]> for GapBuffer<T> {
+    fn from(values: &[T; N]) -> Self {
+        Self::with_values(N, values)
+    }
+}
+
+impl<T: Clone> From<&[T]> for GapBuffer<T> {
+    fn from(values: &[T]) -> Self {
+        Self::with_values(values.len(), values)
+    }
+}
+
+impl<T: Clone> From<Vec<T>> for GapBuffer<T> {
+    fn from(values: Vec<T>) -> Self {
+        // Los valores se mueven a sus posiciones; el hueco queda vacío al final, como en `with_values(len, ..)`.
+        let len = values.len();
+        Self {
+            buffer: values.into_iter().map(Some).collect(),
+            gap_start: len,
+            gap_end: len,
+        }
+    }
+}
+
+impl<'a, T: Clone> IntoIterator for &'a GapBuffer<T> {
+    type Item = &'a T;
+    type IntoIter = GapBufferIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Clone + Debug> Debug for GapBuffer<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "[")?;
+        for (index, value) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{value:?}")?;
+        }
+        write!(f, "]")
+    }
+}
@@ -60,12 +60,24 @@ pub fn dynamic_array() {
     println!("  5. Eliminar de una posición arbitraria (indice: {position}, planeta: {planet:?})\n    {planets:?}");
 }
 
+pub mod array_deque;
+pub mod bit_array;
 pub mod dynamic_array;
+pub mod fixed_array;
+pub mod gap_buffer;
+pub mod ring_buffer;
+pub mod sparse_array;
 pub mod static_array;
 
-pub use dynamic_array::DynamicArray;
+pub use array_deque::ArrayDeque;
+pub use bit_array::BitArray;
+pub use dynamic_array::{concat, DynamicArray};
 use exceptions::Exceptions;
-pub use static_array::StaticArray;
+pub use fixed_array::FixedArray;
+pub use gap_buffer::GapBuffer;
+pub use ring_buffer::RingBuffer;
+pub use sparse_array::SparseArray;
+pub use static_array::{OverflowPolicy, StaticArray};
 
 pub fn da_implementation() -> Result<(), Exceptions> {
     println!("Implementación de un array dinámico");
@@ -90,6 +102,12 @@ pub fn da_implementation() -> Result<(), Exceptions> {
     let position: usize = 0;
     let planet = planets.remove(position)?;
     println!("  5. Eliminar de una posición arbitraria: (index: {position}, planeta: {planet:?})\n    {planets:?}");
+    // 6. Usage as a stack (LIFO)
+    let planet = planets.pop()?;
+    println!("  6. Pila, eliminar el último: (planeta: {planet:?})\n    {planets:?}");
+    // 7. Usage as a queue (FIFO)
+    let planet = planets.shift()?;
+    println!("  7. Cola, eliminar el primero: (planeta: {planet:?})\n    {planets:?}");
     Ok(())
 }
 
@@ -99,9 +117,14 @@ pub fn sa_implementation() -> Result<(), Exceptions> {
     let planets: StaticArray<&str> = StaticArray::new(5);
     println!("  1. Inicialización:\n    {planets:?}");
     // 2. Initializacion with values
-    let mut planets: StaticArray<&str> =
-        StaticArray::with_values(5, &["Venus", "Plutón", "Tierra", "Marte"]);
-    println!("  2.  Inicialización con valores\n    {planets:?}");
+    let mut planets: StaticArray<&str> = StaticArray::with_policy(5, OverflowPolicy::OverwriteEnd);
+    for planet in ["Venus", "Plutón", "Tierra", "Marte"] {
+        planets.push(planet)?;
+    }
+    println!(
+        "  2.  Inicialización con valores (política: {:?})\n    {planets:?}",
+        planets.policy()
+    );
     // 3. Insertion at the ending
     planets.push("Jupiter")?;
     println!("  3. Insertar al final\n    {planets:?}");
@@ -118,3 +141,186 @@ pub fn sa_implementation() -> Result<(), Exceptions> {
     println!("  5. Eliminar de una posición arbitraria: (index: {position}, planeta: {planet:?})\n    {planets:?}");
     Ok(())
 }
+
+pub fn fa_implementation() -> Result<(), Exceptions> {
+    println!("Implementación de un array de tamaño fijo");
+    // 1. Initialization
+    let planets: FixedArray<&str, 5> = FixedArray::new();
+    println!("  1. Inicialización:\n    {planets:?}");
+    // 2. Initializacion with values
+    let mut planets: FixedArray<&str, 5> =
+        FixedArray::with_values(&["Venus", "Plutón", "Tierra", "Marte"]);
+    println!("  2.  Inicialización con valores\n    {planets:?}");
+    // 3. Insertion at an arbitrary position
+    let position: usize = 0;
+    planets.insert(position, "Mercurio")?;
+    println!("  3.  Insertar en una posición arbitraria: (index: {position})\n    {planets:?}");
+    // 4. Deletion at an arbitrary position
+    let position: usize = 2;
+    let planet = planets.remove(position)?;
+    println!("  4. Eliminar de una posición arbitraria: (index: {position}, planeta: {planet:?})\n    {planets:?}");
+    // 5. Conversion to and from the heap-backed array
+    let mut heap_planets = StaticArray::from(planets);
+    heap_planets.push("Jupiter")?;
+    let planets: FixedArray<&str, 5> = FixedArray::try_from(heap_planets)?;
+    println!("  5. Conversión desde y hacia StaticArray\n    {planets:?}");
+    Ok(())
+}
+
+pub fn rb_implementation() -> Result<(), Exceptions> {
+    println!("Implementación de un buffer circular");
+    // 1. Initialization
+    let mut readings: RingBuffer<u32> = RingBuffer::new(3);
+    println!("  1. Inicialización:\n    {readings:?}");
+    // 2. Insertion at the ending
+    for reading in [12, 15, 11] {
+        readings.push_back(reading)?;
+    }
+    println!("  2. Insertar al final\n    {readings:?}");
+    // 3. Insertion into a full buffer
+    if readings.push_back(18).is_err() {
+        println!("  3. Insertar en un buffer lleno: rechazado\n    {readings:?}");
+    }
+    // 4. Deletion at the beginning
+    let reading = readings.pop_front()?;
+    println!("  4. Eliminar el más antiguo: (lectura: {reading})\n    {readings:?}");
+    // 5. Overwriting the oldest element
+    readings.set_overwrite(true);
+    readings.push_back(18)?;
+    let overwritten = readings.push_back(20)?;
+    println!("  5. Sobrescribir el más antiguo: (descartado: {overwritten:?})\n    {readings:?}");
+    Ok(())
+}
+
+pub fn ad_implementation() -> Result<(), Exceptions> {
+    println!("Implementación de una cola doble sobre un array");
+    // 1. Initialization
+    let mut planets: ArrayDeque<&str> = ArrayDeque::new(2);
+    println!(
+        "  1. Inicialización: (capacidad: {})\n    {planets:?}",
+        planets.capacity()
+    );
+    // 2. Insertion at the ending
+    planets.push_back("Tierra");
+    planets.push_back("Marte");
+    println!("  2. Insertar al final\n    {planets:?}");
+    // 3. Insertion at the beginning
+    planets.push_front("Venus");
+    planets.push_front("Mercurio");
+    println!(
+        "  3. Insertar al inicio: (capacidad: {})\n    {planets:?}",
+        planets.capacity()
+    );
+    // 4. Deletion at the beginning
+    let planet = planets.pop_front()?;
+    println!("  4. Eliminar el primero: (planeta: {planet:?})\n    {planets:?}");
+    // 5. Deletion at the ending
+    let planet = planets.pop_back()?;
+    println!("  5. Eliminar el último: (planeta: {planet:?})\n    {planets:?}");
+    Ok(())
+}
+
+pub fn spa_implementation() -> Result<(), Exceptions> {
+    println!("Implementación de un array disperso");
+    // 1. Initialization
+    let mut distances: SparseArray<&str> = SparseArray::new(usize::MAX);
+    println!(
+        "  1. Inicialización: (longitud: {})\n    {distances:?}",
+        distances.len()
+    );
+    // 2. Assigning values at very large indices
+    distances.set(384_400, "Luna")?;
+    distances.set(149_597_870, "Sol")?;
+    distances.set(4_014_000_000_000, "Próxima Centauri")?;
+    println!("  2. Asignación de valores (km)\n    {distances:?}");
+    // 3. Deletion without shifting
+    let position: usize = 149_597_870;
+    let body = distances.remove(position)?;
+    println!(
+        "  3. Eliminar sin desplazar: (índice: {position}, cuerpo: {body:?})\n    {distances:?}"
+    );
+    // 4. Density statistics
+    println!(
+        "  4. Densidad: (ocupadas: {}, densidad: {:e})",
+        distances.populated(),
+        distances.density()
+    );
+    Ok(())
+}
+
+pub fn ba_implementation() -> Result<(), Exceptions> {
+    println!("Implementación de un array de bits");
+    // 1. Initialization
+    let limit: usize = 50;
+    let mut primes = BitArray::new(limit);
+    println!(
+        "  1. Inicialización: (bits: {})\n    {primes:?}",
+        primes.len()
+    );
+    // 2. Sieve of Eratosthenes
+    for index in 2..limit {
+        primes.set(index)?;
+    }
+    for index in 2..limit {
+        if primes.get(index)? {
+            for multiple in (index * index..limit).step_by(index) {
+                primes.clear(multiple)?;
+            }
+        }
+    }
+    let found: Vec<usize> = primes.iter_ones().collect();
+    println!(
+        "  2. Criba de Eratóstenes: (primos: {})\n    {found:?}",
+        primes.count_ones()
+    );
+    // 3. Bitwise operations
+    let mut odds = BitArray::new(limit);
+    for index in (1..limit).step_by(2) {
+        odds.set(index)?;
+    }
+    let odd_primes: Vec<usize> = (&primes & &odds).iter_ones().collect();
+    println!("  3.1 Primos impares (AND):\n    {odd_primes:?}");
+    let only_one: usize = (&primes ^ &odds).count_ones();
+    println!("  3.2 Primos o impares, pero no ambos (XOR): {only_one}");
+    Ok(())
+}
+
+pub fn gb_implementation() -> Result<(), Exceptions> {
+    println!("Implementación de un buffer con hueco");
+    // 1. Initialization
+    let mut text: GapBuffer<char> = GapBuffer::new(4);
+    println!(
+        "  1. Inicialización: (capacidad: {})\n    {text:?}",
+        text.capacity()
+    );
+    // 2. Typing at the cursor
+    for letter in "Hola mndo".chars() {
+        text.insert(letter);
+    }
+    print_text("  2. Escribir en el cursor", &text);
+    // 3. Moving the cursor
+    text.move_gap(6)?;
+    print_text("  3. Mover el cursor", &text);
+    // 4. Inserting at the cursor
+    text.insert('u');
+    print_text("  4. Insertar en el cursor", &text);
+    // 5. Deleting around the cursor
+    text.move_gap(0)?;
+    let deleted = text.delete()?;
+    text.insert('h');
+    print_text(
+        &format!("  5. Reemplazar el primer carácter (eliminado: {deleted:?})"),
+        &text,
+    );
+    Ok(())
+}
+
+fn print_text(title: &str, text: &GapBuffer<char>) {
+    let before: String = text.before_gap().collect();
+    let after: String = text.after_gap().collect();
+    println!(
+        "{title}: (cursor: {}, capacidad: {})\n    \"{before}|{after}\"",
+        text.cursor(),
+        text.capacity()
+    );
+}
@@ -0,0 +1,42 @@
+use std::slice::Iter;
+
+pub struct RingBufferIterator<'a, T: Clone> {
+    front: Iter<'a, Option<T>>,
+    back: Iter<'a, Option<T>>,
+}
+
+impl<'a, T: Clone> RingBufferIterator<'a, T> {
+    pub fn new(front: &'a [Option<T>], back: &'a [Option<T>]) -> Self {
+        Self {
+            front: front.iter(),
+            back: back.iter(),
+        }
+    }
+}
+
+impl<'a, T: Clone> Iterator for RingBufferIterator<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.front
+            .find_map(Option::as_ref)
+            .or_else(|| self.back.find_map(Option::as_ref))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front.len() + self.back.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for RingBufferIterator<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back
+            .by_ref()
+            .rev()
+            .find_map(Option::as_ref)
+            .or_else(|| self.front.by_ref().rev().find_map(Option::as_ref))
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for RingBufferIterator<'_, T> {}
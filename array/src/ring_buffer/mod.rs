@@ -0,0 +1,358 @@
+mod iterator;
+
+use exceptions::Exceptions;
+// `ArrayDeque` usa el mismo anillo de posiciones, por lo que comparte este iterador.
+pub(crate) use iterator::RingBufferIterator;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+
+#[derive(Clone)]
+pub struct RingBuffer<T: Clone> {
+    buffer: Box<[Option<T>]>,
+    head: usize,
+    tail: usize,
+    len: usize,
+    overwrite: bool,
+}
+
+impl<T: Clone> RingBuffer<T> {
+    /// Crea un nuevo buffer circular vacío con la capacidad especificada.
+    ///
+    /// # Parámetros
+    /// - `capacity`: La capacidad del buffer. Define el número máximo de elementos que puede contener al mismo tiempo.
+    ///
+    /// # Retornos
+    /// - Devuelve una nueva instancia de `RingBuffer` sin elementos, que rechaza nuevos elementos cuando está lleno.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::RingBuffer;
+    /// let buffer: RingBuffer<i32> = RingBuffer::new(3);
+    ///
+    /// assert_eq!(buffer.capacity(), 3);
+    /// assert!(buffer.is_empty());
+    /// assert!(!buffer.overwrites());
+    /// ```
+    ///
+    /// # Notas
+    /// - Para crear un buffer que sobrescriba el elemento más antiguo cuando está lleno, utiliza `with_overwrite`.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        let mut vec: Vec<Option<T>> = Vec::with_capacity(capacity);
+        vec.resize_with(capacity, || None);
+
+        Self {
+            buffer: vec.into_boxed_slice(),
+            head: 0,
+            tail: 0,
+            len: 0,
+            overwrite: false,
+        }
+    }
+
+    /// Crea un nuevo buffer circular vacío que, al estar lleno, sobrescribe el elemento más antiguo en cada inserción.
+    ///
+    /// # Parámetros
+    /// - `capacity`: La capacidad del buffer. Define el número máximo de elementos que puede contener al mismo tiempo.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::RingBuffer;
+    /// let mut buffer = RingBuffer::with_overwrite(2);
+    ///
+    /// assert_eq!(buffer.push_back(1), Ok(None));
+    /// assert_eq!(buffer.push_back(2), Ok(None));
+    /// // El buffer está lleno: el elemento más antiguo se descarta y se devuelve.
+    /// assert_eq!(buffer.push_back(3), Ok(Some(1)));
+    /// assert_eq!(buffer.iter().collect::<Vec<_>>(), vec![&2, &3]);
+    /// ```
+    #[must_use]
+    pub fn with_overwrite(capacity: usize) -> Self {
+        let mut buffer = Self::new(capacity);
+        buffer.overwrite = true;
+        buffer
+    }
+
+    /// Crea un nuevo buffer circular con la capacidad especificada y elementos iniciales.
+    ///
+    /// # Parámetros
+    /// - `capacity`: La capacidad del buffer.
+    /// - `values`: Un slice de valores que se utilizarán para inicializar el buffer, en orden de llegada.
+    ///
+    /// # Comportamiento
+    /// - Si `values` tiene más elementos que `capacity`, solo se toman los primeros `capacity`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::RingBuffer;
+    /// let buffer = RingBuffer::with_values(3, &[1, 2, 3, 4]);
+    ///
+    /// assert_eq!(buffer.len(), 3);
+    /// assert_eq!(buffer.front(), Ok(&1));
+    /// assert_eq!(buffer.back(), Ok(&3));
+    /// ```
+    #[must_use]
+    pub fn with_values(capacity: usize, values: &[T]) -> Self {
+        let mut buffer = Self::new(capacity);
+        for (slot, value) in buffer.buffer.iter_mut().zip(values) {
+            *slot = Some(value.clone());
+        }
+        buffer.len = values.len().min(capacity);
+        buffer.tail = buffer.wrap(buffer.len);
+        buffer
+    }
+
+    /// Obtiene una referencia inmutable al elemento en la posición especificada, contando desde el elemento más antiguo.
+    ///
+    /// # Parámetros
+    /// - `index`: La posición lógica del elemento. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::RingBuffer;
+    /// let mut buffer = RingBuffer::with_overwrite(3);
+    /// for value in 1..=4 {
+    ///     buffer.push_back(value).unwrap();
+    /// }
+    ///
+    /// // La posición 0 corresponde siempre al elemento más antiguo, sin importar dónde esté almacenado.
+    /// assert_eq!(buffer.get(0), Ok(&2));
+    /// assert_eq!(buffer.get(2), Ok(&4));
+    /// assert!(buffer.get(3).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - `index` es mayor o igual a `self.len`.
+    pub fn get(&self, index: usize) -> Result<&T, Exceptions> {
+        if index >= self.len {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        self.buffer[self.wrap(self.head + index)]
+            .as_ref()
+            .ok_or(Exceptions::IndexOutOfBounds)
+    }
+
+    /// Obtiene una referencia al elemento más antiguo del buffer, que será el próximo en salir con `pop_front`.
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - El buffer está vacío.
+    pub fn front(&self) -> Result<&T, Exceptions> {
+        self.get(0)
+    }
+
+    /// Obtiene una referencia al elemento más reciente del buffer.
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - El buffer está vacío.
+    pub fn back(&self) -> Result<&T, Exceptions> {
+        match self.len {
+            0 => Err(Exceptions::IndexOutOfBounds),
+            len => self.get(len - 1),
+        }
+    }
+
+    /// Agrega un elemento al final del buffer.
+    ///
+    /// # Parámetros
+    /// - `value`: El valor que se desea agregar.
+    ///
+    /// # Retornos
+    /// - `Ok(None)`: Si había espacio disponible y el valor se agregó.
+    /// - `Ok(Some(T))`: Si el buffer estaba lleno y sobrescribe elementos; devuelve el elemento más antiguo, que fue descartado.
+    /// - `Err(Exceptions::IndexOutOfBounds)`: Si el buffer estaba lleno y no sobrescribe elementos.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::RingBuffer;
+    /// let mut buffer = RingBuffer::new(2);
+    ///
+    /// assert_eq!(buffer.push_back(1), Ok(None));
+    /// assert_eq!(buffer.push_back(2), Ok(None));
+    /// assert!(buffer.push_back(3).is_err());
+    /// assert_eq!(buffer.len(), 2);
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - El buffer está lleno y no fue creado con `with_overwrite`.
+    /// - La capacidad del buffer es `0`.
+    ///
+    /// # Notas
+    /// - La operación tiene un costo constante (`O(1)`): los índices avanzan de forma circular y ningún elemento se desplaza.
+    pub fn push_back(&mut self, value: T) -> Result<Option<T>, Exceptions> {
+        if self.capacity() == 0 || (self.is_full() && !self.overwrite) {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+
+        let overwritten = self.buffer[self.tail].replace(value);
+        self.tail = self.wrap(self.tail + 1);
+        if overwritten.is_some() {
+            self.head = self.tail;
+        } else {
+            self.len += 1;
+        }
+        Ok(overwritten)
+    }
+
+    /// Elimina y devuelve el elemento más antiguo del buffer.
+    ///
+    /// # Retornos
+    /// - `Ok(T)`: Si el buffer no está vacío, devuelve el elemento más antiguo.
+    /// - `Err(Exceptions::IndexOutOfBounds)`: Si el buffer está vacío.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::RingBuffer;
+    /// let mut buffer = RingBuffer::with_values(3, &[1, 2]);
+    ///
+    /// assert_eq!(buffer.pop_front(), Ok(1));
+    /// assert_eq!(buffer.pop_front(), Ok(2));
+    /// assert!(buffer.pop_front().is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - El buffer está vacío.
+    ///
+    /// # Notas
+    /// - La operación tiene un costo constante (`O(1)`) y mueve el valor fuera del buffer sin clonarlo.
+    pub fn pop_front(&mut self) -> Result<T, Exceptions> {
+        if self.is_empty() {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+
+        let value = self.buffer[self.head]
+            .take()
+            .ok_or(Exceptions::IndexOutOfBounds)?;
+        self.head = self.wrap(self.head + 1);
+        self.len -= 1;
+        Ok(value)
+    }
+
+    /// Devuelve la cantidad de elementos almacenados actualmente en el buffer.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Devuelve la capacidad del buffer, es decir, el número máximo de elementos que puede contener.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Verifica si el buffer está vacío.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Verifica si el buffer está lleno.
+    #[must_use]
+    pub const fn is_full(&self) -> bool {
+        self.len == self.buffer.len()
+    }
+
+    /// Indica si el buffer sobrescribe el elemento más antiguo cuando está lleno.
+    #[must_use]
+    pub const fn overwrites(&self) -> bool {
+        self.overwrite
+    }
+
+    /// Establece si el buffer debe sobrescribir el elemento más antiguo cuando está lleno.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::RingBuffer;
+    /// let mut buffer = RingBuffer::with_values(1, &[1]);
+    /// assert!(buffer.push_back(2).is_err());
+    ///
+    /// buffer.set_overwrite(true);
+    /// assert_eq!(buffer.push_back(2), Ok(Some(1)));
+    /// ```
+    pub const fn set_overwrite(&mut self, overwrite: bool) {
+        self.overwrite = overwrite;
+    }
+
+    /// Devuelve un iterador que recorre por referencia los elementos del buffer, desde el más antiguo hasta el más reciente.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::RingBuffer;
+    /// let mut buffer = RingBuffer::with_overwrite(3);
+    /// for value in 1..=5 {
+    ///     buffer.push_back(value).unwrap();
+    /// }
+    ///
+    /// // Los elementos están almacenados como [4, 5, 3], pero se recorren en orden de llegada.
+    /// assert_eq!(buffer.iter().collect::<Vec<_>>(), vec![&3, &4, &5]);
+    /// assert_eq!(buffer.iter().rev().collect::<Vec<_>>(), vec![&5, &4, &3]);
+    /// ```
+    ///
+    /// # Notas
+    /// - Cuando los elementos dan la vuelta al final del almacenamiento, el iterador recorre primero el tramo `head..capacity` y luego el tramo `0..tail`.
+    #[must_use]
+    pub fn iter(&self) -> RingBufferIterator<'_, T> {
+        if self.head + self.len <= self.capacity() {
+            RingBufferIterator::new(&self.buffer[self.head..self.head + self.len], &[])
+        } else {
+            RingBufferIterator::new(&self.buffer[self.head..], &self.buffer[..self.tail])
+        }
+    }
+
+    const fn wrap(&self, index: usize) -> usize {
+        if index >= self.buffer.len() {
+            index - self.buffer.len()
+        } else {
+            index
+        }
+    }
+}
+
+impl<T: Clone> Default for RingBuffer<T> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<T: Clone, const N: usize> From<&[T; N]> for RingBuffer<T> {
+    fn from(values: &[T; N]) -> Self {
+        Self::with_values(N, values)
+    }
+}
+
+impl<T: Clone> From<&[T]> for RingBuffer<T> {
+    fn from(values: &[T]) -> Self {
+        Self::with_values(values.len(), values)
+    }
+}
+
+impl<T: Clone> From<Vec<T>> for RingBuffer<T> {
+    fn from(values: Vec<T>) -> Self {
+        Self::with_values(values.len(), values.as_slice())
+    }
+}
+
+impl<'a, T: Clone> IntoIterator for &'a RingBuffer<T> {
+    type Item = &'a T;
+    type IntoIter = RingBufferIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Clone + Debug> Debug for RingBuffer<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "[")?;
+        for (index, value) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{value:?}")?;
+        }
+        write!(f, "]")
+    }
+}
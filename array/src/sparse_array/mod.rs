@@ -0,0 +1,279 @@
+use exceptions::Exceptions;
+use hash_table::SeparateChainingHashTable;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+
+const INITIAL_BUCKETS: usize = 8;
+
+pub struct SparseArray<T: Clone> {
+    table: SeparateChainingHashTable<usize, T>,
+    len: usize,
+}
+
+impl<T: Clone> SparseArray<T> {
+    /// Crea un nuevo arreglo disperso con la longitud lógica especificada, sin ninguna posición ocupada.
+    ///
+    /// # Parámetros
+    /// - `len`: La longitud lógica del arreglo. Los índices válidos están en el rango `0..len` y puede ser tan grande como `usize::MAX`.
+    ///
+    /// # Retornos
+    /// - Devuelve una nueva instancia de `SparseArray` que no reserva memoria para las posiciones vacías.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::SparseArray;
+    /// let array: SparseArray<i32> = SparseArray::new(usize::MAX);
+    ///
+    /// assert_eq!(array.len(), usize::MAX);
+    /// assert_eq!(array.populated(), 0);
+    /// ```
+    ///
+    /// # Notas
+    /// - Solo las posiciones ocupadas se almacenan, en una `SeparateChainingHashTable` cuya clave es el índice.
+    #[must_use]
+    pub fn new(len: usize) -> Self {
+        Self {
+            table: SeparateChainingHashTable::new(INITIAL_BUCKETS),
+            len,
+        }
+    }
+
+    /// Obtiene una referencia inmutable al elemento en el índice especificado.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del elemento que se desea obtener. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::SparseArray;
+    /// # use exceptions::Exceptions;
+    /// let mut array = SparseArray::new(1_000_000_000);
+    /// array.set(999_999_999, "último")?;
+    ///
+    /// assert_eq!(array.get(999_999_999), Ok(&"último"));
+    /// assert_eq!(array.get(0), Err(Exceptions::KeyNotInitialized));
+    /// assert_eq!(array.get(1_000_000_000), Err(Exceptions::IndexOutOfBounds));
+    /// # Ok::<(), Exceptions>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::IndexOutOfBounds` si `index` es mayor o igual a `self.len`.
+    /// - `Exceptions::KeyNotInitialized` si la posición `index` está vacía.
+    pub fn get(&self, index: usize) -> Result<&T, Exceptions> {
+        self.check_index(index)?;
+        self.table.get(&index)
+    }
+
+    /// Obtiene una referencia mutable al elemento en el índice especificado.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del elemento que se desea obtener. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::IndexOutOfBounds` si `index` es mayor o igual a `self.len`.
+    /// - `Exceptions::KeyNotInitialized` si la posición `index` está vacía.
+    pub fn get_mut(&mut self, index: usize) -> Result<&mut T, Exceptions> {
+        self.check_index(index)?;
+        self.table.get_mut(&index)
+    }
+
+    /// Establece un valor en el índice especificado, ocupando la posición si estaba vacía o reemplazando su valor anterior.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice en el que se desea establecer el valor. Debe estar en el rango `0..self.len`.
+    /// - `value`: El valor que se desea asignar.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::SparseArray;
+    /// # use exceptions::Exceptions;
+    /// let mut array = SparseArray::new(100);
+    ///
+    /// array.set(42, 1)?;
+    /// array.set(42, 2)?;
+    /// assert_eq!(array.get(42), Ok(&2));
+    /// assert_eq!(array.populated(), 1);
+    /// assert!(array.set(100, 3).is_err());
+    /// # Ok::<(), Exceptions>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - `index` es mayor o igual a `self.len`.
+    ///
+    /// # Notas
+    /// - Cuando el factor de carga supera su máximo, la tabla interna duplica sus buckets por sí sola para mantener las cadenas cortas.
+    pub fn set(&mut self, index: usize, value: T) -> Result<(), Exceptions> {
+        self.check_index(index)?;
+        self.table.put(index, value);
+        Ok(())
+    }
+
+    /// Elimina el elemento en el índice especificado y devuelve su valor, dejando la posición vacía.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del elemento que se desea eliminar. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::SparseArray;
+    /// # use exceptions::Exceptions;
+    /// let mut array = SparseArray::new(100);
+    /// array.set(7, "siete")?;
+    ///
+    /// assert_eq!(array.remove(7), Ok("siete"));
+    /// assert_eq!(array.remove(7), Err(Exceptions::KeyNotInitialized));
+    /// assert_eq!(array.len(), 100); // La longitud lógica no cambia.
+    /// # Ok::<(), Exceptions>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::IndexOutOfBounds` si `index` es mayor o igual a `self.len`.
+    /// - `Exceptions::KeyNotInitialized` si la posición `index` ya estaba vacía.
+    ///
+    /// # Notas
+    /// - A diferencia de `StaticArray::remove`, los elementos posteriores no se desplazan: cada valor conserva su índice.
+    pub fn remove(&mut self, index: usize) -> Result<T, Exceptions> {
+        self.check_index(index)?;
+        self.table.remove(&index)
+    }
+
+    /// Devuelve la longitud lógica del arreglo, incluyendo las posiciones vacías.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Verifica si la longitud lógica del arreglo es `0`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::SparseArray;
+    /// let empty: SparseArray<i32> = SparseArray::new(0);
+    /// let unpopulated: SparseArray<i32> = SparseArray::new(1_000);
+    ///
+    /// assert!(empty.is_empty());
+    /// // Un arreglo sin valores pero con longitud lógica no está vacío; para eso existe `has_no_values`.
+    /// assert!(!unpopulated.is_empty());
+    /// assert!(unpopulated.has_no_values());
+    /// ```
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Verifica si el arreglo no tiene ninguna posición ocupada.
+    #[must_use]
+    pub const fn has_no_values(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    /// Devuelve la cantidad de posiciones ocupadas.
+    #[must_use]
+    pub const fn populated(&self) -> usize {
+        self.table.entries_len()
+    }
+
+    /// Calcula la densidad del arreglo: la proporción de posiciones ocupadas respecto a la longitud lógica.
+    ///
+    /// # Retornos
+    /// - Un valor entre `0.0` y `1.0`. Un arreglo de longitud `0` tiene densidad `0.0`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::SparseArray;
+    /// # use exceptions::Exceptions;
+    /// let mut array = SparseArray::new(1_000);
+    /// for index in (0..1_000).step_by(100) {
+    ///     array.set(index, index)?;
+    /// }
+    ///
+    /// assert_eq!(array.populated(), 10);
+    /// assert!((array.density() - 0.01).abs() < f64::EPSILON);
+    /// # Ok::<(), Exceptions>(())
+    /// ```
+    ///
+    /// # Notas
+    /// - Mientras menor sea la densidad, mayor es el ahorro de memoria frente a un `StaticArray` de la misma longitud, que reserva todas sus posiciones.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn density(&self) -> f64 {
+        if self.len == 0 {
+            return 0.0;
+        }
+        self.populated() as f64 / self.len as f64
+    }
+
+    /// Devuelve los índices ocupados, ordenados de menor a mayor.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::SparseArray;
+    /// # use exceptions::Exceptions;
+    /// let mut array = SparseArray::new(usize::MAX);
+    /// array.set(1 << 40, 'b')?;
+    /// array.set(3, 'a')?;
+    ///
+    /// assert_eq!(array.indices(), vec![3, 1 << 40]);
+    /// # Ok::<(), Exceptions>(())
+    /// ```
+    #[must_use]
+    pub fn indices(&self) -> Vec<usize> {
+        let mut indices: Vec<usize> = self.table.get_keys().into_iter().copied().collect();
+        indices.sort_unstable();
+        indices
+    }
+
+    /// Devuelve los pares `(índice, valor)` de las posiciones ocupadas, ordenados por índice.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::SparseArray;
+    /// # use exceptions::Exceptions;
+    /// let mut array = SparseArray::new(10);
+    /// array.set(9, "nueve")?;
+    /// array.set(2, "dos")?;
+    ///
+    /// assert_eq!(array.entries(), vec![(2, &"dos"), (9, &"nueve")]);
+    /// # Ok::<(), Exceptions>(())
+    /// ```
+    #[must_use]
+    pub fn entries(&self) -> Vec<(usize, &T)> {
+        let mut entries: Vec<(usize, &T)> = self
+            .table
+            .get_entries()
+            .into_iter()
+            .map(|(index, value)| (*index, value))
+            .collect();
+        entries.sort_unstable_by_key(|(index, _)| *index);
+        entries
+    }
+
+    const fn check_index(&self, index: usize) -> Result<(), Exceptions> {
+        if index >= self.len {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        Ok(())
+    }
+}
+
+impl<T: Clone> Default for SparseArray<T> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<T: Clone + Debug> Debug for SparseArray<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "{{")?;
+        for (position, (index, value)) in self.entries().into_iter().enumerate() {
+            if position > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{index}: {value:?}")?;
+        }
+        write!(f, "}}")
+    }
+}
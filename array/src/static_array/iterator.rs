@@ -0,0 +1,98 @@
+use std::slice::{Iter, IterMut};
+use std::vec::IntoIter;
+
+pub struct StaticArrayIterator<'a, T: Clone> {
+    slots: Iter<'a, T>,
+}
+
+impl<'a, T: Clone> StaticArrayIterator<'a, T> {
+    pub fn new(slots: &'a [T]) -> Self {
+        Self {
+            slots: slots.iter(),
+        }
+    }
+}
+
+impl<'a, T: Clone> Iterator for StaticArrayIterator<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.slots.len(), Some(self.slots.len()))
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for StaticArrayIterator<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.slots.next_back()
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for StaticArrayIterator<'_, T> {}
+
+pub struct StaticArrayIterMut<'a, T: Clone> {
+    slots: IterMut<'a, T>,
+}
+
+impl<'a, T: Clone> StaticArrayIterMut<'a, T> {
+    pub fn new(slots: &'a mut [T]) -> Self {
+        Self {
+            slots: slots.iter_mut(),
+        }
+    }
+}
+
+impl<'a, T: Clone> Iterator for StaticArrayIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.slots.len(), Some(self.slots.len()))
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for StaticArrayIterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.slots.next_back()
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for StaticArrayIterMut<'_, T> {}
+
+pub struct StaticArrayIntoIter<T: Clone> {
+    slots: IntoIter<T>,
+}
+
+impl<T: Clone> StaticArrayIntoIter<T> {
+    pub fn new(slots: Vec<T>) -> Self {
+        Self {
+            slots: slots.into_iter(),
+        }
+    }
+}
+
+impl<T: Clone> Iterator for StaticArrayIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.slots.len(), Some(self.slots.len()))
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for StaticArrayIntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.slots.next_back()
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for StaticArrayIntoIter<T> {}
@@ -1,12 +1,21 @@
+mod iterator;
+mod overflow_policy;
+
 use exceptions::Exceptions;
+use iterator::{StaticArrayIntoIter, StaticArrayIterMut, StaticArrayIterator};
+pub use overflow_policy::OverflowPolicy;
 use std::fmt::{Debug, Formatter, Result as fmtResult};
+use std::hash::{Hash, Hasher};
+use std::iter::Sum;
+use std::mem::{self, ManuallyDrop, MaybeUninit};
+use std::ops::{Index, IndexMut};
+use std::{ptr, slice};
 
-#[derive(Clone)]
 pub struct StaticArray<T: Clone> {
-    array: Box<[Option<T>]>,
+    array: Box<[MaybeUninit<T>]>,
     len: usize,
     capacity: usize,
-    current: usize,
+    policy: OverflowPolicy,
 }
 
 impl<T: Clone> StaticArray<T> {
@@ -30,20 +39,14 @@ impl<T: Clone> StaticArray<T> {
     /// ```
     ///
     /// # Notas
-    /// - El arreglo estático se inicializa con `None` en cada posición, lo que ocupa espacio en memoria según la capacidad especificada.
+    /// - Se reserva memoria para `capacity` elementos, pero ninguna posición se inicializa hasta que se le asigna un valor.
     /// - Para agregar elementos, utiliza métodos como `push` o `unshift`.
     pub fn new(capacity: usize) -> Self {
-        let mut vec: Vec<Option<T>> = Vec::with_capacity(capacity);
-        for _ in 0..capacity {
-            vec.push(None);
-        }
-        let array: Box<[Option<T>]> = vec.into_boxed_slice();
-
         Self {
-            array,
+            array: Box::new_uninit_slice(capacity),
             len: 0,
             capacity,
-            current: 0,
+            policy: OverflowPolicy::default(),
         }
     }
 
@@ -57,7 +60,7 @@ impl<T: Clone> StaticArray<T> {
     /// - Devuelve una nueva instancia de `StaticArray` inicializada con los valores proporcionados.
     ///
     /// # Comportamiento
-    /// - Si la longitud de `values` es menor que `capacity`, los valores restantes del arreglo se inicializan como `None`.
+    /// - Si la longitud de `values` es menor que `capacity`, las posiciones restantes quedan libres.
     /// - Si la longitud de `values` es mayor o igual a `capacity`, solo se toman los primeros `capacity` elementos del slice.
     ///
     /// # Ejemplo
@@ -91,21 +94,40 @@ impl<T: Clone> StaticArray<T> {
             capacity
         };
 
-        let mut vec: Vec<Option<T>> = Vec::with_capacity(capacity);
-        for index in 0..capacity {
-            match values.get(index) {
-                Some(value) => vec.push(Some(value.to_owned())),
-                None => vec.push(None),
-            }
+        let mut array = Self::new(capacity);
+        for value in &values[..size] {
+            array.array[array.len].write(value.clone());
+            array.len += 1;
         }
-        let array: Box<[Option<T>]> = vec.into_boxed_slice();
+        array
+    }
 
-        Self {
-            array,
-            len: size,
-            capacity,
-            current: 0,
-        }
+    /// Crea un nuevo arreglo estático vacío con la capacidad y la política de desbordamiento especificadas.
+    ///
+    /// # Parámetros
+    /// - `capacity`: La capacidad del arreglo estático.
+    /// - `policy`: La política que aplican `push`, `unshift` e `insert` cuando el arreglo está lleno.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::{OverflowPolicy, StaticArray};
+    /// let mut array = StaticArray::with_policy(2, OverflowPolicy::OverwriteEnd);
+    /// array.push(1).unwrap();
+    /// array.push(2).unwrap();
+    ///
+    /// // El arreglo está lleno: el nuevo elemento reemplaza al último.
+    /// array.push(3).unwrap();
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&1, &3]);
+    /// assert_eq!(array.policy(), OverflowPolicy::OverwriteEnd);
+    /// ```
+    ///
+    /// # Notas
+    /// - `new`, `with_values` y las conversiones `From` utilizan `OverflowPolicy::Reject`.
+    #[must_use]
+    pub fn with_policy(capacity: usize, policy: OverflowPolicy) -> Self {
+        let mut array = Self::new(capacity);
+        array.policy = policy;
+        array
     }
 
     /// Obtiene una referencia inmutable al elemento en el índice especificado.
@@ -129,13 +151,10 @@ impl<T: Clone> StaticArray<T> {
     /// # Errors
     /// Este método retornará `Exceptions::IndexOutOfBounds` si:
     /// - `index` es mayor o igual a `self.len`.
-    /// - No hay un valor presente en el índice especificado.
     pub fn get(&self, index: usize) -> Result<&T, Exceptions> {
-        match (index, self.array.get(index)) {
-            (i, _) if i >= self.len => Err(Exceptions::IndexOutOfBounds),
-            (_, None) => Err(Exceptions::IndexOutOfBounds),
-            (_, Some(value)) => value.as_ref().ok_or(Exceptions::IndexOutOfBounds),
-        }
+        self.as_slice()
+            .get(index)
+            .ok_or(Exceptions::IndexOutOfBounds)
     }
 
     /// Obtiene una referencia mutable al elemento en el índice especificado.
@@ -161,13 +180,10 @@ impl<T: Clone> StaticArray<T> {
     /// # Errors
     /// Este método retornará `Exceptions::IndexOutOfBounds` si:
     /// - `index` es mayor o igual a `self.len`.
-    /// - No hay un valor presente en el índice especificado.
     pub fn get_mut(&mut self, index: usize) -> Result<&mut T, Exceptions> {
-        match (index, self.array.get_mut(index)) {
-            (i, _) if i >= self.len => Err(Exceptions::IndexOutOfBounds),
-            (_, None) => Err(Exceptions::IndexOutOfBounds),
-            (_, Some(value)) => value.as_mut().ok_or(Exceptions::IndexOutOfBounds),
-        }
+        self.as_mut_slice()
+            .get_mut(index)
+            .ok_or(Exceptions::IndexOutOfBounds)
     }
 
     /// Establece un valor en el índice especificado del arreglo estático.
@@ -200,10 +216,7 @@ impl<T: Clone> StaticArray<T> {
     /// Esta función no modifica la capacidad del arreglo. Para añadir nuevos valores
     /// fuera del rango actual, utiliza métodos como `unshift` o `push`.
     pub fn set(&mut self, index: usize, value: T) -> Result<(), Exceptions> {
-        if index >= self.len {
-            return Err(Exceptions::IndexOutOfBounds);
-        }
-        self.array[index] = Some(value);
+        *self.get_mut(index)? = value;
         Ok(())
     }
 
@@ -212,53 +225,48 @@ impl<T: Clone> StaticArray<T> {
     /// # Parámetros
     /// - `value`: El valor que se desea insertar al inicio del arreglo.
     ///
+    /// # Retornos
+    /// - `Ok(())`: Si el elemento se inserta correctamente.
+    /// - `Err(Exceptions::IndexOutOfBounds)`: Si el arreglo está lleno y su política es `OverflowPolicy::Reject`.
+    ///
     /// # Comportamiento
     /// - Si la longitud actual (`len`) es menor que la capacidad (`capacity`), el nuevo elemento se inserta en el índice `0`, y los elementos existentes se desplazan una posición hacia la derecha.
-    /// - Si la longitud actual es igual a la capacidad, el último elemento se descarta para hacer espacio al nuevo valor.
+    /// - Si el arreglo está lleno, se aplica su `OverflowPolicy`: `Reject` retorna un error, `OverwriteEnd` descarta el último elemento y `DropOldest` reemplaza el primero.
     /// - Este método no redimensiona el arreglo, ya que `StaticArray` tiene una capacidad fija.
     ///
     /// # Ejemplo
     /// ```
-    /// # use array::StaticArray;
+    /// # use array::{OverflowPolicy, StaticArray};
     /// let mut array = StaticArray::with_values(3, &[1, 2, 3]);
     ///
-    /// assert_eq!(array.len(), array.capacity());
-    ///
-    /// // Inserta el valor 0 al inicio.
-    /// array.unshift(0);
+    /// // Con la política por defecto, un arreglo lleno rechaza el elemento.
+    /// assert!(array.unshift(0).is_err());
     ///
-    /// // El arreglo ahora es [0, 1, 2], el último elemento (3) fue descartado.
-    /// assert_eq!(array.get(0), Ok(&0));
-    /// assert_eq!(array.get(1), Ok(&1));
-    /// assert_eq!(array.len(), 3);
-    /// assert_eq!(array.len(), array.capacity());
+    /// // Con `OverwriteEnd`, el último elemento se descarta.
+    /// let mut array = StaticArray::with_policy(3, OverflowPolicy::OverwriteEnd);
+    /// for value in [1, 2, 3] {
+    ///     array.push(value).unwrap();
+    /// }
+    /// assert_eq!(array.unshift(0), Ok(()));
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&0, &1, &2]);
     ///
     /// // Inserta en un arreglo parcialmente lleno.
     /// let mut array = StaticArray::new(5);
-    /// array.unshift(42);
+    /// assert_eq!(array.unshift(42), Ok(()));
     /// assert_eq!(array.len(), 1);
     /// assert_eq!(array.get(0), Ok(&42));
     /// ```
     ///
+    /// # Errors
+    /// Este método retornará `Exceptions::IndexOutOfBounds` si:
+    /// - El arreglo está lleno y su política es `OverflowPolicy::Reject`.
+    /// - La capacidad del arreglo es `0`.
+    ///
     /// # Notas
     /// - El desplazamiento de elementos tiene un costo proporcional a la longitud actual del arreglo (`O(n)`), por lo que es menos eficiente que agregar al final (`push`).
     /// - El arreglo no se expande dinámicamente.
-    /// - Si el arreglo está lleno, el último elemento se descarta para mantener la capacidad fija.
-    pub fn unshift(&mut self, value: T) {
-        let size: usize = if self.len + 1 < self.capacity {
-            self.len + 1
-        } else {
-            self.capacity
-        };
-
-        let arr = &self.array.clone();
-        for i in (1..size).rev() {
-            self.array[i].clone_from(&arr[i - 1]);
-        }
-        self.array[0] = Some(value);
-        if self.len < self.capacity {
-            self.len += 1;
-        }
+    pub fn unshift(&mut self, value: T) -> Result<(), Exceptions> {
+        self.place(0, value)
     }
 
     /// Agrega un elemento al final del arreglo estático.
@@ -268,10 +276,10 @@ impl<T: Clone> StaticArray<T> {
     ///
     /// # Retornos
     /// - `Ok(())`: Si el elemento se agrega correctamente al arreglo.
-    /// - `Err(Exceptions::IndexOutOfBounds)`: Si no hay espacio disponible en el arreglo para agregar el nuevo elemento.
+    /// - `Err(Exceptions::IndexOutOfBounds)`: Si el arreglo está lleno y su política es `OverflowPolicy::Reject`.
     ///
     /// # Comportamiento
-    /// - El método no redimensiona el arreglo. Si la longitud actual (`len`) es igual a la capacidad (`capacity`), se retorna un error.
+    /// - El método no redimensiona el arreglo. Si la longitud actual (`len`) es igual a la capacidad (`capacity`), se aplica su `OverflowPolicy`: `Reject` retorna un error, `OverwriteEnd` reemplaza el último elemento y `DropOldest` descarta el primero.
     /// - El nuevo elemento se almacena al final del arreglo y la longitud (`len`) se incrementa en `1`.
     ///
     /// # Ejemplo
@@ -295,17 +303,13 @@ impl<T: Clone> StaticArray<T> {
     ///
     /// # Errors
     /// Este método retornará `Exceptions::IndexOutOfBounds` si:
-    /// - La longitud actual del arreglo (`len`) es igual a su capacidad máxima (`capacity`).
+    /// - La longitud actual del arreglo (`len`) es igual a su capacidad máxima (`capacity`) y su política es `OverflowPolicy::Reject`.
+    /// - La capacidad del arreglo es `0`.
     ///
     /// # Notas
     /// - Este método no ajusta la capacidad del arreglo automáticamente.
     pub fn push(&mut self, value: T) -> Result<(), Exceptions> {
-        if self.len == self.capacity {
-            return Err(Exceptions::IndexOutOfBounds);
-        }
-        self.array[self.len] = Some(value);
-        self.len += 1;
-        Ok(())
+        self.place(self.len, value)
     }
 
     /// Inserta un valor en el índice especificado del arreglo estático, desplazando los elementos existentes.
@@ -321,52 +325,45 @@ impl<T: Clone> StaticArray<T> {
     /// # Comportamiento
     /// - Si la longitud actual (`len`) es menor que la capacidad (`capacity`), el nuevo elemento se inserta, y los elementos existentes se desplazan una posición hacia la derecha.
     /// - Los elementos desde el índice especificado hasta el final se desplazan una posición hacia la derecha.
-    /// - Si la longitud actual es igual a la capacidad, el último elemento se descarta para hacer espacio al nuevo valor.
+    /// - Si la longitud actual es igual a la capacidad, se aplica su `OverflowPolicy`: `Reject` retorna un error, `OverwriteEnd` descarta el último elemento y `DropOldest` descarta el primero, por lo que el valor queda una posición antes de `index`.
     /// - Este método no redimensiona el arreglo, ya que tiene una capacidad fija.
     ///
     /// # Ejemplo
     /// ```
-    /// # use array::StaticArray;
-    /// # use exceptions::Exceptions;
-    /// let mut array = StaticArray::with_values(3, &[1, 2, 3]);
+    /// # use array::{OverflowPolicy, StaticArray};
+    /// let mut array = StaticArray::with_values(4, &[1, 2, 3]);
     ///
     /// // Inserta el valor 42 en el índice 1.
     /// assert_eq!(array.insert(1, 42), Ok(()));
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&1, &42, &2, &3]);
     ///
-    /// // El arreglo ahora es [1, 42, 2].
-    /// assert_eq!(array.get(1), Ok(&42));
-    /// assert_eq!(array.get(2), Ok(&2));
-    /// assert_eq!(array.len(), 3);
+    /// // El arreglo está lleno y la política por defecto rechaza el elemento.
+    /// assert!(array.insert(1, 10).is_err());
     ///
     /// // Intentar insertar fuera de los límites retorna un error.
     /// assert!(array.insert(5, 10).is_err());
+    ///
+    /// // Con `OverwriteEnd`, el último elemento se descarta.
+    /// let mut array = StaticArray::with_policy(3, OverflowPolicy::OverwriteEnd);
+    /// for value in [1, 2, 3] {
+    ///     array.push(value).unwrap();
+    /// }
+    /// assert_eq!(array.insert(1, 42), Ok(()));
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&1, &42, &2]);
     /// ```
     ///
     /// # Errors
     /// Este método retornará `Exceptions::IndexOutOfBounds` si:
     /// - `index` es mayor o igual a `self.len`.
+    /// - El arreglo está lleno y su política es `OverflowPolicy::Reject`.
     ///
     /// # Notas
-    /// - Este método no puede modificar la capacidad del arreglo estático, eliminará el último elemento si no hay espacio suficiente para insertar el nuevo elemento.
+    /// - Este método no puede modificar la capacidad del arreglo estático.
     pub fn insert(&mut self, index: usize, value: T) -> Result<(), Exceptions> {
         if index >= self.len {
             return Err(Exceptions::IndexOutOfBounds);
         }
-        let size: usize = if self.len + 1 < self.capacity {
-            self.len + 1
-        } else {
-            self.capacity
-        };
-
-        let arr = &self.array.clone();
-        for i in ((index + 1)..size).rev() {
-            self.array[i].clone_from(&arr[i - 1]);
-        }
-        self.array[index] = Some(value);
-        if self.len < self.capacity {
-            self.len += 1;
-        }
-        Ok(())
+        self.place(index, value)
     }
 
     /// Elimina y devuelve el primer elemento del arreglo estático, desplazando los elementos restantes hacia la izquierda.
@@ -403,20 +400,7 @@ impl<T: Clone> StaticArray<T> {
     /// # Notas
     /// - El desplazamiento de elementos tiene un costo proporcional a la longitud actual del arreglo (`O(n)`), por lo que no es eficiente para usos repetidos en arreglos grandes.
     pub fn shift(&mut self) -> Result<T, Exceptions> {
-        if self.is_empty() {
-            return Err(Exceptions::IndexOutOfBounds);
-        }
-
-        let Some(value) = self.array[0].clone() else {
-            return Err(Exceptions::IndexOutOfBounds);
-        };
-        let slice = &self.array.clone()[1..self.len];
-        for (i, v) in slice.iter().enumerate() {
-            self.array[i].clone_from(v);
-        }
-        self.array[self.len - 1] = None;
-        self.len -= 1;
-        Ok(value)
+        self.remove(0)
     }
 
     /// Elimina y devuelve el último elemento del arreglo estático.
@@ -427,7 +411,7 @@ impl<T: Clone> StaticArray<T> {
     ///
     /// # Comportamiento
     /// - El último elemento del arreglo estático se elimina y se devuelve.
-    /// - La posición donde estaba el elemento eliminado se establece como `None`.
+    /// - La posición donde estaba el elemento eliminado queda libre.
     ///
     /// # Ejemplo
     /// ```
@@ -457,11 +441,9 @@ impl<T: Clone> StaticArray<T> {
             return Err(Exceptions::IndexOutOfBounds);
         }
 
-        let Some(value) = self.array[self.len - 1].clone() else {
-            return Err(Exceptions::IndexOutOfBounds);
-        };
-        self.array[self.len - 1] = None;
         self.len -= 1;
+        // SAFETY: la posición `self.len` estaba inicializada y, al reducir la longitud, deja de considerarse ocupada.
+        let value = unsafe { self.array[self.len].assume_init_read() };
         Ok(value)
     }
 
@@ -497,24 +479,49 @@ impl<T: Clone> StaticArray<T> {
     /// Este método retornará `Exceptions::IndexOutOfBounds` si:
     /// - `index` es mayor o igual a `self.len`.
     pub fn remove(&mut self, index: usize) -> Result<T, Exceptions> {
-        let value = match (index, self.array.get(index)) {
-            (i, _) if i >= self.len => return Err(Exceptions::IndexOutOfBounds),
-            (_, None) => return Err(Exceptions::IndexOutOfBounds),
-            (_, Some(value)) => match value {
-                Some(v) => v.clone(),
-                None => return Err(Exceptions::IndexOutOfBounds),
-            },
-        };
-
-        let slice = &self.array.clone()[(index + 1)..self.len];
-        for (i, v) in slice.iter().enumerate() {
-            self.array[index + i].clone_from(v);
+        if index >= self.len {
+            return Err(Exceptions::IndexOutOfBounds);
         }
-        self.array[self.len - 1] = None;
+
+        let base = self.array.as_mut_ptr();
+        // SAFETY: `index < self.len`, así que la posición está inicializada; tras leerla, el desplazamiento de `index + 1..self.len` una posición a la izquierda la cubre.
+        let value = unsafe {
+            let value = (*base.add(index)).assume_init_read();
+            ptr::copy(base.add(index + 1), base.add(index), self.len - index - 1);
+            value
+        };
         self.len -= 1;
         Ok(value)
     }
 
+    /// Invierte el orden de los elementos del arreglo estático en el mismo lugar.
+    ///
+    /// # Comportamiento
+    /// - Intercambia las posiciones de ambos extremos, avanzando hacia el centro, hasta recorrer la mitad de los elementos.
+    /// - La capacidad libre no se modifica.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::StaticArray;
+    /// let mut array = StaticArray::with_values(5, &[1, 2, 3, 4]);
+    ///
+    /// array.reverse();
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&4, &3, &2, &1]);
+    /// assert_eq!(array.capacity(), 5);
+    /// ```
+    ///
+    /// # Notas
+    /// - La operación tiene un costo lineal (`O(n)`) y no clona ni reserva memoria: los valores solo se mueven de posición.
+    pub fn reverse(&mut self) {
+        self.as_mut_slice().reverse();
+    }
+
+    /// Devuelve la política de desbordamiento del arreglo estático.
+    #[must_use]
+    pub const fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+
     /// Devuelve la cantidad de elementos almacenados actualmente en el arreglo estático.
     ///
     /// # Retornos
@@ -522,7 +529,7 @@ impl<T: Clone> StaticArray<T> {
     ///
     /// # Comportamiento
     /// - La longitud (`len`) representa el número de elementos válidos almacenados en el arreglo, no la capacidad total.
-    /// - Los espacios vacíos (inicializados como `None`) no se cuentan como parte de la longitud.
+    /// - Las posiciones libres de la capacidad, que no están inicializadas, no se cuentan como parte de la longitud.
     ///
     /// # Ejemplo
     /// ```
@@ -614,6 +621,276 @@ impl<T: Clone> StaticArray<T> {
     pub const fn is_empty(&self) -> bool {
         self.len == 0
     }
+
+    /// Devuelve un iterador que recorre por referencia los elementos del arreglo estático, desde el índice `0` hasta `len - 1`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::StaticArray;
+    /// let array = StaticArray::with_values(5, &[1, 2, 3]);
+    ///
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// assert_eq!(array.iter().rev().next(), Some(&3));
+    ///
+    /// // Recorrer el arreglo no lo modifica ni lo consume.
+    /// for value in &array {
+    ///     assert!(*value > 0);
+    /// }
+    /// assert_eq!(array.len(), 3);
+    /// ```
+    ///
+    /// # Notas
+    /// - Solo se recorren las posiciones inicializadas; la capacidad libre no se visita.
+    #[must_use]
+    pub fn iter(&self) -> StaticArrayIterator<'_, T> {
+        StaticArrayIterator::new(self.as_slice())
+    }
+
+    /// Devuelve un iterador que recorre los elementos del arreglo estático por referencia mutable, desde el índice `0` hasta `len - 1`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::StaticArray;
+    /// let mut array = StaticArray::with_values(5, &[1, 2, 3]);
+    ///
+    /// // Duplicar cada valor sin llamar a `get_mut` por índice.
+    /// for value in array.iter_mut() {
+    ///     *value *= 2;
+    /// }
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&2, &4, &6]);
+    ///
+    /// // `&mut array` también puede recorrerse directamente.
+    /// for value in &mut array {
+    ///     *value += 1;
+    /// }
+    /// assert_eq!(array.get(0), Ok(&3));
+    /// ```
+    ///
+    /// # Notas
+    /// - Solo se recorren las posiciones inicializadas; la longitud y la capacidad no cambian.
+    pub fn iter_mut(&mut self) -> StaticArrayIterMut<'_, T> {
+        StaticArrayIterMut::new(self.as_mut_slice())
+    }
+
+    /// Devuelve una referencia al primer elemento que cumple el predicado.
+    ///
+    /// # Parámetros
+    /// - `predicate`: La condición que debe cumplir el elemento buscado.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::StaticArray;
+    /// let array = StaticArray::from(&[3, 8, 5, 10]);
+    ///
+    /// assert_eq!(array.find(|value| value % 2 == 0), Some(&8));
+    /// assert_eq!(array.find(|value| *value > 10), None);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`) y se detiene en la primera coincidencia.
+    pub fn find<F: FnMut(&T) -> bool>(&self, mut predicate: F) -> Option<&T> {
+        self.iter().find(|value| predicate(value))
+    }
+
+    /// Devuelve el índice del primer elemento que cumple el predicado.
+    ///
+    /// # Parámetros
+    /// - `predicate`: La condición que debe cumplir el elemento buscado.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::StaticArray;
+    /// let array = StaticArray::from(&["Venus", "Tierra", "Marte"]);
+    ///
+    /// assert_eq!(array.position(|planet| planet.starts_with('T')), Some(1));
+    /// assert_eq!(array.position(|planet| planet.is_empty()), None);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`) y se detiene en la primera coincidencia.
+    pub fn position<F: FnMut(&T) -> bool>(&self, predicate: F) -> Option<usize> {
+        self.iter().position(predicate)
+    }
+
+    /// Combina todos los elementos del arreglo estático en un único valor, aplicando una función acumuladora de izquierda a derecha.
+    ///
+    /// # Parámetros
+    /// - `init`: El valor inicial del acumulador.
+    /// - `f`: Una función que recibe el acumulador y una referencia a cada elemento, y devuelve el nuevo acumulador.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::StaticArray;
+    /// let array = StaticArray::with_values(5, &[3, 1, 4]);
+    ///
+    /// let digits = array.fold(String::new(), |text, value| text + &value.to_string());
+    /// assert_eq!(digits, "314");
+    /// ```
+    ///
+    /// # Notas
+    /// - Solo se recorren las posiciones inicializadas. Un arreglo vacío devuelve `init`.
+    pub fn fold<B, F: FnMut(B, &T) -> B>(&self, init: B, f: F) -> B {
+        self.iter().fold(init, f)
+    }
+
+    fn as_slice(&self) -> &[T] {
+        // SAFETY: las posiciones `0..self.len` están inicializadas y `MaybeUninit<T>` tiene la misma representación en memoria que `T`.
+        unsafe { slice::from_raw_parts(self.array.as_ptr().cast::<T>(), self.len) }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: igual que en `as_slice`; el préstamo mutable de `self` garantiza el acceso exclusivo.
+        unsafe { slice::from_raw_parts_mut(self.array.as_mut_ptr().cast::<T>(), self.len) }
+    }
+
+    fn into_vec(mut self) -> Vec<T> {
+        let len = self.len;
+        // Con la longitud en `0`, `Drop` no libera los elementos que pasan al vector.
+        self.len = 0;
+        let mut slots = ManuallyDrop::new(mem::take(&mut self.array).into_vec());
+        // SAFETY: `MaybeUninit<T>` tiene la misma representación que `T`, las primeras `len` posiciones están inicializadas y la memoria se cede al vector sin liberarse.
+        unsafe { Vec::from_raw_parts(slots.as_mut_ptr().cast::<T>(), len, slots.capacity()) }
+    }
+
+    fn place(&mut self, index: usize, value: T) -> Result<(), Exceptions> {
+        if self.capacity == 0 {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        let base = self.array.as_mut_ptr();
+        if self.len < self.capacity {
+            // SAFETY: hay al menos una posición libre después de `self.len`, por lo que `index..self.len` cabe desplazado una posición a la derecha.
+            unsafe { ptr::copy(base.add(index), base.add(index + 1), self.len - index) };
+            self.array[index].write(value);
+            self.len += 1;
+            return Ok(());
+        }
+        let discarded = match self.policy {
+            OverflowPolicy::Reject => return Err(Exceptions::IndexOutOfBounds),
+            OverflowPolicy::OverwriteEnd => {
+                let index = index.min(self.len - 1);
+                // SAFETY: el arreglo está lleno, así que la última posición está inicializada; tras leerla, el desplazamiento de `index..self.len - 1` una posición a la derecha la cubre.
+                let discarded = unsafe {
+                    let discarded = (*base.add(self.len - 1)).assume_init_read();
+                    ptr::copy(base.add(index), base.add(index + 1), self.len - 1 - index);
+                    discarded
+                };
+                self.array[index].write(value);
+                discarded
+            }
+            OverflowPolicy::DropOldest => {
+                let index = index.saturating_sub(1);
+                // SAFETY: el arreglo está lleno, así que la primera posición está inicializada; tras leerla, el desplazamiento de `1..=index` una posición a la izquierda la cubre.
+                let discarded = unsafe {
+                    let discarded = (*base).assume_init_read();
+                    ptr::copy(base.add(1), base, index);
+                    discarded
+                };
+                self.array[index].write(value);
+                discarded
+            }
+        };
+        // El elemento descartado se libera cuando el arreglo ya es consistente, por si su `drop` entra en pánico.
+        drop(discarded);
+        Ok(())
+    }
+}
+
+impl<T: Clone + PartialEq> StaticArray<T> {
+    /// Verifica si el arreglo estático contiene un elemento igual al valor especificado.
+    ///
+    /// # Parámetros
+    /// - `value`: Una referencia al valor que se desea buscar.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::StaticArray;
+    /// let array = StaticArray::with_values(5, &[10, 20, 30]);
+    ///
+    /// assert!(array.contains(&20));
+    /// assert!(!array.contains(&40));
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`) y solo recorre las posiciones inicializadas.
+    #[must_use]
+    pub fn contains(&self, value: &T) -> bool {
+        self.iter().any(|current| current == value)
+    }
+}
+
+impl<T: Clone + Ord> StaticArray<T> {
+    /// Devuelve una referencia al menor elemento del arreglo estático, o `None` si está vacío.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::StaticArray;
+    /// let array = StaticArray::with_values(5, &[3, 1, 4]);
+    ///
+    /// assert_eq!(array.min(), Some(&1));
+    /// assert_eq!(StaticArray::<i32>::new(2).min(), None);
+    /// ```
+    ///
+    /// # Notas
+    /// - Si hay varios elementos mínimos, se devuelve el primero.
+    #[must_use]
+    pub fn min(&self) -> Option<&T> {
+        self.iter().min()
+    }
+
+    /// Devuelve una referencia al mayor elemento del arreglo estático, o `None` si está vacío.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::StaticArray;
+    /// let array = StaticArray::with_values(5, &[3, 1, 4]);
+    ///
+    /// assert_eq!(array.max(), Some(&4));
+    /// ```
+    ///
+    /// # Notas
+    /// - Si hay varios elementos máximos, se devuelve el último.
+    #[must_use]
+    pub fn max(&self) -> Option<&T> {
+        self.iter().max()
+    }
+}
+
+impl<T: Clone + Sum> StaticArray<T> {
+    /// Suma todos los elementos del arreglo estático.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::StaticArray;
+    /// let array = StaticArray::with_values(5, &[1.5, 2.0, 0.5]);
+    ///
+    /// assert_eq!(array.sum(), 4.0);
+    /// assert_eq!(StaticArray::<i32>::new(2).sum(), 0);
+    /// ```
+    ///
+    /// # Notas
+    /// - Cada elemento se clona antes de sumarse, lo que no tiene costo para los tipos numéricos. Un arreglo vacío devuelve el neutro de la suma (`0`).
+    #[must_use]
+    pub fn sum(&self) -> T {
+        self.iter().cloned().sum()
+    }
+}
+
+impl<T: Clone> Clone for StaticArray<T> {
+    fn clone(&self) -> Self {
+        let mut array = Self::with_values(self.capacity, self.as_slice());
+        array.policy = self.policy;
+        array
+    }
+}
+
+impl<T: Clone> Drop for StaticArray<T> {
+    fn drop(&mut self) {
+        let initialized = ptr::from_mut(self.as_mut_slice());
+        // La longitud se reduce antes de liberar los elementos, para que un `drop` que entre en pánico no provoque una doble liberación.
+        self.len = 0;
+        // SAFETY: los elementos de `initialized` están inicializados y ya quedaron fuera de `0..self.len`.
+        unsafe { ptr::drop_in_place(initialized) };
+    }
 }
 
 impl<T: Clone, const N: usize> From<&[T; N]> for StaticArray<T> {
@@ -634,23 +911,99 @@ impl<T: Clone> From<Vec<T>> for StaticArray<T> {
     }
 }
 
-impl<T: Clone> Iterator for StaticArray<T> {
-    type Item = T;
+impl<T: Clone> Index<usize> for StaticArray<T> {
+    type Output = T;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.current < self.len {
-            self.current += 1;
-            self.array[self.current - 1].clone()
-        } else {
-            None
+    /// Obtiene una referencia inmutable al elemento en el índice especificado mediante la sintaxis `array[index]`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::StaticArray;
+    /// let array = StaticArray::from(&[10, 20, 30]);
+    ///
+    /// assert_eq!(array[1], 20);
+    /// ```
+    ///
+    /// # Panics
+    /// Entra en pánico si `index` es mayor o igual a la longitud del arreglo (`len`). Para obtener un `Result` en lugar de un pánico, utiliza `get`.
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Ok(value) => value,
+            Err(_) => panic!(
+                "index out of bounds: the len is {} but the index is {index}",
+                self.len
+            ),
         }
     }
 }
 
+impl<T: Clone> IndexMut<usize> for StaticArray<T> {
+    /// Obtiene una referencia mutable al elemento en el índice especificado mediante la sintaxis `array[index]`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::StaticArray;
+    /// let mut array = StaticArray::from(&[10, 20, 30]);
+    ///
+    /// array[1] = 25;
+    /// assert_eq!(array.get(1), Ok(&25));
+    /// ```
+    ///
+    /// # Panics
+    /// Entra en pánico si `index` es mayor o igual a la longitud del arreglo (`len`). Para obtener un `Result` en lugar de un pánico, utiliza `get_mut` o `set`.
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len;
+        match self.get_mut(index) {
+            Ok(value) => value,
+            Err(_) => panic!("index out of bounds: the len is {len} but the index is {index}"),
+        }
+    }
+}
+
+impl<T: Clone> IntoIterator for StaticArray<T> {
+    type Item = T;
+    type IntoIter = StaticArrayIntoIter<T>;
+
+    /// Consume el arreglo estático y devuelve un iterador que entrega sus elementos por propiedad.
+    ///
+    /// # Comportamiento
+    /// - Los valores se mueven fuera de sus posiciones sin clonarse; la capacidad libre se descarta.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::StaticArray;
+    /// let array = StaticArray::with_values(5, &[String::from("a"), String::from("b")]);
+    ///
+    /// let upper: Vec<String> = array.into_iter().map(|value| value.to_uppercase()).collect();
+    /// assert_eq!(upper, vec![String::from("A"), String::from("B")]);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        StaticArrayIntoIter::new(self.into_vec())
+    }
+}
+
+impl<'a, T: Clone> IntoIterator for &'a StaticArray<T> {
+    type Item = &'a T;
+    type IntoIter = StaticArrayIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: Clone> IntoIterator for &'a mut StaticArray<T> {
+    type Item = &'a mut T;
+    type IntoIter = StaticArrayIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
 impl<T: Clone + Debug> Debug for StaticArray<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
         write!(f, "[")?;
-        for (index, value) in self.clone().enumerate() {
+        for (index, value) in self.iter().enumerate() {
             if index > 0 {
                 write!(f, ", ")?;
             }
@@ -659,3 +1012,63 @@ impl<T: Clone + Debug> Debug for StaticArray<T> {
         write!(f, "]")
     }
 }
+
+impl<T: Clone + PartialEq> PartialEq for StaticArray<T> {
+    /// Compara dos arreglos estáticos elemento por elemento, considerando solo las posiciones inicializadas.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::StaticArray;
+    /// let left = StaticArray::with_values(5, &[1, 2, 3]);
+    /// let right = StaticArray::with_values(3, &[1, 2, 3]);
+    ///
+    /// // La capacidad no forma parte de la comparación.
+    /// assert_eq!(left, right);
+    /// assert_eq!(left, vec![1, 2, 3]);
+    /// assert_eq!(left, [1, 2, 3][..]);
+    /// assert_ne!(left, StaticArray::from(&[1, 2]));
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Clone + Eq> Eq for StaticArray<T> {}
+
+impl<T: Clone + PartialEq> PartialEq<[T]> for StaticArray<T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.len == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Clone + PartialEq> PartialEq<&[T]> for StaticArray<T> {
+    fn eq(&self, other: &&[T]) -> bool {
+        *self == **other
+    }
+}
+
+impl<T: Clone + PartialEq> PartialEq<Vec<T>> for StaticArray<T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        *self == *other.as_slice()
+    }
+}
+
+impl<T: Clone + Hash> Hash for StaticArray<T> {
+    /// Calcula el hash a partir de la longitud y de los elementos inicializados, en orden, de modo que dos arreglos iguales producen el mismo hash sin importar su capacidad.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use array::StaticArray;
+    /// # use std::collections::HashSet;
+    /// let mut set = HashSet::new();
+    ///
+    /// set.insert(StaticArray::with_values(5, &[1, 2]));
+    /// assert!(set.contains(&StaticArray::from(&[1, 2])));
+    /// ```
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for value in self {
+            value.hash(state);
+        }
+    }
+}
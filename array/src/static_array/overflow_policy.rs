@@ -0,0 +1,23 @@
+/// Define qué hace un `StaticArray` lleno cuando se intenta agregar un elemento con `push`, `unshift` o `insert`.
+///
+/// # Ejemplo
+/// ```
+/// # use array::{OverflowPolicy, StaticArray};
+/// let mut array = StaticArray::with_policy(2, OverflowPolicy::DropOldest);
+/// array.push(1).unwrap();
+/// array.push(2).unwrap();
+///
+/// // El arreglo está lleno: se descarta el primer elemento para hacer espacio.
+/// array.push(3).unwrap();
+/// assert_eq!(array.iter().collect::<Vec<_>>(), vec![&2, &3]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Rechaza el nuevo elemento y retorna `Exceptions::IndexOutOfBounds`. Es la política por defecto.
+    #[default]
+    Reject,
+    /// Descarta el último elemento del arreglo para hacer espacio. Con `push`, el nuevo elemento reemplaza al último.
+    OverwriteEnd,
+    /// Descarta el primer elemento del arreglo para hacer espacio, desplazando hacia la izquierda los elementos anteriores a la posición de inserción.
+    DropOldest,
+}
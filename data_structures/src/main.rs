@@ -1,14 +1,53 @@
-use array::{da_implementation, dynamic_array, sa_implementation, static_array};
+use array::{
+    ad_implementation, ba_implementation, da_implementation, dynamic_array, fa_implementation,
+    gb_implementation, rb_implementation, sa_implementation, spa_implementation, static_array,
+};
 use exceptions::Exceptions;
-use hash_table::ht_implementation;
-use linked_list::ll_implementation;
+use graph::gr_implementation;
+use hash_table::{
+    cht_implementation, cms_implementation, ht_implementation, lhm_implementation,
+    mm_implementation, oa_implementation, ttl_implementation,
+};
+use heap::{dh_implementation, fh_implementation, ph_implementation};
+use linked_list::{
+    cll_implementation, dll_implementation, ll_implementation, lru_implementation,
+    sl_implementation, ull_implementation, xll_implementation,
+};
+use stack::{st_implementation, tsq_implementation};
+use tree::{bt_implementation, tm_implementation};
 
 fn main() -> Result<(), Exceptions> {
     static_array();
     dynamic_array();
     da_implementation()?;
     sa_implementation()?;
+    fa_implementation()?;
+    rb_implementation()?;
+    ad_implementation()?;
+    spa_implementation()?;
+    ba_implementation()?;
+    gb_implementation()?;
     ll_implementation()?;
+    dll_implementation()?;
+    cll_implementation()?;
+    sl_implementation()?;
+    ull_implementation()?;
+    xll_implementation()?;
+    lru_implementation()?;
     ht_implementation()?;
+    oa_implementation()?;
+    lhm_implementation()?;
+    mm_implementation()?;
+    cht_implementation()?;
+    ttl_implementation()?;
+    cms_implementation()?;
+    dh_implementation()?;
+    ph_implementation()?;
+    fh_implementation()?;
+    st_implementation()?;
+    tsq_implementation()?;
+    tm_implementation()?;
+    bt_implementation()?;
+    gr_implementation()?;
     Ok(())
 }
@@ -4,4 +4,5 @@ pub enum Exceptions {
     KeyNotInitialized,
     DuplicateKey,
     NoSuchElement(String),
+    CycleDetected(String),
 }
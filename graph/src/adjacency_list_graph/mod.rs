@@ -0,0 +1,560 @@
+use exceptions::Exceptions;
+use hash_table::{LinkedHashMap, SeparateChainingHashTable};
+use linked_list::SinglyLinkedList;
+use stack::TwoStackQueue;
+use std::borrow::Borrow;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+use std::hash::Hash;
+
+pub struct AdjacencyListGraph<V: Clone + Hash + Eq> {
+    // Cada vértice guarda la lista de sus vecinos; el mapa conserva el orden en que se agregaron los vértices.
+    adjacency: LinkedHashMap<V, SinglyLinkedList<V>>,
+    directed: bool,
+    edges_len: usize,
+}
+
+impl<V: Clone + Hash + Eq> AdjacencyListGraph<V> {
+    /// Crea un nuevo grafo dirigido vacío.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use graph::AdjacencyListGraph;
+    /// let graph: AdjacencyListGraph<&str> = AdjacencyListGraph::new_directed();
+    ///
+    /// assert!(graph.is_directed());
+    /// assert!(graph.is_empty());
+    /// ```
+    #[must_use]
+    pub fn new_directed() -> Self {
+        Self::with_direction(true)
+    }
+
+    /// Crea un nuevo grafo no dirigido vacío.
+    ///
+    /// # Comportamiento
+    /// - Cada arista se guarda en las listas de sus dos extremos, salvo los lazos, que se guardan una sola vez.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use graph::AdjacencyListGraph;
+    /// let graph: AdjacencyListGraph<&str> = AdjacencyListGraph::new_undirected();
+    ///
+    /// assert!(!graph.is_directed());
+    /// ```
+    #[must_use]
+    pub fn new_undirected() -> Self {
+        Self::with_direction(false)
+    }
+
+    fn with_direction(directed: bool) -> Self {
+        Self {
+            adjacency: LinkedHashMap::new(8),
+            directed,
+            edges_len: 0,
+        }
+    }
+
+    /// Agrega un vértice sin aristas.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use graph::AdjacencyListGraph;
+    /// # use exceptions::Exceptions;
+    /// let mut graph = AdjacencyListGraph::new_undirected();
+    /// graph.add_vertex("Lima").unwrap();
+    ///
+    /// assert!(graph.contains_vertex("Lima"));
+    /// assert_eq!(graph.add_vertex("Lima"), Err(Exceptions::DuplicateKey));
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::DuplicateKey` si el grafo ya contiene el vértice.
+    pub fn add_vertex(&mut self, vertex: V) -> Result<(), Exceptions> {
+        self.adjacency.insert(vertex, SinglyLinkedList::new())
+    }
+
+    /// Agrega una arista desde `from` hacia `to`; en un grafo no dirigido también se agrega la arista inversa.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use graph::AdjacencyListGraph;
+    /// # use exceptions::Exceptions;
+    /// let mut graph = AdjacencyListGraph::new_directed();
+    /// graph.add_vertex("a").unwrap();
+    /// graph.add_vertex("b").unwrap();
+    /// graph.add_edge(&"a", &"b").unwrap();
+    ///
+    /// assert!(graph.has_edge(&"a", &"b"));
+    /// assert!(!graph.has_edge(&"b", &"a"));
+    /// assert_eq!(graph.add_edge(&"a", &"b"), Err(Exceptions::DuplicateKey));
+    /// assert_eq!(graph.add_edge(&"a", &"c"), Err(Exceptions::KeyNotInitialized));
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::KeyNotInitialized` si alguno de los dos vértices no existe.
+    /// - `Exceptions::DuplicateKey` si la arista ya existe, ya que el grafo no admite aristas paralelas.
+    ///
+    /// # Notas
+    /// - Comprobar que la arista no exista recorre la lista de `from`, por lo que el costo es `O(grado(from))`.
+    pub fn add_edge(&mut self, from: &V, to: &V) -> Result<(), Exceptions> {
+        if !self.contains_vertex(to) {
+            return Err(Exceptions::KeyNotInitialized);
+        }
+        if self.has_edge(from, to) {
+            return Err(Exceptions::DuplicateKey);
+        }
+        self.adjacency.get_mut(from)?.push(to.clone());
+        if !self.directed && from != to {
+            self.adjacency.get_mut(to)?.push(from.clone());
+        }
+        self.edges_len += 1;
+        Ok(())
+    }
+
+    /// Verifica si el grafo contiene el vértice especificado.
+    pub fn contains_vertex<Q: ?Sized + Hash + Eq>(&self, vertex: &Q) -> bool
+    where
+        V: Borrow<Q>,
+    {
+        self.adjacency.contains_key(vertex)
+    }
+
+    /// Verifica si existe una arista desde `from` hacia `to`.
+    pub fn has_edge<Q: ?Sized + Hash + Eq>(&self, from: &Q, to: &Q) -> bool
+    where
+        V: Borrow<Q>,
+    {
+        self.adjacency
+            .get(from)
+            .is_ok_and(|neighbors| neighbors.find(|neighbor| neighbor.borrow() == to).is_some())
+    }
+
+    /// Obtiene la lista de vecinos del vértice especificado, en el orden en que se agregaron las aristas.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use graph::AdjacencyListGraph;
+    /// let mut graph = AdjacencyListGraph::new_undirected();
+    /// for vertex in [1, 2, 3] {
+    ///     graph.add_vertex(vertex).unwrap();
+    /// }
+    /// graph.add_edge(&1, &2).unwrap();
+    /// graph.add_edge(&3, &1).unwrap();
+    ///
+    /// assert_eq!(graph.neighbors(&1).unwrap().to_vec(), vec![2, 3]);
+    /// assert_eq!(graph.neighbors(&3).unwrap().to_vec(), vec![1]);
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::KeyNotInitialized` si el vértice no existe.
+    pub fn neighbors<Q: ?Sized + Hash + Eq>(
+        &self,
+        vertex: &Q,
+    ) -> Result<&SinglyLinkedList<V>, Exceptions>
+    where
+        V: Borrow<Q>,
+    {
+        self.adjacency.get(vertex)
+    }
+
+    /// Devuelve el grado del vértice: sus aristas salientes en un grafo dirigido, o sus aristas incidentes en uno no dirigido.
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::KeyNotInitialized` si el vértice no existe.
+    pub fn degree<Q: ?Sized + Hash + Eq>(&self, vertex: &Q) -> Result<usize, Exceptions>
+    where
+        V: Borrow<Q>,
+    {
+        self.neighbors(vertex).map(SinglyLinkedList::len)
+    }
+
+    /// Devuelve la cantidad de aristas que llegan al vértice; en un grafo no dirigido coincide con `degree`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use graph::AdjacencyListGraph;
+    /// let mut graph = AdjacencyListGraph::new_directed();
+    /// for vertex in ['a', 'b', 'c'] {
+    ///     graph.add_vertex(vertex).unwrap();
+    /// }
+    /// graph.add_edge(&'a', &'c').unwrap();
+    /// graph.add_edge(&'b', &'c').unwrap();
+    ///
+    /// assert_eq!(graph.degree(&'c'), Ok(0));
+    /// assert_eq!(graph.in_degree(&'c'), Ok(2));
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::KeyNotInitialized` si el vértice no existe.
+    ///
+    /// # Notas
+    /// - En un grafo dirigido las listas solo guardan las aristas salientes, así que se recorren todas (`O(V + E)`).
+    pub fn in_degree<Q: ?Sized + Hash + Eq>(&self, vertex: &Q) -> Result<usize, Exceptions>
+    where
+        V: Borrow<Q>,
+    {
+        if !self.directed {
+            return self.degree(vertex);
+        }
+        if !self.contains_vertex(vertex) {
+            return Err(Exceptions::KeyNotInitialized);
+        }
+        Ok(self
+            .adjacency
+            .iter()
+            .filter(|(_, neighbors)| {
+                neighbors
+                    .find(|neighbor| neighbor.borrow() == vertex)
+                    .is_some()
+            })
+            .count())
+    }
+
+    /// Elimina la arista desde `from` hacia `to`; en un grafo no dirigido también se elimina la arista inversa.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use graph::AdjacencyListGraph;
+    /// # use exceptions::Exceptions;
+    /// let mut graph = AdjacencyListGraph::new_undirected();
+    /// graph.add_vertex("x").unwrap();
+    /// graph.add_vertex("y").unwrap();
+    /// graph.add_edge(&"x", &"y").unwrap();
+    /// graph.remove_edge(&"y", &"x").unwrap();
+    ///
+    /// assert!(!graph.has_edge(&"x", &"y"));
+    /// assert_eq!(graph.edges_len(), 0);
+    /// assert!(matches!(graph.remove_edge(&"x", &"y"), Err(Exceptions::NoSuchElement(_))));
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::KeyNotInitialized` si `from` no existe.
+    /// - `Exceptions::NoSuchElement` si la arista no existe.
+    pub fn remove_edge<Q: ?Sized + Hash + Eq>(&mut self, from: &Q, to: &Q) -> Result<(), Exceptions>
+    where
+        V: Borrow<Q>,
+    {
+        Self::unlink(self.adjacency.get_mut(from)?, to)?;
+        if !self.directed && from != to {
+            Self::unlink(self.adjacency.get_mut(to)?, from)?;
+        }
+        self.edges_len -= 1;
+        Ok(())
+    }
+
+    /// Elimina el vértice junto con todas las aristas que salen de él o llegan a él, y devuelve sus vecinos.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use graph::AdjacencyListGraph;
+    /// let mut graph = AdjacencyListGraph::new_directed();
+    /// for vertex in [1, 2, 3] {
+    ///     graph.add_vertex(vertex).unwrap();
+    /// }
+    /// graph.add_edge(&1, &2).unwrap();
+    /// graph.add_edge(&2, &3).unwrap();
+    /// graph.add_edge(&3, &1).unwrap();
+    ///
+    /// assert_eq!(graph.remove_vertex(&2).unwrap().to_vec(), vec![3]);
+    /// assert_eq!(graph.vertices(), vec![&1, &3]);
+    /// assert_eq!(graph.edges_len(), 1);
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::KeyNotInitialized` si el vértice no existe.
+    ///
+    /// # Notas
+    /// - En un grafo no dirigido solo se visitan los vecinos del vértice; en uno dirigido hay que recorrer todas las listas
+    ///   para encontrar las aristas entrantes (`O(V + E)`).
+    pub fn remove_vertex<Q: ?Sized + Hash + Eq>(
+        &mut self,
+        vertex: &Q,
+    ) -> Result<SinglyLinkedList<V>, Exceptions>
+    where
+        V: Borrow<Q>,
+    {
+        let neighbors = self.adjacency.remove(vertex)?;
+        self.edges_len -= neighbors.len();
+        let sources: Vec<V> = if self.directed {
+            self.adjacency.get_keys().into_iter().cloned().collect()
+        } else {
+            neighbors.to_vec()
+        };
+        for source in sources {
+            if let Ok(list) = self.adjacency.get_mut::<V>(&source) {
+                let len = list.len();
+                list.retain(|neighbor| neighbor.borrow() != vertex);
+                if self.directed {
+                    self.edges_len -= len - list.len();
+                }
+            }
+        }
+        Ok(neighbors)
+    }
+
+    /// Devuelve los vértices en el orden en que se agregaron.
+    #[must_use]
+    pub fn vertices(&self) -> Vec<&V> {
+        self.adjacency.get_keys()
+    }
+
+    /// Devuelve la cantidad de vértices del grafo.
+    #[must_use]
+    pub const fn vertices_len(&self) -> usize {
+        self.adjacency.entries_len()
+    }
+
+    /// Devuelve la cantidad de aristas del grafo; en uno no dirigido cada arista se cuenta una sola vez.
+    #[must_use]
+    pub const fn edges_len(&self) -> usize {
+        self.edges_len
+    }
+
+    /// Verifica si el grafo no tiene vértices.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.adjacency.is_empty()
+    }
+
+    /// Verifica si el grafo es dirigido.
+    #[must_use]
+    pub const fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Ordena los vértices de modo que cada arista vaya de un vértice a otro posterior (algoritmo de Kahn).
+    ///
+    /// # Comportamiento
+    /// - Encola los vértices sin aristas entrantes; al desencolar uno, descuenta una arista entrante a cada vecino y encola
+    ///   los que se quedan sin ellas.
+    /// - Entre vértices disponibles a la vez se respeta el orden en que se agregaron, por lo que el resultado es determinista.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use graph::AdjacencyListGraph;
+    /// # use exceptions::Exceptions;
+    /// let mut graph = AdjacencyListGraph::new_directed();
+    /// for task in ["vestirse", "calcetines", "zapatos"] {
+    ///     graph.add_vertex(task).unwrap();
+    /// }
+    /// graph.add_edge(&"calcetines", &"zapatos").unwrap();
+    /// graph.add_edge(&"zapatos", &"vestirse").unwrap();
+    ///
+    /// assert_eq!(graph.topological_sort(), Ok(vec!["calcetines", "zapatos", "vestirse"]));
+    /// graph.add_edge(&"vestirse", &"calcetines").unwrap();
+    /// assert!(matches!(graph.topological_sort(), Err(Exceptions::CycleDetected(_))));
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::CycleDetected` si el grafo tiene un ciclo, indicando cuántos vértices quedaron sin ordenar.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(V + E)`).
+    /// - En un grafo no dirigido cada arista se recorre en ambos sentidos, así que solo hay orden si no tiene aristas.
+    pub fn topological_sort(&self) -> Result<Vec<V>, Exceptions> {
+        let mut in_degrees: SeparateChainingHashTable<V, usize> = self
+            .adjacency
+            .iter()
+            .map(|(vertex, _)| (vertex.clone(), 0))
+            .collect();
+        for (_, neighbors) in self.adjacency.iter() {
+            for neighbor in neighbors.values() {
+                *in_degrees.get_mut(neighbor)? += 1;
+            }
+        }
+        let order = self.peel(in_degrees, 0);
+        if order.len() < self.vertices_len() {
+            return Err(Exceptions::CycleDetected(format!(
+                "The graph has a cycle: {} of its {} vertices could not be ordered",
+                self.vertices_len() - order.len(),
+                self.vertices_len()
+            )));
+        }
+        Ok(order)
+    }
+
+    /// Verifica si el grafo tiene algún ciclo.
+    ///
+    /// # Comportamiento
+    /// - En un grafo dirigido, hay un ciclo si el orden topológico no alcanza a todos los vértices.
+    /// - En un grafo no dirigido se eliminan repetidamente los vértices con a lo sumo un vecino: solo un bosque se vacía por
+    ///   completo. Ir y volver por la misma arista no cuenta como ciclo, pero un lazo sí.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use graph::AdjacencyListGraph;
+    /// let mut graph = AdjacencyListGraph::new_undirected();
+    /// for vertex in 1..=4 {
+    ///     graph.add_vertex(vertex).unwrap();
+    /// }
+    /// graph.add_edge(&1, &2).unwrap();
+    /// graph.add_edge(&2, &3).unwrap();
+    /// graph.add_edge(&2, &4).unwrap();
+    ///
+    /// assert!(!graph.has_cycle());
+    /// graph.add_edge(&3, &4).unwrap();
+    /// assert!(graph.has_cycle());
+    /// ```
+    #[must_use]
+    pub fn has_cycle(&self) -> bool {
+        if self.directed {
+            return self.topological_sort().is_err();
+        }
+        let degrees: SeparateChainingHashTable<V, usize> = self
+            .adjacency
+            .iter()
+            .map(|(vertex, neighbors)| {
+                let has_loop = neighbors.find(|neighbor| neighbor == vertex).is_some();
+                (vertex.clone(), neighbors.len() + usize::from(has_loop))
+            })
+            .collect();
+        self.peel(degrees, 1).len() < self.vertices_len()
+    }
+
+    // Desencola los vértices cuyo grado no supera `threshold`, descontándolo en sus vecinos, y devuelve el orden en que salieron.
+    fn peel(&self, mut degrees: SeparateChainingHashTable<V, usize>, threshold: usize) -> Vec<V> {
+        let mut queue = TwoStackQueue::new();
+        for (vertex, _) in self.adjacency.iter() {
+            if degrees.get(vertex).is_ok_and(|degree| *degree <= threshold) {
+                queue.enqueue(vertex.clone());
+            }
+        }
+        let mut order = Vec::with_capacity(self.vertices_len());
+        while let Ok(vertex) = queue.dequeue() {
+            if let Ok(neighbors) = self.adjacency.get(&vertex) {
+                for neighbor in neighbors.values() {
+                    if let Ok(degree) = degrees.get_mut(neighbor) {
+                        if *degree > threshold {
+                            *degree -= 1;
+                            if *degree == threshold {
+                                queue.enqueue(neighbor.clone());
+                            }
+                        }
+                    }
+                }
+            }
+            order.push(vertex);
+        }
+        order
+    }
+
+    fn unlink<Q: ?Sized + Eq>(list: &mut SinglyLinkedList<V>, vertex: &Q) -> Result<(), Exceptions>
+    where
+        V: Borrow<Q>,
+    {
+        let index = list
+            .position(|neighbor| neighbor.borrow() == vertex)
+            .ok_or_else(|| Exceptions::NoSuchElement(String::from("The edge does not exist")))?;
+        list.remove(index)?;
+        Ok(())
+    }
+}
+
+impl<V: Clone + Hash + Eq + Debug> Debug for AdjacencyListGraph<V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "{:?}", self.adjacency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AdjacencyListGraph;
+
+    fn cycle(directed: bool) -> AdjacencyListGraph<u32> {
+        let mut graph = if directed {
+            AdjacencyListGraph::new_directed()
+        } else {
+            AdjacencyListGraph::new_undirected()
+        };
+        for vertex in 0..6 {
+            graph.add_vertex(vertex).unwrap();
+        }
+        for vertex in 0..6 {
+            graph.add_edge(&vertex, &((vertex + 1) % 6)).unwrap();
+        }
+        graph.add_edge(&0, &3).unwrap();
+        graph.add_edge(&4, &4).unwrap();
+        graph
+    }
+
+    #[test]
+    fn removing_a_vertex_drops_its_edges_in_both_directions() {
+        for directed in [true, false] {
+            let mut graph = cycle(directed);
+            assert_eq!(graph.edges_len(), 8);
+            graph.remove_vertex(&4).unwrap();
+            graph.remove_vertex(&0).unwrap();
+            assert_eq!(graph.edges_len(), 2);
+            for vertex in [1, 2, 3, 5] {
+                assert!(!graph.has_edge(&vertex, &0));
+                assert!(!graph.has_edge(&vertex, &4));
+            }
+            let degrees: usize = graph
+                .vertices()
+                .iter()
+                .map(|v| graph.degree(*v).unwrap())
+                .sum();
+            assert_eq!(degrees, if directed { 2 } else { 4 });
+        }
+    }
+
+    #[test]
+    fn topological_sort_respects_every_edge() {
+        let mut graph = AdjacencyListGraph::new_directed();
+        for vertex in 0..30_u32 {
+            graph.add_vertex(vertex).unwrap();
+        }
+        for from in 0..30_u32 {
+            for to in (from + 1..30).filter(|to| (from * 7 + to * 13) % 5 == 0) {
+                graph
+                    .add_edge(&((from * 11) % 30), &((to * 11) % 30))
+                    .unwrap();
+            }
+        }
+        let order = graph.topological_sort().unwrap();
+        let position = |vertex: &u32| order.iter().position(|v| v == vertex).unwrap();
+        assert_eq!(order.len(), 30);
+        for vertex in graph.vertices() {
+            for neighbor in graph.neighbors(vertex).unwrap() {
+                assert!(position(vertex) < position(&neighbor));
+            }
+        }
+        assert!(!graph.has_cycle());
+        let from = order[0];
+        let to = *graph.neighbors(&from).unwrap().front().unwrap();
+        graph.add_edge(&to, &from).unwrap();
+        assert!(graph.has_cycle());
+    }
+
+    #[test]
+    fn cycles_are_detected_in_undirected_graphs() {
+        let mut graph = cycle(false);
+        assert!(graph.has_cycle());
+        graph.remove_edge(&0, &3).unwrap();
+        graph.remove_edge(&2, &3).unwrap();
+        assert!(graph.has_cycle());
+        graph.remove_edge(&4, &4).unwrap();
+        assert!(!graph.has_cycle());
+        assert!(cycle(true).has_cycle());
+    }
+
+    #[test]
+    fn undirected_edges_are_stored_at_both_ends() {
+        let mut graph = cycle(false);
+        assert_eq!(graph.neighbors(&0).unwrap().to_vec(), vec![1, 5, 3]);
+        assert_eq!(graph.degree(&4), Ok(3));
+        graph.remove_edge(&3, &0).unwrap();
+        graph.remove_edge(&4, &4).unwrap();
+        assert!(!graph.has_edge(&0, &3));
+        assert_eq!(graph.in_degree(&0), Ok(2));
+        assert_eq!(graph.edges_len(), 6);
+    }
+}
@@ -0,0 +1,55 @@
+pub mod adjacency_list_graph;
+
+pub use adjacency_list_graph::AdjacencyListGraph;
+use exceptions::Exceptions;
+
+pub fn gr_implementation() -> Result<(), Exceptions> {
+    println!("Grafo (lista de adyacencia)");
+    let mut roads: AdjacencyListGraph<&str> = AdjacencyListGraph::new_undirected();
+    for city in ["Santiago", "Valparaíso", "Rancagua", "Talca"] {
+        roads.add_vertex(city)?;
+    }
+    for (from, to) in [
+        ("Santiago", "Valparaíso"),
+        ("Santiago", "Rancagua"),
+        ("Rancagua", "Talca"),
+    ] {
+        roads.add_edge(&from, &to)?;
+    }
+    let (vertices, edges) = (roads.vertices_len(), roads.edges_len());
+    println!("  1. Grafo no dirigido ({vertices} vértices, {edges} aristas):\n    {roads:?}");
+    let degree = roads.degree(&"Santiago")?;
+    let neighbors = roads.neighbors(&"Santiago")?;
+    println!("  2. Vecinos de Santiago (grado {degree}): {neighbors:?}");
+    roads.remove_vertex(&"Rancagua")?;
+    let edges = roads.edges_len();
+    println!("  3. Eliminar Rancagua (aristas restantes: {edges}):\n    {roads:?}");
+    let mut courses: AdjacencyListGraph<&str> = AdjacencyListGraph::new_directed();
+    for course in ["Cálculo I", "Cálculo II", "Álgebra", "Ecuaciones"] {
+        courses.add_vertex(course)?;
+    }
+    for (from, to) in [
+        ("Cálculo I", "Cálculo II"),
+        ("Cálculo II", "Ecuaciones"),
+        ("Álgebra", "Ecuaciones"),
+    ] {
+        courses.add_edge(&from, &to)?;
+    }
+    println!("  4. Grafo dirigido (requisitos):\n    {courses:?}");
+    let (out_degree, in_degree) = (
+        courses.degree(&"Ecuaciones")?,
+        courses.in_degree(&"Ecuaciones")?,
+    );
+    println!("  5. Ecuaciones: grado de salida {out_degree}, grado de entrada {in_degree}");
+    let order = courses.topological_sort()?;
+    println!("  6. Orden topológico (Kahn):\n    {order:?}");
+    courses.remove_edge(&"Álgebra", &"Ecuaciones")?;
+    println!("  7. Eliminar la arista Álgebra -> Ecuaciones:\n    {courses:?}");
+    courses.add_edge(&"Ecuaciones", &"Cálculo I")?;
+    let has_cycle = courses.has_cycle();
+    println!("  8. Agregar la arista Ecuaciones -> Cálculo I (¿ciclo?: {has_cycle}):");
+    if let Err(Exceptions::CycleDetected(message)) = courses.topological_sort() {
+        println!("    {message}");
+    }
+    Ok(())
+}
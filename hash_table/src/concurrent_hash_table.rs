@@ -0,0 +1,177 @@
+use crate::hasher::Fnv1aHasher;
+use crate::separate_chaining_hash_table::SeparateChainingHashTable;
+use exceptions::Exceptions;
+use std::borrow::Borrow;
+use std::hash::{Hash, Hasher};
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+const DEFAULT_SHARDS: usize = 16;
+
+pub struct ConcurrentHashTable<K: Clone + Hash + Eq, V: Clone, H: Hasher + Default = Fnv1aHasher> {
+    shards: Box<[RwLock<SeparateChainingHashTable<K, V, H>>]>,
+}
+
+impl<K: Clone + Hash + Eq, V: Clone, H: Hasher + Default> ConcurrentHashTable<K, V, H> {
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self::with_shards(DEFAULT_SHARDS, capacity)
+    }
+
+    #[must_use]
+    pub fn with_shards(shards: usize, capacity: usize) -> Self {
+        assert!(
+            shards > 0,
+            "a concurrent hash table needs at least one shard"
+        );
+        let shard_capacity = capacity.div_ceil(shards).max(1);
+        Self {
+            shards: (0..shards)
+                .map(|_| RwLock::new(SeparateChainingHashTable::new(shard_capacity)))
+                .collect(),
+        }
+    }
+
+    pub fn get<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Result<V, Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        self.read(key).get(key).cloned()
+    }
+
+    pub fn contains_key<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.read(key).contains_key(key)
+    }
+
+    pub fn insert<Q: Into<K>>(&self, key: Q, value: V) -> Result<(), Exceptions> {
+        let key: K = key.into();
+        self.write(&key).insert(key, value)
+    }
+
+    pub fn put<Q: Into<K>>(&self, key: Q, value: V) -> Option<V> {
+        let key: K = key.into();
+        self.write(&key).put(key, value)
+    }
+
+    pub fn update<Q: Into<K>, F: FnOnce(&mut V)>(&self, key: Q, default: V, update: F) -> V {
+        let key: K = key.into();
+        let mut shard = self.write(&key);
+        let value = shard.get_or_insert_with(key, || default);
+        update(value);
+        value.clone()
+    }
+
+    pub fn remove<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Result<V, Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        self.write(key).remove(key)
+    }
+
+    #[must_use]
+    pub fn entries_len(&self) -> usize {
+        self.shard_lengths().iter().sum()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries_len() == 0
+    }
+
+    #[must_use]
+    pub fn shards_len(&self) -> usize {
+        self.shards.len()
+    }
+
+    #[must_use]
+    pub fn shard_lengths(&self) -> Vec<usize> {
+        self.shards
+            .iter()
+            .map(|shard| {
+                shard
+                    .read()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .entries_len()
+            })
+            .collect()
+    }
+
+    fn shard<Q: ?Sized + Hash>(&self, key: &Q) -> &RwLock<SeparateChainingHashTable<K, V, H>> {
+        // Cada shard reparte sus claves con los bits bajos del hash; el shard se elige con los altos.
+        let hash = SeparateChainingHashTable::<K, V, H>::hash(key);
+        &self.shards[hash.rotate_left(usize::BITS / 2) % self.shards.len()]
+    }
+
+    fn read<Q: ?Sized + Hash>(
+        &self,
+        key: &Q,
+    ) -> RwLockReadGuard<'_, SeparateChainingHashTable<K, V, H>> {
+        self.shard(key)
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write<Q: ?Sized + Hash>(
+        &self,
+        key: &Q,
+    ) -> RwLockWriteGuard<'_, SeparateChainingHashTable<K, V, H>> {
+        self.shard(key)
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConcurrentHashTable;
+    use exceptions::Exceptions;
+    use std::thread;
+
+    #[test]
+    fn behaves_like_a_table_from_one_thread() {
+        let table: ConcurrentHashTable<String, u32> = ConcurrentHashTable::with_shards(4, 8);
+        table.insert("a", 1).unwrap();
+        assert_eq!(table.insert("a", 2), Err(Exceptions::DuplicateKey));
+        assert_eq!(table.put("a", 3), Some(1));
+        assert_eq!(table.get("a"), Ok(3));
+        assert!(table.contains_key("a"));
+        assert_eq!(table.remove("a"), Ok(3));
+        assert_eq!(table.get("a"), Err(Exceptions::KeyNotInitialized));
+        assert!(table.is_empty());
+        assert_eq!(table.shards_len(), 4);
+    }
+
+    #[test]
+    fn stress_from_many_threads() {
+        const THREADS: usize = 8;
+        const KEYS: usize = 2_000;
+        let table: ConcurrentHashTable<usize, usize> = ConcurrentHashTable::new(16);
+
+        thread::scope(|scope| {
+            for thread in 0..THREADS {
+                let table = &table;
+                scope.spawn(move || {
+                    for key in (thread * KEYS)..((thread + 1) * KEYS) {
+                        table.insert(key, key * 2).unwrap();
+                        table.update(usize::MAX, 0, |hits| *hits += 1);
+                    }
+                    for key in (thread * KEYS)..((thread + 1) * KEYS) {
+                        assert_eq!(table.get(&key), Ok(key * 2));
+                        if key % 2 == 0 {
+                            assert_eq!(table.remove(&key), Ok(key * 2));
+                        }
+                    }
+                });
+            }
+        });
+
+        assert_eq!(table.get(&usize::MAX), Ok(THREADS * KEYS));
+        assert_eq!(table.entries_len(), THREADS * KEYS / 2 + 1);
+        for key in 0..THREADS * KEYS {
+            assert_eq!(table.contains_key(&key), key % 2 == 1);
+        }
+        assert!(table.shard_lengths().iter().all(|&len| len > 0));
+    }
+}
@@ -0,0 +1,112 @@
+use crate::hasher::Fnv1aHasher;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+pub struct CountMinSketch<H: Hasher + Default = Fnv1aHasher> {
+    counters: Box<[usize]>,
+    width: usize,
+    depth: usize,
+    total: usize,
+    hasher: PhantomData<H>,
+}
+
+impl<H: Hasher + Default> CountMinSketch<H> {
+    #[must_use]
+    pub fn new(width: usize, depth: usize) -> Self {
+        assert!(
+            width > 0 && depth > 0,
+            "a count-min sketch needs a positive width and depth"
+        );
+        Self {
+            counters: vec![0; width * depth].into_boxed_slice(),
+            width,
+            depth,
+            total: 0,
+            hasher: PhantomData,
+        }
+    }
+
+    pub fn add<K: Hash + ?Sized>(&mut self, key: &K, count: usize) {
+        for row in 0..self.depth {
+            let index = self.index(row, key);
+            self.counters[index] += count;
+        }
+        self.total += count;
+    }
+
+    pub fn estimate<K: Hash + ?Sized>(&self, key: &K) -> usize {
+        (0..self.depth)
+            .map(|row| self.counters[self.index(row, key)])
+            .min()
+            .unwrap_or(0)
+    }
+
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub const fn depth(&self) -> usize {
+        self.depth
+    }
+
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.total
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn index<K: Hash + ?Sized>(&self, row: usize, key: &K) -> usize {
+        // Cada fila antepone su número a la clave para obtener una función hash distinta.
+        let mut hasher = H::default();
+        row.hash(&mut hasher);
+        key.hash(&mut hasher);
+        row * self.width + hasher.finish() as usize % self.width
+    }
+}
+
+impl<H: Hasher + Default> Debug for CountMinSketch<H> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        for (row, counters) in self.counters.chunks(self.width).enumerate() {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{counters:?}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CountMinSketch;
+    use crate::Counter;
+
+    #[test]
+    fn estimates_never_undercount() {
+        let mut sketch: CountMinSketch = CountMinSketch::new(16, 4);
+        let mut exact: Counter<u32> = Counter::new(64);
+        for value in 0..500_u32 {
+            let key = value % 37;
+            sketch.add(&key, 1);
+            exact.add(key);
+        }
+        for key in 0..37_u32 {
+            assert!(sketch.estimate(&key) >= exact.count(&key));
+        }
+        assert_eq!(sketch.total(), 500);
+    }
+
+    #[test]
+    fn wide_sketches_are_exact_for_few_keys() {
+        let mut sketch: CountMinSketch = CountMinSketch::new(1024, 4);
+        sketch.add("sol", 3);
+        sketch.add("luna", 2);
+        sketch.add("sol", 1);
+        assert_eq!(sketch.estimate("sol"), 4);
+        assert_eq!(sketch.estimate("luna"), 2);
+        assert_eq!(sketch.estimate("mar"), 0);
+    }
+}
@@ -0,0 +1,145 @@
+use crate::hasher::Fnv1aHasher;
+use crate::separate_chaining_hash_table::{
+    SeparateChainingHashTable, SeparateChainingHashTableIterator,
+};
+use std::borrow::Borrow;
+use std::cmp::Reverse;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+use std::hash::{Hash, Hasher};
+
+pub struct Counter<K: Clone + Hash + Eq, H: Hasher + Default = Fnv1aHasher> {
+    table: SeparateChainingHashTable<K, usize, H>,
+    total: usize,
+}
+
+impl<K: Clone + Hash + Eq, H: Hasher + Default> Counter<K, H> {
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            table: SeparateChainingHashTable::new(capacity.max(1)),
+            total: 0,
+        }
+    }
+
+    pub fn add<Q: Into<K>>(&mut self, key: Q) -> usize {
+        self.add_n(key, 1)
+    }
+
+    pub fn add_n<Q: Into<K>>(&mut self, key: Q, n: usize) -> usize {
+        let count = self.table.get_or_insert_with(key, || 0);
+        *count += n;
+        self.total += n;
+        *count
+    }
+
+    pub fn count<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> usize
+    where
+        K: Borrow<Q>,
+    {
+        self.table.get(key).copied().unwrap_or(0)
+    }
+
+    #[must_use]
+    pub fn most_common(&self, n: usize) -> Vec<(&K, usize)> {
+        let mut counts: Vec<(&K, usize)> = self.iter().map(|(key, count)| (key, *count)).collect();
+        counts.sort_by_key(|&(_, count)| Reverse(count));
+        counts.truncate(n);
+        counts
+    }
+
+    pub fn merge(&mut self, other: Self) {
+        self.total += other.total;
+        self.table
+            .merge(other.table, |current, incoming| current + incoming);
+    }
+
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.total
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.table.entries_len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    #[must_use]
+    pub fn iter(&self) -> SeparateChainingHashTableIterator<'_, K, usize> {
+        self.table.iter()
+    }
+}
+
+impl<K: Clone + Hash + Eq, H: Hasher + Default, Q: Into<K>> FromIterator<Q> for Counter<K, H> {
+    fn from_iter<I: IntoIterator<Item = Q>>(iter: I) -> Self {
+        let mut counter = Self::new(1);
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<K: Clone + Hash + Eq, H: Hasher + Default, Q: Into<K>> Extend<Q> for Counter<K, H> {
+    fn extend<I: IntoIterator<Item = Q>>(&mut self, iter: I) {
+        for key in iter {
+            self.add(key);
+        }
+    }
+}
+
+impl<'a, K: Clone + Hash + Eq, H: Hasher + Default> IntoIterator for &'a Counter<K, H> {
+    type Item = (&'a K, &'a usize);
+    type IntoIter = SeparateChainingHashTableIterator<'a, K, usize>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: Clone + Hash + Eq + Debug, H: Hasher + Default> Debug for Counter<K, H> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "{:?}", self.table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Counter;
+
+    #[test]
+    fn counts_collected_keys() {
+        let counter: Counter<String> = "a b a c a b".split_whitespace().collect();
+        assert_eq!(counter.count("a"), 3);
+        assert_eq!(counter.count("b"), 2);
+        assert_eq!(counter.count("z"), 0);
+        assert_eq!(counter.len(), 3);
+        assert_eq!(counter.total(), 6);
+    }
+
+    #[test]
+    fn most_common_is_sorted_by_count() {
+        let mut counter: Counter<u32> = Counter::new(4);
+        counter.add_n(1_u32, 5);
+        counter.add_n(2_u32, 9);
+        counter.add_n(3_u32, 1);
+        assert_eq!(counter.add(3_u32), 2);
+
+        assert_eq!(counter.most_common(2), vec![(&2, 9), (&1, 5)]);
+        assert_eq!(counter.most_common(10).len(), 3);
+        assert!(counter.most_common(0).is_empty());
+    }
+
+    #[test]
+    fn merge_adds_the_counts() {
+        let mut left: Counter<String> = ["x", "y"].into_iter().collect();
+        let right: Counter<String> = ["y", "z", "z"].into_iter().collect();
+        left.merge(right);
+        assert_eq!(left.count("x"), 1);
+        assert_eq!(left.count("y"), 2);
+        assert_eq!(left.count("z"), 2);
+        assert_eq!(left.total(), 5);
+    }
+}
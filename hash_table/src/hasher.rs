@@ -0,0 +1,130 @@
+use std::hash::Hasher;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Fnv1aHasher {
+    state: u64,
+}
+
+impl Default for Fnv1aHasher {
+    fn default() -> Self {
+        Self {
+            state: FNV_OFFSET_BASIS,
+        }
+    }
+}
+
+impl Hasher for Fnv1aHasher {
+    fn finish(&self) -> u64 {
+        self.state
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state ^= u64::from(*byte);
+            self.state = self.state.wrapping_mul(FNV_PRIME);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NaiveSumHasher {
+    sum: u64,
+}
+
+impl Hasher for NaiveSumHasher {
+    fn finish(&self) -> u64 {
+        self.sum
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.sum = self.sum.wrapping_add(u64::from(*byte));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Fnv1aHasher, NaiveSumHasher};
+    use std::collections::HashSet;
+    use std::hash::{Hash, Hasher};
+
+    fn hash_with<H: Hasher + Default>(key: &str) -> u64 {
+        let mut hasher = H::default();
+        key.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn bucket_loads<H: Hasher + Default>(keys: &[String], buckets: u64) -> Vec<usize> {
+        let mut loads = vec![0; usize::try_from(buckets).unwrap()];
+        for key in keys {
+            loads[usize::try_from(hash_with::<H>(key) % buckets).unwrap()] += 1;
+        }
+        loads
+    }
+
+    fn numeric_keys() -> Vec<String> {
+        (0..1_000).map(|value| format!("{value:03}")).collect()
+    }
+
+    #[test]
+    fn fnv1a_matches_reference_vectors() {
+        let mut hasher = Fnv1aHasher::default();
+        assert_eq!(hasher.finish(), 0xcbf2_9ce4_8422_2325);
+        hasher.write(b"a");
+        assert_eq!(hasher.finish(), 0xaf63_dc4c_8601_ec8c);
+
+        let mut hasher = Fnv1aHasher::default();
+        hasher.write(b"foobar");
+        assert_eq!(hasher.finish(), 0x8594_4171_f739_67e8);
+    }
+
+    #[test]
+    fn naive_sum_collides_on_permutations() {
+        for (left, right) in [("ab", "ba"), ("01", "10"), ("abc", "cab")] {
+            assert_eq!(
+                hash_with::<NaiveSumHasher>(left),
+                hash_with::<NaiveSumHasher>(right)
+            );
+            assert_ne!(
+                hash_with::<Fnv1aHasher>(left),
+                hash_with::<Fnv1aHasher>(right)
+            );
+        }
+    }
+
+    #[test]
+    fn fnv1a_has_no_collisions_on_numeric_keys() {
+        let keys = numeric_keys();
+        let fnv: HashSet<u64> = keys
+            .iter()
+            .map(|key| hash_with::<Fnv1aHasher>(key))
+            .collect();
+        let naive: HashSet<u64> = keys
+            .iter()
+            .map(|key| hash_with::<NaiveSumHasher>(key))
+            .collect();
+
+        assert_eq!(fnv.len(), keys.len());
+        // "000" a "999" solo producen 28 sumas distintas.
+        assert_eq!(naive.len(), 28);
+    }
+
+    #[test]
+    fn fnv1a_spreads_keys_across_buckets() {
+        let keys = numeric_keys();
+        let buckets = 64;
+        let expected = keys.len() / 64;
+
+        let fnv = bucket_loads::<Fnv1aHasher>(&keys, buckets);
+        assert!(fnv.iter().all(|load| *load > 0));
+        assert!(fnv.iter().all(|load| *load < expected * 2));
+
+        let naive = bucket_loads::<NaiveSumHasher>(&keys, buckets);
+        assert!(naive.iter().filter(|load| **load > 0).count() <= 28);
+        assert!(naive.iter().any(|load| *load > expected * 4));
+    }
+}
@@ -1,11 +1,42 @@
+mod concurrent_hash_table;
+mod count_min_sketch;
+mod counter;
+mod hasher;
+mod linked_hash_map;
+mod multi_map;
+mod open_addressing_hash_table;
 mod separate_chaining_hash_table;
+mod ttl_cache;
 
+pub use concurrent_hash_table::ConcurrentHashTable;
+pub use count_min_sketch::CountMinSketch;
+pub use counter::Counter;
 use exceptions::Exceptions;
-pub use separate_chaining_hash_table::SeparateChainingHashTable;
+pub use hasher::{Fnv1aHasher, NaiveSumHasher};
+pub use linked_hash_map::{LinkedHashMap, LinkedHashMapIterator};
+pub use multi_map::{MultiMap, MultiMapIterator};
+pub use open_addressing_hash_table::{
+    DoubleHashing, LinearProbing, OpenAddressingHashTable, ProbeSequence, ProbeStats,
+    QuadraticProbing,
+};
+pub use separate_chaining_hash_table::{
+    Entry, SeparateChainingHashTable, SeparateChainingHashTableBuckets,
+    SeparateChainingHashTableDrain, SeparateChainingHashTableIntoIter,
+    SeparateChainingHashTableIterMut, SeparateChainingHashTableIterator,
+    SeparateChainingHashTableKeys, SeparateChainingHashTableValues,
+    SeparateChainingHashTableValuesMut, StringHashTable, TableStats,
+};
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+use std::thread;
+use std::time::Duration;
+pub use ttl_cache::TtlCache;
 
 pub fn ht_implementation() -> Result<(), Exceptions> {
     println!("Tabla hash con encadenamiento separado");
-    let mut table = SeparateChainingHashTable::new(6);
+    // La suma de bytes hace visibles las colisiones y el efecto del rehashing.
+    let mut table: StringHashTable<&str, NaiveSumHasher> =
+        SeparateChainingHashTable::with_manual_rehashing(6);
     println!("  1. Creación en blanco:\n    {table:?}");
     table.insert("00", "Cien")?;
     println!("  2. Ingresar datos:\n    {table:?}");
@@ -19,6 +50,16 @@ pub fn ht_implementation() -> Result<(), Exceptions> {
     table.insert("10", "Diez")?;
     let buckets_len = table.buckets_len();
     println!("  5. Colisiones (buckets en uso: {buckets_len}):\n    {table:?}");
+    let (key, permutation) = (String::from("01"), String::from("10"));
+    let naive = (
+        <StringHashTable<&str, NaiveSumHasher>>::hash(&key),
+        <StringHashTable<&str, NaiveSumHasher>>::hash(&permutation),
+    );
+    let fnv = (
+        <StringHashTable<&str>>::hash(&key),
+        <StringHashTable<&str>>::hash(&permutation),
+    );
+    println!("  5.1 Hash de una permutación (\"01\", \"10\"):\n    suma de bytes: {naive:?}, FNV-1a: {fnv:?}");
     table.insert("02", "Dos")?;
     table.insert("03", "Tres")?;
     table.insert("04", "Cuatro")?;
@@ -53,34 +94,215 @@ pub fn ht_implementation() -> Result<(), Exceptions> {
     table.insert("34", "Treinta y cuatro")?;
     table.insert("35", "Treinta y cinco")?;
     table.insert("36", "Treinta y seis")?;
-    /*
-        [06, 15, 24, 33]
-        [01, 10, 07, 16, 25, 34]
-        [02, 08, 11, 17, 20, 26, 35]
-        [03, 09, 12, 18, 21, 27, 30, 36]
-        [04, 13, 19, 22, 28, 31]
-        [05, 14, 23, 29, 32]
-     */
-    println!("  6.1 Rehashing (inicial):\n    {table:?}");
+    println!("  6.1 Rehashing (inicial):");
+    print_buckets(&table);
+    print_distribution(&table);
     table.rehashing(3);
-    /*
-        [06, 15, 24, 33, 03, 09, 12, 18, 21, 27, 30, 36]
-        [01, 10, 07, 16, 25, 34, 04, 13, 19, 22, 28, 31]
-        [02, 08, 11, 17, 20, 26, 35, 05, 14, 23, 29, 32]
-     */
-    println!("  6.2 Rehashing (decremento):\n    {table:?}");
+    println!("  6.2 Rehashing (decremento):");
+    print_buckets(&table);
+    print_distribution(&table);
     table.rehashing(9);
-    /*
-        [03, 12, 21, 30]
-        [04, 13, 22, 31]
-        [05, 14, 23, 32]
-        [06, 15, 24, 33]
-        [07, 16, 25, 34]
-        [08, 17, 26, 35]
-        [09, 18, 27, 36]
-        [01, 10, 19, 28]
-        [02, 11, 20, 29]
-     */
-    println!("  6.2 Rehashing (incremento):\n    {table:?}");
+    println!("  6.2 Rehashing (incremento):");
+    print_buckets(&table);
+    print_distribution(&table);
+    let automatic: SeparateChainingHashTable<u32, u32> =
+        (0..12).map(|value| (value, value * value)).collect();
+    let max_load_factor = automatic.max_load_factor();
+    let buckets_len = automatic.buckets_len();
+    let capacity = automatic.capacity();
+    println!("  7. Rehashing automático (factor de carga máximo: {max_load_factor:?}, buckets en uso: {buckets_len} de {capacity}):\n    {automatic:?}");
+    let stats = automatic.stats();
+    println!(
+        "    Estadísticas: factor de carga {:.2}, cadena promedio {:.2}, cadena más larga {}, buckets vacíos {}",
+        stats.load_factor(),
+        stats.average_chain_len(),
+        stats.longest_chain(),
+        stats.empty_buckets()
+    );
+    let sorted: Vec<_> = automatic.keys_sorted().collect();
+    println!("    Claves en orden: {sorted:?}");
+    let mut memo: SeparateChainingHashTable<u64, u64> = SeparateChainingHashTable::new(8);
+    let mut computed = 0;
+    for n in [10, 20, 10, 30, 20] {
+        memo.get_or_insert_with(n, || {
+            computed += 1;
+            (1..=n).sum()
+        });
+    }
+    println!("  8. Memoización (sumas calculadas: {computed} de 5 consultas):\n    {memo:?}");
+    memo.clear();
+    let capacity = memo.capacity();
+    println!("  9. Limpieza (capacidad conservada: {capacity}):\n    {memo:?}");
+    let mut words: Counter<String> = "el sol y la luna".split_whitespace().collect();
+    words.merge("la luna y el mar".split_whitespace().collect());
+    println!("  10. Combinación de conteos de palabras:\n    {words:?}");
+    let most_common = words.most_common(2);
+    println!("    Las más frecuentes: {most_common:?}");
+    let nine = table["09"];
+    println!("  11. Acceso por índice (table[\"09\"]: {nine})");
+    Ok(())
+}
+
+pub fn oa_implementation() -> Result<(), Exceptions> {
+    println!("Tabla hash con direccionamiento abierto");
+    let mut table: OpenAddressingHashTable<String, &str> = OpenAddressingHashTable::new(4);
+    let capacity = table.capacity();
+    println!("  1. Creación en blanco (capacidad: {capacity}):\n    {table:?}");
+    table.insert("Mercurio", "☿")?;
+    table.insert("Venus", "♀")?;
+    table.insert("Tierra", "♁")?;
+    let capacity = table.capacity();
+    println!("  2. Ingresar datos (capacidad: {capacity}):\n    {table:?}");
+    table.remove("Venus")?;
+    println!("  3. Eliminar datos (la posición queda marcada como borrada):\n    {table:?}");
+    println!("  4. Estrategias de sondeo (48 claves en 64 posiciones):");
+    compare_probes::<LinearProbing>()?;
+    compare_probes::<QuadraticProbing>()?;
+    compare_probes::<DoubleHashing>()?;
+    Ok(())
+}
+
+pub fn lhm_implementation() -> Result<(), Exceptions> {
+    println!("Tabla hash enlazada (orden de inserción)");
+    let mut map: LinkedHashMap<String, &str> = LinkedHashMap::new(4);
+    println!("  1. Creación en blanco:\n    {map:?}");
+    map.insert("Neptuno", "♆")?;
+    map.insert("Mercurio", "☿")?;
+    map.insert("Tierra", "♁")?;
+    map.insert("Marte", "♂")?;
+    println!("  2. Ingresar datos (se conserva el orden):\n    {map:?}");
+    map.put("Tierra", "🜨");
+    println!("  3. Sobrescribir un valor (mantiene su posición):\n    {map:?}");
+    map.remove("Mercurio")?;
+    map.insert("Mercurio", "☿")?;
+    println!("  4. Eliminar y volver a ingresar (pasa al final):\n    {map:?}");
+    let keys = map.get_keys();
+    println!("  5. Claves en orden:\n    {keys:?}");
+    Ok(())
+}
+
+pub fn mm_implementation() -> Result<(), Exceptions> {
+    println!("Multimapa (varios valores por clave)");
+    let mut index: MultiMap<String, usize> = MultiMap::new(8);
+    let lines = ["el sol sale", "la luna sale", "el sol y la luna"];
+    for (number, line) in lines.iter().enumerate() {
+        for word in line.split_whitespace() {
+            index.insert(word, number + 1);
+        }
+    }
+    let keys_len = index.keys_len();
+    let values_len = index.values_len();
+    println!(
+        "  1. Índice invertido (palabras: {keys_len}, apariciones: {values_len}):\n    {index:?}"
+    );
+    let lines = index.get_all("sol")?;
+    println!("  2. Líneas con \"sol\":\n    {lines:?}");
+    index.remove_one("luna", &2)?;
+    println!("  3. Eliminar una aparición (\"luna\" en la línea 2):\n    {index:?}");
+    let removed = index.remove_all("sale")?;
+    println!("  4. Eliminar todas las apariciones (\"sale\": {removed:?}):\n    {index:?}");
+    Ok(())
+}
+
+pub fn cht_implementation() -> Result<(), Exceptions> {
+    println!("Tabla hash concurrente (shards con bloqueo)");
+    let table: ConcurrentHashTable<String, usize> = ConcurrentHashTable::with_shards(4, 16);
+    let words = ["sol", "luna", "mar", "sol", "tierra", "luna", "sol"];
+    thread::scope(|scope| {
+        for worker in 0..3 {
+            let table = &table;
+            scope.spawn(move || {
+                for word in words.iter().skip(worker).step_by(3) {
+                    table.update(*word, 0, |count| *count += 1);
+                }
+            });
+        }
+    });
+    let shard_lengths = table.shard_lengths();
+    println!("  1. Conteo desde 3 hilos (entradas por shard: {shard_lengths:?})");
+    let sol = table.get("sol")?;
+    let luna = table.get("luna")?;
+    println!("  2. Consultar (sol: {sol}, luna: {luna})");
+    table.remove("mar")?;
+    let entries_len = table.entries_len();
+    println!("  3. Eliminar \"mar\" (entradas: {entries_len})");
+    Ok(())
+}
+
+pub fn ttl_implementation() -> Result<(), Exceptions> {
+    println!("Caché con expiración (TTL)");
+    let mut cache: TtlCache<String, &str> = TtlCache::new(4, Duration::from_secs(60));
+    cache.put("sesión", "activa");
+    cache.put_with_ttl("código", "1234", Duration::ZERO);
+    let entries_len = cache.entries_len();
+    println!("  1. Insertar (entradas almacenadas: {entries_len}):\n    {cache:?}");
+    let expired = cache.get("código").is_err();
+    println!("  2. Consultar una entrada expirada (no encontrada: {expired})");
+    let purged = cache.purge_expired();
+    let entries_len = cache.entries_len();
+    println!(
+        "  3. Purgar expiradas (eliminadas: {purged}, entradas: {entries_len}):\n    {cache:?}"
+    );
+    Ok(())
+}
+
+pub fn cms_implementation() -> Result<(), Exceptions> {
+    println!("Count-Min Sketch (conteo aproximado)");
+    let text = "el sol y la luna y el mar y la tierra bajo el sol de la tarde";
+    let mut sketch: CountMinSketch = CountMinSketch::new(8, 3);
+    for word in text.split_whitespace() {
+        sketch.add(word, 1);
+    }
+    let exact: Counter<String> = text.split_whitespace().collect();
+    let (width, depth) = (sketch.width(), sketch.depth());
+    println!("  1. Contadores ({depth} filas de {width}):");
+    for row in format!("{sketch:?}").lines() {
+        println!("    {row}");
+    }
+    println!("  2. Estimación frente al conteo exacto:");
+    for word in ["el", "y", "sol", "mar", "cielo"] {
+        let estimate = sketch.estimate(word);
+        let count = exact.count(word);
+        println!("    {word:?}: estimado {estimate}, exacto {count}");
+    }
+    Ok(())
+}
+
+fn print_buckets<K: Clone + Hash + Eq + Debug, V: Clone, H: Hasher + Default>(
+    table: &SeparateChainingHashTable<K, V, H>,
+) {
+    for (index, entries) in table.iter_buckets() {
+        let keys: Vec<&K> = entries.iter().map(Entry::get_key).collect();
+        println!("    [{index}] {keys:?}");
+    }
+}
+
+fn print_distribution<K: Clone + Hash + Eq, V: Clone, H: Hasher + Default>(
+    table: &SeparateChainingHashTable<K, V, H>,
+) {
+    println!(
+        "    Longitudes de las cadenas: {:?} (máxima: {}, histograma: {:?})",
+        table.bucket_lengths(),
+        table.max_chain_len(),
+        table.collision_histogram()
+    );
+}
+
+fn compare_probes<P: ProbeSequence>() -> Result<(), Exceptions> {
+    // La suma de bytes agrupa las claves y exagera las diferencias entre estrategias.
+    let mut table: OpenAddressingHashTable<String, usize, P, NaiveSumHasher> =
+        OpenAddressingHashTable::new(64);
+    for value in 0..48 {
+        table.insert(format!("{value:02}"), value)?;
+    }
+    let stats = table.probe_stats();
+    println!(
+        "    {}: {} sondeos en {} operaciones (promedio: {:.2}, máximo: {})",
+        table.strategy(),
+        stats.probes(),
+        stats.operations(),
+        stats.average(),
+        stats.longest()
+    );
     Ok(())
 }
@@ -0,0 +1,39 @@
+use std::slice::Iter;
+
+pub struct LinkedHashMapIterator<'a, K, V> {
+    entries: Iter<'a, Option<(K, V)>>,
+    remaining: usize,
+}
+
+impl<'a, K, V> LinkedHashMapIterator<'a, K, V> {
+    pub(super) fn new(entries: &'a [Option<(K, V)>], entries_len: usize) -> Self {
+        Self {
+            entries: entries.iter(),
+            remaining: entries_len,
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for LinkedHashMapIterator<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value) = self.entries.find_map(Option::as_ref)?;
+        self.remaining -= 1;
+        Some((key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for LinkedHashMapIterator<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (key, value) = self.entries.rfind(|entry| entry.is_some())?.as_ref()?;
+        self.remaining -= 1;
+        Some((key, value))
+    }
+}
+
+impl<K, V> ExactSizeIterator for LinkedHashMapIterator<'_, K, V> {}
@@ -0,0 +1,233 @@
+mod iterator;
+
+use crate::hasher::Fnv1aHasher;
+use crate::separate_chaining_hash_table::SeparateChainingHashTable;
+use exceptions::Exceptions;
+pub use iterator::LinkedHashMapIterator;
+use std::borrow::Borrow;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+use std::hash::{Hash, Hasher};
+use std::mem;
+
+pub struct LinkedHashMap<K: Clone + Hash + Eq, V: Clone, H: Hasher + Default = Fnv1aHasher> {
+    // Posición de cada clave dentro de `entries`, que conserva el orden de inserción.
+    indices: SeparateChainingHashTable<K, usize, H>,
+    entries: Vec<Option<(K, V)>>,
+    deleted_len: usize,
+}
+
+impl<K: Clone + Hash + Eq, V: Clone, H: Hasher + Default> LinkedHashMap<K, V, H> {
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            indices: SeparateChainingHashTable::new(capacity.max(1)),
+            entries: Vec::with_capacity(capacity),
+            deleted_len: 0,
+        }
+    }
+
+    pub fn get<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Result<&V, Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        let position = *self.indices.get(key)?;
+        match &self.entries[position] {
+            Some((_, value)) => Ok(value),
+            None => Err(Exceptions::KeyNotInitialized),
+        }
+    }
+
+    pub fn contains_key<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.indices.contains_key(key)
+    }
+
+    pub fn get_mut<Q: ?Sized + Hash + Eq>(&mut self, key: &Q) -> Result<&mut V, Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        let position = *self.indices.get(key)?;
+        match &mut self.entries[position] {
+            Some((_, value)) => Ok(value),
+            None => Err(Exceptions::KeyNotInitialized),
+        }
+    }
+
+    pub fn insert<Q: Into<K>>(&mut self, key: Q, value: V) -> Result<(), Exceptions> {
+        let key: K = key.into();
+        self.indices.insert(key.clone(), self.entries.len())?;
+        self.entries.push(Some((key, value)));
+        Ok(())
+    }
+
+    pub fn put<Q: Into<K>>(&mut self, key: Q, value: V) -> Option<V> {
+        let key: K = key.into();
+        if let Ok(value_mut) = self.get_mut(&key) {
+            return Some(mem::replace(value_mut, value));
+        }
+        self.indices.put(key.clone(), self.entries.len());
+        self.entries.push(Some((key, value)));
+        None
+    }
+
+    pub fn remove<Q: ?Sized + Hash + Eq>(&mut self, key: &Q) -> Result<V, Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    pub fn remove_entry<Q: ?Sized + Hash + Eq>(&mut self, key: &Q) -> Result<(K, V), Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        let position = self.indices.remove(key)?;
+        let entry = self.entries[position]
+            .take()
+            .ok_or(Exceptions::KeyNotInitialized)?;
+        self.deleted_len += 1;
+        if self.deleted_len > self.indices.entries_len() {
+            self.compact();
+        }
+        Ok(entry)
+    }
+
+    #[must_use]
+    pub fn get_keys(&self) -> Vec<&K> {
+        self.iter().map(|(key, _)| key).collect()
+    }
+
+    #[must_use]
+    pub fn get_values(&self) -> Vec<&V> {
+        self.iter().map(|(_, value)| value).collect()
+    }
+
+    #[must_use]
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.iter().next()
+    }
+
+    #[must_use]
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.iter().next_back()
+    }
+
+    #[must_use]
+    pub const fn entries_len(&self) -> usize {
+        self.indices.entries_len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    #[must_use]
+    pub fn iter(&self) -> LinkedHashMapIterator<'_, K, V> {
+        LinkedHashMapIterator::new(&self.entries, self.entries_len())
+    }
+
+    fn compact(&mut self) {
+        self.entries.retain(Option::is_some);
+        for (position, (key, _)) in self.entries.iter().flatten().enumerate() {
+            *self
+                .indices
+                .get_mut(key)
+                .expect("every live entry is indexed") = position;
+        }
+        self.deleted_len = 0;
+    }
+}
+
+impl<'a, K: Clone + Hash + Eq, V: Clone, H: Hasher + Default> IntoIterator
+    for &'a LinkedHashMap<K, V, H>
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = LinkedHashMapIterator<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: Clone + Hash + Eq + Debug, V: Clone + Debug, H: Hasher + Default> Debug
+    for LinkedHashMap<K, V, H>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "{{")?;
+        for (index, (key, value)) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{key:?}: {value:?}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinkedHashMap;
+    use exceptions::Exceptions;
+
+    #[test]
+    fn iterates_in_insertion_order() {
+        let mut map: LinkedHashMap<String, usize> = LinkedHashMap::new(2);
+        for (value, key) in ["zeta", "alfa", "mu", "beta", "omega"].iter().enumerate() {
+            map.insert(*key, value).unwrap();
+        }
+        assert_eq!(map.get_keys(), vec!["zeta", "alfa", "mu", "beta", "omega"]);
+        assert_eq!(map.get_values(), vec![&0, &1, &2, &3, &4]);
+        assert_eq!(
+            format!("{map:?}"),
+            r#"{"zeta": 0, "alfa": 1, "mu": 2, "beta": 3, "omega": 4}"#
+        );
+    }
+
+    #[test]
+    fn put_keeps_the_original_position() {
+        let mut map: LinkedHashMap<String, usize> = LinkedHashMap::new(4);
+        map.insert("a", 1).unwrap();
+        map.insert("b", 2).unwrap();
+        assert_eq!(map.put("a", 10), Some(1));
+        assert_eq!(map.put("c", 3), None);
+        assert_eq!(map.insert("b", 20), Err(Exceptions::DuplicateKey));
+        assert_eq!(map.get_keys(), vec!["a", "b", "c"]);
+        assert_eq!(map.get("a"), Ok(&10));
+    }
+
+    #[test]
+    fn removed_keys_are_reinserted_at_the_end() {
+        let mut map: LinkedHashMap<u32, u32> = LinkedHashMap::new(4);
+        for key in 0..4_u32 {
+            map.insert(key, key).unwrap();
+        }
+        assert_eq!(map.remove(&1_u32), Ok(1));
+        assert_eq!(map.remove(&1_u32), Err(Exceptions::KeyNotInitialized));
+        map.insert(1_u32, 100).unwrap();
+        assert_eq!(map.get_keys(), vec![&0, &2, &3, &1]);
+        assert_eq!(map.first(), Some((&0, &0)));
+        assert_eq!(map.last(), Some((&1, &100)));
+    }
+
+    #[test]
+    fn compaction_keeps_positions_consistent() {
+        let mut map: LinkedHashMap<u32, u32> = LinkedHashMap::new(4);
+        for key in 0..20_u32 {
+            map.insert(key, key * 2).unwrap();
+        }
+        for key in (0..20_u32).filter(|key| key % 3 != 0) {
+            assert_eq!(map.remove_entry(&key), Ok((key, key * 2)));
+        }
+        assert_eq!(map.entries_len(), 7);
+        assert_eq!(map.iter().len(), 7);
+        assert_eq!(map.get_keys(), vec![&0, &3, &6, &9, &12, &15, &18]);
+        for key in (0..20_u32).step_by(3) {
+            assert_eq!(map.get(&key), Ok(&(key * 2)));
+        }
+        *map.get_mut(&9_u32).unwrap() += 1;
+        assert_eq!(map.get(&9_u32), Ok(&19));
+    }
+}
@@ -0,0 +1,44 @@
+use crate::separate_chaining_hash_table::SeparateChainingHashTableIterator;
+use std::slice::Iter;
+
+pub struct MultiMapIterator<'a, K: Clone, V: Clone> {
+    entries: SeparateChainingHashTableIterator<'a, K, Vec<V>>,
+    current: Option<(&'a K, Iter<'a, V>)>,
+    remaining: usize,
+}
+
+impl<'a, K: Clone, V: Clone> MultiMapIterator<'a, K, V> {
+    pub(super) const fn new(
+        entries: SeparateChainingHashTableIterator<'a, K, Vec<V>>,
+        values_len: usize,
+    ) -> Self {
+        Self {
+            entries,
+            current: None,
+            remaining: values_len,
+        }
+    }
+}
+
+impl<'a, K: Clone + Eq, V: Clone> Iterator for MultiMapIterator<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((key, values)) = &mut self.current {
+                if let Some(value) = values.next() {
+                    self.remaining -= 1;
+                    return Some((key, value));
+                }
+            }
+            let (key, values) = self.entries.next()?;
+            self.current = Some((key, values.iter()));
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K: Clone + Eq, V: Clone> ExactSizeIterator for MultiMapIterator<'_, K, V> {}
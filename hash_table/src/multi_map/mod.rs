@@ -0,0 +1,169 @@
+mod iterator;
+
+use crate::hasher::Fnv1aHasher;
+use crate::separate_chaining_hash_table::SeparateChainingHashTable;
+use exceptions::Exceptions;
+pub use iterator::MultiMapIterator;
+use std::borrow::Borrow;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+use std::hash::{Hash, Hasher};
+
+pub struct MultiMap<K: Clone + Hash + Eq, V: Clone, H: Hasher + Default = Fnv1aHasher> {
+    table: SeparateChainingHashTable<K, Vec<V>, H>,
+    values_len: usize,
+}
+
+impl<K: Clone + Hash + Eq, V: Clone, H: Hasher + Default> MultiMap<K, V, H> {
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            table: SeparateChainingHashTable::new(capacity.max(1)),
+            values_len: 0,
+        }
+    }
+
+    pub fn insert<Q: Into<K>>(&mut self, key: Q, value: V) {
+        self.table.get_or_insert_with(key, Vec::new).push(value);
+        self.values_len += 1;
+    }
+
+    pub fn get_all<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Result<&[V], Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        self.table.get(key).map(Vec::as_slice)
+    }
+
+    pub fn contains_key<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.table.contains_key(key)
+    }
+
+    pub fn remove_one<Q: ?Sized + Hash + Eq>(&mut self, key: &Q, value: &V) -> Result<V, Exceptions>
+    where
+        K: Borrow<Q>,
+        V: PartialEq,
+    {
+        let values = self.table.get_mut(key)?;
+        let position = values
+            .iter()
+            .position(|current| current == value)
+            .ok_or_else(|| Exceptions::NoSuchElement(String::from("Value not found")))?;
+        let removed = values.remove(position);
+        if values.is_empty() {
+            self.table.remove(key)?;
+        }
+        self.values_len -= 1;
+        Ok(removed)
+    }
+
+    pub fn remove_all<Q: ?Sized + Hash + Eq>(&mut self, key: &Q) -> Result<Vec<V>, Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        let values = self.table.remove(key)?;
+        self.values_len -= values.len();
+        Ok(values)
+    }
+
+    #[must_use]
+    pub fn get_keys(&self) -> Vec<&K> {
+        self.table.get_keys()
+    }
+
+    #[must_use]
+    pub const fn keys_len(&self) -> usize {
+        self.table.entries_len()
+    }
+
+    #[must_use]
+    pub const fn values_len(&self) -> usize {
+        self.values_len
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.values_len == 0
+    }
+
+    #[must_use]
+    pub fn iter(&self) -> MultiMapIterator<'_, K, V> {
+        MultiMapIterator::new(self.table.iter(), self.values_len)
+    }
+}
+
+impl<'a, K: Clone + Hash + Eq, V: Clone, H: Hasher + Default> IntoIterator
+    for &'a MultiMap<K, V, H>
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = MultiMapIterator<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: Clone + Hash + Eq + Debug, V: Clone + Debug, H: Hasher + Default> Debug
+    for MultiMap<K, V, H>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "{:?}", self.table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MultiMap;
+    use exceptions::Exceptions;
+
+    #[test]
+    fn keeps_every_value_per_key_in_insertion_order() {
+        let mut map: MultiMap<String, u32> = MultiMap::new(4);
+        map.insert("a", 1);
+        map.insert("b", 2);
+        map.insert("a", 3);
+        map.insert("a", 1);
+
+        assert_eq!(map.get_all("a"), Ok(&[1, 3, 1][..]));
+        assert_eq!(map.get_all("b"), Ok(&[2][..]));
+        assert_eq!(map.get_all("c"), Err(Exceptions::KeyNotInitialized));
+        assert_eq!(map.keys_len(), 2);
+        assert_eq!(map.values_len(), 4);
+    }
+
+    #[test]
+    fn remove_one_drops_the_key_with_its_last_value() {
+        let mut map: MultiMap<String, u32> = MultiMap::new(4);
+        map.insert("a", 1);
+        map.insert("a", 2);
+
+        assert_eq!(map.remove_one("a", &1), Ok(1));
+        assert_eq!(
+            map.remove_one("a", &1),
+            Err(Exceptions::NoSuchElement(String::from("Value not found")))
+        );
+        assert_eq!(map.remove_one("a", &2), Ok(2));
+        assert!(!map.contains_key("a"));
+        assert_eq!(map.remove_one("a", &2), Err(Exceptions::KeyNotInitialized));
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn remove_all_and_flattened_iteration() {
+        let mut map: MultiMap<u32, u32> = MultiMap::new(2);
+        for value in 0..12_u32 {
+            map.insert(value % 3, value);
+        }
+        let iter = map.iter();
+        assert_eq!(iter.len(), 12);
+        let mut pairs: Vec<(u32, u32)> = iter.map(|(key, value)| (*key, *value)).collect();
+        pairs.sort_unstable();
+        assert_eq!(pairs[..4], [(0, 0), (0, 3), (0, 6), (0, 9)]);
+
+        assert_eq!(map.remove_all(&1_u32), Ok(vec![1, 4, 7, 10]));
+        assert_eq!(map.values_len(), 8);
+        assert!(map.iter().all(|(key, _)| *key != 1));
+    }
+}
@@ -0,0 +1,25 @@
+use super::Slot;
+use std::slice::Iter;
+
+pub struct OpenAddressingHashTableIterator<'a, K, V> {
+    slots: Iter<'a, Slot<K, V>>,
+}
+
+impl<'a, K, V> OpenAddressingHashTableIterator<'a, K, V> {
+    pub(super) fn new(slots: &'a [Slot<K, V>]) -> Self {
+        Self {
+            slots: slots.iter(),
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for OpenAddressingHashTableIterator<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots.find_map(|slot| match slot {
+            Slot::Occupied(key, value) => Some((key, value)),
+            Slot::Empty | Slot::Deleted => None,
+        })
+    }
+}
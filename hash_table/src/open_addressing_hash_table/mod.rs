@@ -0,0 +1,306 @@
+mod iterator;
+mod probe;
+mod stats;
+
+use crate::hasher::Fnv1aHasher;
+use exceptions::Exceptions;
+use iterator::OpenAddressingHashTableIterator;
+pub use probe::{DoubleHashing, LinearProbing, ProbeSequence, QuadraticProbing};
+pub use stats::ProbeStats;
+use std::borrow::Borrow;
+use std::cell::Cell;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::mem;
+
+#[derive(Clone)]
+enum Slot<K, V> {
+    Empty,
+    Occupied(K, V),
+    Deleted,
+}
+
+pub struct OpenAddressingHashTable<
+    K: Clone + Hash + Eq,
+    V: Clone,
+    P: ProbeSequence = LinearProbing,
+    H: Hasher + Default = Fnv1aHasher,
+> {
+    slots: Box<[Slot<K, V>]>,
+    entries_len: usize,
+    deleted_len: usize,
+    stats: Cell<ProbeStats>,
+    strategy: PhantomData<(P, H)>,
+}
+
+impl<K: Clone + Hash + Eq, V: Clone, P: ProbeSequence, H: Hasher + Default>
+    OpenAddressingHashTable<K, V, P, H>
+{
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            slots: vec![Slot::Empty; capacity.next_power_of_two()].into_boxed_slice(),
+            entries_len: 0,
+            deleted_len: 0,
+            stats: Cell::new(ProbeStats::default()),
+            strategy: PhantomData,
+        }
+    }
+
+    pub fn get<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Result<&V, Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        match self.find(key) {
+            Ok(index) => match &self.slots[index] {
+                Slot::Occupied(_, value) => Ok(value),
+                Slot::Empty | Slot::Deleted => Err(Exceptions::KeyNotInitialized),
+            },
+            Err(_) => Err(Exceptions::KeyNotInitialized),
+        }
+    }
+
+    pub fn get_mut<Q: ?Sized + Hash + Eq>(&mut self, key: &Q) -> Result<&mut V, Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        match self.find(key) {
+            Ok(index) => match &mut self.slots[index] {
+                Slot::Occupied(_, value) => Ok(value),
+                Slot::Empty | Slot::Deleted => Err(Exceptions::KeyNotInitialized),
+            },
+            Err(_) => Err(Exceptions::KeyNotInitialized),
+        }
+    }
+
+    pub fn insert<Q: Into<K>>(&mut self, key: Q, value: V) -> Result<(), Exceptions> {
+        let key: K = key.into();
+        let index = match self.find(&key) {
+            Ok(_) => return Err(Exceptions::DuplicateKey),
+            Err(Some(index))
+                if !self.exceeds_load_factor(self.entries_len + self.deleted_len + 1) =>
+            {
+                index
+            }
+            Err(_) => {
+                self.rehashing();
+                self.vacant(&key)
+            }
+        };
+        if matches!(self.slots[index], Slot::Deleted) {
+            self.deleted_len -= 1;
+        }
+        self.slots[index] = Slot::Occupied(key, value);
+        self.entries_len += 1;
+        Ok(())
+    }
+
+    pub fn remove<Q: ?Sized + Hash + Eq>(&mut self, key: &Q) -> Result<V, Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        let Ok(index) = self.find(key) else {
+            return Err(Exceptions::KeyNotInitialized);
+        };
+        match mem::replace(&mut self.slots[index], Slot::Deleted) {
+            Slot::Occupied(_, value) => {
+                self.entries_len -= 1;
+                self.deleted_len += 1;
+                Ok(value)
+            }
+            Slot::Empty | Slot::Deleted => Err(Exceptions::KeyNotInitialized),
+        }
+    }
+
+    #[must_use]
+    pub const fn entries_len(&self) -> usize {
+        self.entries_len
+    }
+
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.entries_len == 0
+    }
+
+    #[must_use]
+    pub fn strategy(&self) -> &'static str {
+        P::NAME
+    }
+
+    #[must_use]
+    pub fn probe_stats(&self) -> ProbeStats {
+        self.stats.get()
+    }
+
+    pub fn reset_probe_stats(&mut self) {
+        self.stats.set(ProbeStats::default());
+    }
+
+    pub fn hash<Q: ?Sized + Hash>(key: &Q) -> usize {
+        let mut hasher = H::default();
+        key.hash(&mut hasher);
+        hasher.finish() as usize
+    }
+
+    #[must_use]
+    pub fn iter(&self) -> OpenAddressingHashTableIterator<'_, K, V> {
+        OpenAddressingHashTableIterator::new(&self.slots)
+    }
+
+    // `Ok(posición)` si la clave existe; si no, `Err` con la primera posición libre del recorrido.
+    fn find<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Result<usize, Option<usize>>
+    where
+        K: Borrow<Q>,
+    {
+        let capacity = self.slots.len();
+        let hash = Self::hash(key);
+        let mut free: Option<usize> = None;
+        for attempt in 0..capacity {
+            let index = hash.wrapping_add(P::offset(hash, attempt)) % capacity;
+            match &self.slots[index] {
+                Slot::Occupied(current, _) if current.borrow() == key => {
+                    self.record(attempt + 1);
+                    return Ok(index);
+                }
+                Slot::Occupied(..) => {}
+                Slot::Deleted => {
+                    free = free.or(Some(index));
+                }
+                Slot::Empty => {
+                    self.record(attempt + 1);
+                    return Err(free.or(Some(index)));
+                }
+            }
+        }
+        self.record(capacity);
+        Err(free)
+    }
+
+    // Primera posición vacía del recorrido de `key`, sin registrar sondeos. Solo se usa tras un rehashing,
+    // cuando no quedan posiciones borradas y el factor de carga garantiza que hay posiciones vacías.
+    fn vacant(&self, key: &K) -> usize {
+        let capacity = self.slots.len();
+        let hash = Self::hash(key);
+        (0..capacity)
+            .map(|attempt| hash.wrapping_add(P::offset(hash, attempt)) % capacity)
+            .find(|index| matches!(self.slots[*index], Slot::Empty))
+            .expect("rehashing leaves empty slots")
+    }
+
+    const fn exceeds_load_factor(&self, used: usize) -> bool {
+        used * 4 > self.slots.len() * 3
+    }
+
+    fn record(&self, probes: usize) {
+        let mut stats = self.stats.get();
+        stats.record(probes);
+        self.stats.set(stats);
+    }
+
+    fn rehashing(&mut self) {
+        let capacity = if self.exceeds_load_factor(self.entries_len + 1) {
+            (self.slots.len() * 2).max(1)
+        } else {
+            self.slots.len()
+        };
+        let slots = mem::replace(
+            &mut self.slots,
+            vec![Slot::Empty; capacity].into_boxed_slice(),
+        );
+        self.deleted_len = 0;
+        for slot in slots {
+            if let Slot::Occupied(key, value) = slot {
+                let index = self.vacant(&key);
+                self.slots[index] = Slot::Occupied(key, value);
+            }
+        }
+    }
+}
+
+impl<'a, K: Clone + Hash + Eq, V: Clone, P: ProbeSequence, H: Hasher + Default> IntoIterator
+    for &'a OpenAddressingHashTable<K, V, P, H>
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = OpenAddressingHashTableIterator<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: Clone + Hash + Eq + Debug, V: Clone + Debug, P: ProbeSequence, H: Hasher + Default> Debug
+    for OpenAddressingHashTable<K, V, P, H>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "{{")?;
+        for (index, (key, value)) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{key:?}: {value:?}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DoubleHashing, OpenAddressingHashTable, ProbeSequence, QuadraticProbing};
+
+    fn round_trip<P: ProbeSequence>() {
+        let mut table: OpenAddressingHashTable<u32, u32, P> = OpenAddressingHashTable::new(4);
+        for value in 0..100_u32 {
+            table.insert(value, value * 2).unwrap();
+        }
+        assert_eq!(table.entries_len(), 100);
+        assert!(table.capacity() * 3 >= table.entries_len() * 4);
+
+        for value in (0..100_u32).step_by(2) {
+            assert_eq!(table.remove(&value), Ok(value * 2));
+        }
+        for value in 0..100_u32 {
+            assert_eq!(table.get(&value).is_ok(), value % 2 == 1);
+        }
+        assert!(table.insert(1_u32, 0).is_err());
+        table.insert(0_u32, 7).unwrap();
+        assert_eq!(table.get(&0_u32), Ok(&7));
+        assert_eq!(table.iter().count(), 51);
+    }
+
+    #[test]
+    fn every_strategy_supports_insert_get_and_remove() {
+        round_trip::<super::LinearProbing>();
+        round_trip::<QuadraticProbing>();
+        round_trip::<DoubleHashing>();
+    }
+
+    #[test]
+    fn tombstones_do_not_break_probe_chains() {
+        let mut table: OpenAddressingHashTable<u32, u32> = OpenAddressingHashTable::new(64);
+        for value in 0..40_u32 {
+            table.insert(value, value).unwrap();
+        }
+        for value in 0..39_u32 {
+            table.remove(&value).unwrap();
+        }
+        assert_eq!(table.get(&39_u32), Ok(&39));
+    }
+
+    #[test]
+    fn probe_stats_count_every_lookup() {
+        let mut table: OpenAddressingHashTable<u32, u32> = OpenAddressingHashTable::new(8);
+        table.insert(1_u32, 1).unwrap();
+        table.reset_probe_stats();
+        table.get(&1_u32).unwrap();
+        let stats = table.probe_stats();
+        assert_eq!(stats.operations(), 1);
+        assert_eq!(stats.longest(), stats.probes());
+        assert!(stats.average() >= 1.0);
+    }
+}
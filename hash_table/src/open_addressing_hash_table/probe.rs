@@ -0,0 +1,70 @@
+// Parte fraccionaria de la razón áurea escalada a 64 bits (hashing de Fibonacci).
+const GOLDEN_RATIO: u64 = 0x9e37_79b9_7f4a_7c15;
+
+pub trait ProbeSequence {
+    const NAME: &'static str;
+
+    // Desplazamiento respecto a `hash` en el intento número `attempt` (empezando en `0`).
+    fn offset(hash: usize, attempt: usize) -> usize;
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LinearProbing;
+
+impl ProbeSequence for LinearProbing {
+    const NAME: &'static str = "Sondeo lineal";
+
+    fn offset(_hash: usize, attempt: usize) -> usize {
+        attempt
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct QuadraticProbing;
+
+impl ProbeSequence for QuadraticProbing {
+    const NAME: &'static str = "Sondeo cuadrático";
+
+    // Números triangulares: con una capacidad potencia de dos recorren todas las posiciones.
+    fn offset(_hash: usize, attempt: usize) -> usize {
+        attempt.wrapping_mul(attempt.wrapping_add(1)) / 2
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DoubleHashing;
+
+impl ProbeSequence for DoubleHashing {
+    const NAME: &'static str = "Doble hashing";
+
+    // El paso es un segundo hash (los bits altos del hash multiplicado por la razón áurea),
+    // forzado a ser impar para recorrer todas las posiciones.
+    fn offset(hash: usize, attempt: usize) -> usize {
+        let step = (hash.wrapping_mul(GOLDEN_RATIO as usize) >> (usize::BITS / 2)) | 1;
+        attempt.wrapping_mul(step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DoubleHashing, LinearProbing, ProbeSequence, QuadraticProbing};
+    use std::collections::HashSet;
+
+    fn visits_every_slot<P: ProbeSequence>(hash: usize, capacity: usize) -> bool {
+        let slots: HashSet<usize> = (0..capacity)
+            .map(|attempt| hash.wrapping_add(P::offset(hash, attempt)) % capacity)
+            .collect();
+        slots.len() == capacity
+    }
+
+    #[test]
+    fn every_strategy_visits_every_slot_of_a_power_of_two_table() {
+        for capacity in [1, 2, 8, 64] {
+            for hash in [0, 7, 0xdead_beef, usize::MAX] {
+                assert!(visits_every_slot::<LinearProbing>(hash, capacity));
+                assert!(visits_every_slot::<QuadraticProbing>(hash, capacity));
+                assert!(visits_every_slot::<DoubleHashing>(hash, capacity));
+            }
+        }
+    }
+}
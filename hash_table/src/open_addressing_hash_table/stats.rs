@@ -0,0 +1,38 @@
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProbeStats {
+    operations: usize,
+    probes: usize,
+    longest: usize,
+}
+
+impl ProbeStats {
+    pub(crate) fn record(&mut self, probes: usize) {
+        self.operations += 1;
+        self.probes += probes;
+        self.longest = self.longest.max(probes);
+    }
+
+    #[must_use]
+    pub const fn operations(&self) -> usize {
+        self.operations
+    }
+
+    #[must_use]
+    pub const fn probes(&self) -> usize {
+        self.probes
+    }
+
+    #[must_use]
+    pub const fn longest(&self) -> usize {
+        self.longest
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn average(&self) -> f64 {
+        if self.operations == 0 {
+            return 0.0;
+        }
+        self.probes as f64 / self.operations as f64
+    }
+}
@@ -1,36 +1,49 @@
+use std::borrow::Borrow;
+
 #[derive(Debug, Clone)]
-pub struct Entry<T: Clone> {
+pub struct Entry<K: Clone, V: Clone> {
     // index: usize, // Si quisiera recuperar todo el orden de creación
-    key: String,
-    value: T,
+    key: K,
+    value: V,
 }
 
-impl<T: Clone> Entry<T> {
-    pub const fn new(key: String, value: T) -> Self {
+impl<K: Clone + Eq, V: Clone> Entry<K, V> {
+    pub(crate) const fn new(key: K, value: V) -> Self {
         Self { key, value }
     }
 
-    pub const fn get(&self) -> &T {
+    pub const fn get(&self) -> &V {
         &self.value
     }
 
-    pub fn get_mut(&mut self) -> &mut T {
+    pub(crate) fn get_mut(&mut self) -> &mut V {
         &mut self.value
     }
 
-    pub const fn get_key(&self) -> &String {
+    pub const fn get_key(&self) -> &K {
         &self.key
     }
 
-    pub fn compare_key(&self, key: &String) -> bool {
-        &self.key == key
+    pub fn compare_key<Q: ?Sized + Eq>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.key.borrow() == key
     }
 
-    pub const fn get_entry(&self) -> (&String, &T) {
+    pub const fn get_entry(&self) -> (&K, &V) {
         (&self.key, &self.value)
     }
 
-    pub fn set(&mut self, value: T) {
+    pub(crate) fn get_entry_mut(&mut self) -> (&K, &mut V) {
+        (&self.key, &mut self.value)
+    }
+
+    pub(crate) fn into_entry(self) -> (K, V) {
+        (self.key, self.value)
+    }
+
+    pub(crate) fn set(&mut self, value: V) {
         self.value = value;
     }
 }
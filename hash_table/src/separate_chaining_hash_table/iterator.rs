@@ -1,27 +1,257 @@
-pub struct SeparateChainingHashTableIterator<'a, T: Clone> {
-    entries: Vec<(&'a String, &'a T)>,
-    current: usize,
+use super::entry::Entry;
+use std::iter::Enumerate;
+use std::slice::{Iter, IterMut};
+use std::vec::{Drain, IntoIter};
+
+pub struct SeparateChainingHashTableIterator<'a, K: Clone, V: Clone> {
+    buckets: Iter<'a, Vec<Entry<K, V>>>,
+    entries: Iter<'a, Entry<K, V>>,
+    remaining: usize,
 }
 
-impl<'a, T: Clone> SeparateChainingHashTableIterator<'a, T> {
-    pub const fn new(entries: Vec<(&'a String, &'a T)>) -> Self {
+impl<'a, K: Clone, V: Clone> SeparateChainingHashTableIterator<'a, K, V> {
+    pub fn new(buckets: &'a [Vec<Entry<K, V>>], entries_len: usize) -> Self {
         Self {
-            entries,
-            current: 0,
+            buckets: buckets.iter(),
+            entries: [].iter(),
+            remaining: entries_len,
         }
     }
 }
 
-impl<'a, T: Clone> Iterator for SeparateChainingHashTableIterator<'a, T> {
-    type Item = (&'a String, &'a T);
+impl<'a, K: Clone + Eq, V: Clone> Iterator for SeparateChainingHashTableIterator<'a, K, V> {
+    type Item = (&'a K, &'a V);
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.entries.get(self.current) {
-            Some(entry) => {
-                self.current += 1;
-                Some(*entry)
+        loop {
+            if let Some(entry) = self.entries.next() {
+                self.remaining -= 1;
+                return Some(entry.get_entry());
             }
-            None => None,
+            self.entries = self.buckets.next()?.iter();
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K: Clone + Eq, V: Clone> ExactSizeIterator for SeparateChainingHashTableIterator<'_, K, V> {}
+
+pub struct SeparateChainingHashTableKeys<'a, K: Clone, V: Clone> {
+    entries: SeparateChainingHashTableIterator<'a, K, V>,
+}
+
+impl<'a, K: Clone, V: Clone> SeparateChainingHashTableKeys<'a, K, V> {
+    pub const fn new(entries: SeparateChainingHashTableIterator<'a, K, V>) -> Self {
+        Self { entries }
+    }
+}
+
+impl<'a, K: Clone + Eq, V: Clone> Iterator for SeparateChainingHashTableKeys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl<K: Clone + Eq, V: Clone> ExactSizeIterator for SeparateChainingHashTableKeys<'_, K, V> {}
+
+pub struct SeparateChainingHashTableValues<'a, K: Clone, V: Clone> {
+    entries: SeparateChainingHashTableIterator<'a, K, V>,
+}
+
+impl<'a, K: Clone, V: Clone> SeparateChainingHashTableValues<'a, K, V> {
+    pub const fn new(entries: SeparateChainingHashTableIterator<'a, K, V>) -> Self {
+        Self { entries }
+    }
+}
+
+impl<'a, K: Clone + Eq, V: Clone> Iterator for SeparateChainingHashTableValues<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
 }
+
+impl<K: Clone + Eq, V: Clone> ExactSizeIterator for SeparateChainingHashTableValues<'_, K, V> {}
+
+pub struct SeparateChainingHashTableIterMut<'a, K: Clone, V: Clone> {
+    buckets: IterMut<'a, Vec<Entry<K, V>>>,
+    entries: IterMut<'a, Entry<K, V>>,
+    remaining: usize,
+}
+
+impl<'a, K: Clone, V: Clone> SeparateChainingHashTableIterMut<'a, K, V> {
+    pub fn new(buckets: &'a mut [Vec<Entry<K, V>>], entries_len: usize) -> Self {
+        Self {
+            buckets: buckets.iter_mut(),
+            entries: [].iter_mut(),
+            remaining: entries_len,
+        }
+    }
+}
+
+impl<'a, K: Clone + Eq, V: Clone> Iterator for SeparateChainingHashTableIterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(entry) = self.entries.next() {
+                self.remaining -= 1;
+                return Some(entry.get_entry_mut());
+            }
+            self.entries = self.buckets.next()?.iter_mut();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K: Clone + Eq, V: Clone> ExactSizeIterator for SeparateChainingHashTableIterMut<'_, K, V> {}
+
+pub struct SeparateChainingHashTableValuesMut<'a, K: Clone, V: Clone> {
+    entries: SeparateChainingHashTableIterMut<'a, K, V>,
+}
+
+impl<'a, K: Clone, V: Clone> SeparateChainingHashTableValuesMut<'a, K, V> {
+    pub const fn new(entries: SeparateChainingHashTableIterMut<'a, K, V>) -> Self {
+        Self { entries }
+    }
+}
+
+impl<'a, K: Clone + Eq, V: Clone> Iterator for SeparateChainingHashTableValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl<K: Clone + Eq, V: Clone> ExactSizeIterator for SeparateChainingHashTableValuesMut<'_, K, V> {}
+
+pub struct SeparateChainingHashTableDrain<'a, K: Clone, V: Clone> {
+    buckets: IterMut<'a, Vec<Entry<K, V>>>,
+    entries: Option<Drain<'a, Entry<K, V>>>,
+    remaining: usize,
+}
+
+impl<'a, K: Clone, V: Clone> SeparateChainingHashTableDrain<'a, K, V> {
+    pub fn new(buckets: &'a mut [Vec<Entry<K, V>>], entries_len: usize) -> Self {
+        Self {
+            buckets: buckets.iter_mut(),
+            entries: None,
+            remaining: entries_len,
+        }
+    }
+}
+
+impl<K: Clone + Eq, V: Clone> Iterator for SeparateChainingHashTableDrain<'_, K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(entry) = self.entries.as_mut().and_then(Iterator::next) {
+                self.remaining -= 1;
+                return Some(entry.into_entry());
+            }
+            self.entries = Some(self.buckets.next()?.drain(..));
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K: Clone + Eq, V: Clone> ExactSizeIterator for SeparateChainingHashTableDrain<'_, K, V> {}
+
+impl<K: Clone, V: Clone> Drop for SeparateChainingHashTableDrain<'_, K, V> {
+    fn drop(&mut self) {
+        self.entries = None;
+        for entries in &mut self.buckets {
+            entries.clear();
+        }
+    }
+}
+
+pub struct SeparateChainingHashTableIntoIter<K: Clone, V: Clone> {
+    buckets: IntoIter<Vec<Entry<K, V>>>,
+    entries: IntoIter<Entry<K, V>>,
+    remaining: usize,
+}
+
+impl<K: Clone, V: Clone> SeparateChainingHashTableIntoIter<K, V> {
+    pub fn new(buckets: Vec<Vec<Entry<K, V>>>, entries_len: usize) -> Self {
+        Self {
+            buckets: buckets.into_iter(),
+            entries: Vec::new().into_iter(),
+            remaining: entries_len,
+        }
+    }
+}
+
+impl<K: Clone + Eq, V: Clone> Iterator for SeparateChainingHashTableIntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(entry) = self.entries.next() {
+                self.remaining -= 1;
+                return Some(entry.into_entry());
+            }
+            self.entries = self.buckets.next()?.into_iter();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K: Clone + Eq, V: Clone> ExactSizeIterator for SeparateChainingHashTableIntoIter<K, V> {}
+
+pub struct SeparateChainingHashTableBuckets<'a, K: Clone, V: Clone> {
+    buckets: Enumerate<Iter<'a, Vec<Entry<K, V>>>>,
+}
+
+impl<'a, K: Clone, V: Clone> SeparateChainingHashTableBuckets<'a, K, V> {
+    pub fn new(buckets: &'a [Vec<Entry<K, V>>]) -> Self {
+        Self {
+            buckets: buckets.iter().enumerate(),
+        }
+    }
+}
+
+impl<'a, K: Clone, V: Clone> Iterator for SeparateChainingHashTableBuckets<'a, K, V> {
+    type Item = (usize, &'a [Entry<K, V>]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.buckets
+            .next()
+            .map(|(index, entries)| (index, entries.as_slice()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.buckets.size_hint()
+    }
+}
+
+impl<K: Clone, V: Clone> ExactSizeIterator for SeparateChainingHashTableBuckets<'_, K, V> {}
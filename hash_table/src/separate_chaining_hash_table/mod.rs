@@ -1,54 +1,124 @@
 mod entry;
 mod iterator;
+#[cfg(feature = "serde")]
+mod serialization;
+mod stats;
 
-use entry::Entry;
+use crate::hasher::Fnv1aHasher;
+pub use entry::Entry;
 use exceptions::Exceptions;
-use iterator::SeparateChainingHashTableIterator;
+pub use iterator::{
+    SeparateChainingHashTableBuckets, SeparateChainingHashTableDrain,
+    SeparateChainingHashTableIntoIter, SeparateChainingHashTableIterMut,
+    SeparateChainingHashTableIterator, SeparateChainingHashTableKeys,
+    SeparateChainingHashTableValues, SeparateChainingHashTableValuesMut,
+};
+pub use stats::TableStats;
+use std::borrow::Borrow;
 use std::fmt::{Debug, Formatter, Result as fmtResult};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::Index;
+use std::vec;
 
-pub struct SeparateChainingHashTable<T: Clone> {
-    buckets: Box<[Vec<Entry<T>>]>,
+const DEFAULT_MAX_LOAD_FACTOR: f64 = 0.75;
+
+pub struct SeparateChainingHashTable<
+    K: Clone + Hash + Eq,
+    V: Clone,
+    H: Hasher + Default = Fnv1aHasher,
+> {
+    buckets: Box<[Vec<Entry<K, V>>]>,
     entries_len: usize,
+    max_load_factor: Option<f64>,
+    hasher: PhantomData<H>,
 }
 
-impl<T: Clone> SeparateChainingHashTable<T> {
+pub type StringHashTable<V, H = Fnv1aHasher> = SeparateChainingHashTable<String, V, H>;
+
+impl<K: Clone + Hash + Eq, V: Clone, H: Hasher + Default> SeparateChainingHashTable<K, V, H> {
     #[must_use]
     pub fn new(capacity: usize) -> Self {
-        let buckets: Box<[Vec<Entry<T>>]> = vec![Vec::new(); capacity].into_boxed_slice();
+        let buckets: Box<[Vec<Entry<K, V>>]> = vec![Vec::new(); capacity].into_boxed_slice();
 
         Self {
             buckets,
             entries_len: 0,
+            max_load_factor: Some(DEFAULT_MAX_LOAD_FACTOR),
+            hasher: PhantomData,
         }
     }
 
-    pub fn get<S: Into<String>>(&self, key: S) -> Result<&T, Exceptions> {
-        let key: String = key.into();
-        let index = Self::hash(&key) % self.buckets.len();
+    #[must_use]
+    pub fn with_manual_rehashing(capacity: usize) -> Self {
+        let mut table = Self::new(capacity);
+        table.max_load_factor = None;
+        table
+    }
+
+    #[must_use]
+    pub fn with_capacity_and_load_factor(capacity: usize, max_load_factor: f64) -> Self {
+        let mut table = Self::new(capacity);
+        table.set_max_load_factor(Some(max_load_factor));
+        table
+    }
+
+    pub fn get<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Result<&V, Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        let index = Self::hash(key) % self.buckets.len();
         for entry in &self.buckets[index] {
-            if entry.compare_key(&key) {
+            if entry.compare_key(key) {
                 return Ok(entry.get());
             }
         }
         Err(Exceptions::KeyNotInitialized)
     }
 
-    pub fn get_mut<S: Into<String>>(&mut self, key: S) -> Result<&mut T, Exceptions> {
-        let key: String = key.into();
-        let index = Self::hash(&key) % self.buckets.len();
+    pub fn get_key_value<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Result<(&K, &V), Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        let index = Self::hash(key) % self.buckets.len();
+        self.buckets[index]
+            .iter()
+            .find(|entry| entry.compare_key(key))
+            .map(Entry::get_entry)
+            .ok_or(Exceptions::KeyNotInitialized)
+    }
+
+    pub fn contains_key<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        let index = Self::hash(key) % self.buckets.len();
+        self.buckets[index]
+            .iter()
+            .any(|entry| entry.compare_key(key))
+    }
+
+    pub fn get_mut<Q: ?Sized + Hash + Eq>(&mut self, key: &Q) -> Result<&mut V, Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        let index = Self::hash(key) % self.buckets.len();
         for entry in &mut self.buckets[index] {
-            if entry.compare_key(&key) {
+            if entry.compare_key(key) {
                 return Ok(entry.get_mut());
             }
         }
         Err(Exceptions::KeyNotInitialized)
     }
 
-    pub fn set<S: Into<String>>(&mut self, key: S, value: T) -> Result<(), Exceptions> {
-        let key: String = key.into();
-        let index = Self::hash(&key) % self.buckets.len();
+    pub fn set<Q: ?Sized + Hash + Eq>(&mut self, key: &Q, value: V) -> Result<(), Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        let index = Self::hash(key) % self.buckets.len();
         for entry in &mut self.buckets[index] {
-            if entry.compare_key(&key) {
+            if entry.compare_key(key) {
                 entry.set(value);
                 return Ok(());
             }
@@ -56,8 +126,8 @@ impl<T: Clone> SeparateChainingHashTable<T> {
         Err(Exceptions::KeyNotInitialized)
     }
 
-    pub fn insert<S: Into<String>>(&mut self, key: S, value: T) -> Result<(), Exceptions> {
-        let key: String = key.into();
+    pub fn insert<Q: Into<K>>(&mut self, key: Q, value: V) -> Result<(), Exceptions> {
+        let key: K = key.into();
         let index = Self::hash(&key) % self.buckets.len();
         let key_exist = self.buckets[index]
             .iter()
@@ -65,66 +135,93 @@ impl<T: Clone> SeparateChainingHashTable<T> {
         if key_exist {
             Err(Exceptions::DuplicateKey)
         } else {
-            let entry = Entry::new(key, value);
-            self.buckets[index].push(entry);
-            self.entries_len += 1;
+            self.push_entry(index, key, value);
             Ok(())
         }
     }
 
-    pub fn remove<S: Into<String>>(&mut self, key: S) -> Result<T, Exceptions> {
-        let key: String = key.into();
+    pub fn put<Q: Into<K>>(&mut self, key: Q, value: V) -> Option<V> {
+        let key: K = key.into();
         let index = Self::hash(&key) % self.buckets.len();
-        let find_entry: Option<usize> = self.buckets[index]
-            .iter()
-            .position(|entry| entry.compare_key(&key));
-        match find_entry {
-            Some(index) => {
-                let entry: Entry<T> = self.buckets[index].remove(index);
-                self.entries_len -= 1;
-                Ok(entry.get().to_owned())
+        for entry in &mut self.buckets[index] {
+            if entry.compare_key(&key) {
+                return Some(mem::replace(entry.get_mut(), value));
             }
-            None => Err(Exceptions::KeyNotInitialized),
         }
+        self.push_entry(index, key, value);
+        None
     }
 
-    #[must_use]
-    pub fn get_values(&self) -> Vec<&T> {
-        let mut values: Vec<&T> = Vec::with_capacity(self.entries_len);
-        for entries in &self.buckets {
-            if !entries.is_empty() {
-                for entry in entries {
-                    values.push(entry.get());
+    pub fn get_or_insert_with<Q: Into<K>, F: FnOnce() -> V>(
+        &mut self,
+        key: Q,
+        default: F,
+    ) -> &mut V {
+        let key: K = key.into();
+        let index = Self::hash(&key) % self.buckets.len();
+        let found = self.buckets[index]
+            .iter()
+            .position(|entry| entry.compare_key(&key));
+        let (index, position) = match found {
+            Some(position) => (index, position),
+            None => self.push_entry(index, key, default()),
+        };
+        self.buckets[index][position].get_mut()
+    }
+
+    pub fn merge<F: Fn(&V, &V) -> V>(&mut self, mut other: Self, resolve: F) {
+        self.reserve(other.entries_len);
+        for (key, value) in other.drain() {
+            let index = Self::hash(&key) % self.buckets.len();
+            match self.buckets[index]
+                .iter_mut()
+                .find(|entry| entry.compare_key(&key))
+            {
+                Some(entry) => {
+                    let merged = resolve(entry.get(), &value);
+                    entry.set(merged);
+                }
+                None => {
+                    self.push_entry(index, key, value);
                 }
             }
         }
-        values
+    }
+
+    pub fn remove<Q: ?Sized + Hash + Eq>(&mut self, key: &Q) -> Result<V, Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    pub fn remove_entry<Q: ?Sized + Hash + Eq>(&mut self, key: &Q) -> Result<(K, V), Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        let bucket = Self::hash(key) % self.buckets.len();
+        let position = self.buckets[bucket]
+            .iter()
+            .position(|entry| entry.compare_key(key))
+            .ok_or(Exceptions::KeyNotInitialized)?;
+        let entry: Entry<K, V> = self.buckets[bucket].remove(position);
+        self.entries_len -= 1;
+        Ok(entry.into_entry())
     }
 
     #[must_use]
-    pub fn get_keys(&self) -> Vec<&String> {
-        let mut keys: Vec<&String> = Vec::with_capacity(self.entries_len);
-        for entries in &self.buckets {
-            if !entries.is_empty() {
-                for entry in entries {
-                    keys.push(entry.get_key());
-                }
-            }
-        }
-        keys
+    pub fn get_values(&self) -> Vec<&V> {
+        self.values().collect()
     }
 
     #[must_use]
-    pub fn get_entries(&self) -> Vec<(&String, &T)> {
-        let mut keys_values: Vec<(&String, &T)> = Vec::with_capacity(self.entries_len);
-        for entries in &self.buckets {
-            if !entries.is_empty() {
-                for entry in entries {
-                    keys_values.push(entry.get_entry());
-                }
-            }
-        }
-        keys_values
+    pub fn get_keys(&self) -> Vec<&K> {
+        self.keys().collect()
+    }
+
+    #[must_use]
+    pub fn get_entries(&self) -> Vec<(&K, &V)> {
+        self.iter().collect()
     }
 
     #[must_use]
@@ -140,49 +237,270 @@ impl<T: Clone> SeparateChainingHashTable<T> {
             .count()
     }
 
+    #[must_use]
+    pub fn bucket_lengths(&self) -> Vec<usize> {
+        self.buckets.iter().map(Vec::len).collect()
+    }
+
+    #[must_use]
+    pub fn max_chain_len(&self) -> usize {
+        self.buckets.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    #[must_use]
+    pub fn collision_histogram(&self) -> Vec<usize> {
+        let mut histogram = vec![0; self.max_chain_len() + 1];
+        for entries in &self.buckets {
+            histogram[entries.len()] += 1;
+        }
+        histogram
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn load_factor(&self) -> f64 {
+        self.entries_len as f64 / self.buckets.len() as f64
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn average_chain_len(&self) -> f64 {
+        let buckets_len = self.buckets_len();
+        if buckets_len == 0 {
+            return 0.0;
+        }
+        self.entries_len as f64 / buckets_len as f64
+    }
+
+    #[must_use]
+    pub fn stats(&self) -> TableStats {
+        TableStats::new(
+            self.entries_len,
+            self.buckets.len(),
+            self.buckets.len() - self.buckets_len(),
+            self.max_chain_len(),
+        )
+    }
+
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.buckets.len()
+    }
+
     #[must_use]
     pub const fn is_empty(&self) -> bool {
         self.entries_len == 0
     }
 
-    pub fn hash(value: &String) -> usize {
-        let mut h: usize = 0;
-        for val in value.as_bytes() {
-            h = h.wrapping_add(*val as usize);
+    pub fn hash<Q: ?Sized + Hash>(key: &Q) -> usize {
+        let mut hasher = H::default();
+        key.hash(&mut hasher);
+        hasher.finish() as usize
+    }
+
+    #[must_use]
+    pub const fn max_load_factor(&self) -> Option<f64> {
+        self.max_load_factor
+    }
+
+    pub fn set_max_load_factor(&mut self, max_load_factor: Option<f64>) {
+        if let Some(max) = max_load_factor {
+            assert!(max > 0.0, "max load factor must be positive");
         }
-        h
+        self.max_load_factor = max_load_factor;
     }
 
     pub fn rehashing(&mut self, capacity: usize) {
-        let buckets = self.buckets.clone();
-        self.buckets = vec![Vec::new(); capacity].into_boxed_slice();
+        // Las entradas se mueven a su nueva cubeta sin clonar claves ni valores.
+        let buckets = mem::replace(
+            &mut self.buckets,
+            vec![Vec::new(); capacity].into_boxed_slice(),
+        );
+        for entry in buckets.into_vec().into_iter().flatten() {
+            let index = Self::hash(entry.get_key()) % capacity;
+            self.buckets[index].push(entry);
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub fn shrink_to_fit(&mut self) {
+        let max = self.max_load_factor.unwrap_or(1.0);
+        let mut capacity = ((self.entries_len as f64 / max).ceil() as usize).max(1);
+        while self.exceeds_load_factor(self.entries_len, capacity) {
+            capacity += 1;
+        }
+        if capacity < self.buckets.len() {
+            self.rehashing(capacity);
+        }
+    }
+
+    #[must_use]
+    pub fn iter(&self) -> SeparateChainingHashTableIterator<'_, K, V> {
+        SeparateChainingHashTableIterator::new(&self.buckets, self.entries_len)
+    }
+
+    #[must_use]
+    pub fn keys(&self) -> SeparateChainingHashTableKeys<'_, K, V> {
+        SeparateChainingHashTableKeys::new(self.iter())
+    }
+
+    #[must_use]
+    pub fn iter_buckets(&self) -> SeparateChainingHashTableBuckets<'_, K, V> {
+        SeparateChainingHashTableBuckets::new(&self.buckets)
+    }
+
+    #[must_use]
+    pub fn values(&self) -> SeparateChainingHashTableValues<'_, K, V> {
+        SeparateChainingHashTableValues::new(self.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> SeparateChainingHashTableIterMut<'_, K, V> {
+        SeparateChainingHashTableIterMut::new(&mut self.buckets, self.entries_len)
+    }
+
+    pub fn values_mut(&mut self) -> SeparateChainingHashTableValuesMut<'_, K, V> {
+        SeparateChainingHashTableValuesMut::new(self.iter_mut())
+    }
+
+    pub fn clear(&mut self) {
+        for entries in &mut self.buckets {
+            entries.clear();
+        }
         self.entries_len = 0;
-        // let mut keys_values: Vec<(&String, &T)> = Vec::with_capacity(self.entries_len);
-        for entries in buckets {
-            if !entries.is_empty() {
-                for entry in entries {
-                    let _ = self.insert(entry.get_key(), entry.get().to_owned());
-                }
+    }
+
+    pub fn drain(&mut self) -> SeparateChainingHashTableDrain<'_, K, V> {
+        let entries_len = mem::take(&mut self.entries_len);
+        SeparateChainingHashTableDrain::new(&mut self.buckets, entries_len)
+    }
+
+    fn push_entry(&mut self, index: usize, key: K, value: V) -> (usize, usize) {
+        let index = if self.exceeds_load_factor(self.entries_len + 1, self.buckets.len()) {
+            self.rehashing(self.next_capacity());
+            Self::hash(&key) % self.buckets.len()
+        } else {
+            index
+        };
+        let entry = Entry::new(key, value);
+        self.buckets[index].push(entry);
+        self.entries_len += 1;
+        (index, self.buckets[index].len() - 1)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        let entries_len = self.entries_len + additional;
+        if self.exceeds_load_factor(entries_len, self.buckets.len()) {
+            let mut capacity = self.next_capacity();
+            while self.exceeds_load_factor(entries_len, capacity) {
+                capacity *= 2;
             }
+            self.rehashing(capacity);
         }
     }
 
+    #[allow(clippy::cast_precision_loss)]
+    fn exceeds_load_factor(&self, entries_len: usize, capacity: usize) -> bool {
+        self.max_load_factor
+            .is_some_and(|max| entries_len as f64 / capacity as f64 > max)
+    }
+
+    fn next_capacity(&self) -> usize {
+        (self.buckets.len() * 2).max(1)
+    }
+}
+
+impl<K: Clone + Hash + Eq + Ord, V: Clone, H: Hasher + Default> SeparateChainingHashTable<K, V, H> {
     #[must_use]
-    pub fn iter(&self) -> SeparateChainingHashTableIterator<T> {
-        SeparateChainingHashTableIterator::new(self.get_entries())
+    pub fn iter_sorted(&self) -> vec::IntoIter<(&K, &V)> {
+        let mut entries = self.get_entries();
+        entries.sort_unstable_by_key(|&(key, _)| key);
+        entries.into_iter()
+    }
+
+    #[must_use]
+    pub fn keys_sorted(&self) -> vec::IntoIter<&K> {
+        let mut keys = self.get_keys();
+        keys.sort_unstable();
+        keys.into_iter()
+    }
+}
+
+impl<K, V, H, Q> Index<&Q> for SeparateChainingHashTable<K, V, H>
+where
+    K: Clone + Hash + Eq + Borrow<Q>,
+    V: Clone,
+    H: Hasher + Default,
+    Q: ?Sized + Hash + Eq + Debug,
+{
+    type Output = V;
+
+    fn index(&self, key: &Q) -> &Self::Output {
+        let index = Self::hash(key) % self.buckets.len();
+        self.buckets[index]
+            .iter()
+            .find(|entry| entry.compare_key(key))
+            .map_or_else(|| panic!("key not found: {key:?}"), Entry::get)
     }
 }
 
-impl<'a, T: Clone> IntoIterator for &'a SeparateChainingHashTable<T> {
-    type Item = (&'a String, &'a T);
-    type IntoIter = SeparateChainingHashTableIterator<'a, T>;
+impl<K: Clone + Hash + Eq, V: Clone, H: Hasher + Default> FromIterator<(K, V)>
+    for SeparateChainingHashTable<K, V, H>
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut table = Self::new(1);
+        table.extend(iter);
+        table
+    }
+}
+
+impl<K: Clone + Hash + Eq, V: Clone, H: Hasher + Default> Extend<(K, V)>
+    for SeparateChainingHashTable<K, V, H>
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for (key, value) in iter {
+            self.put(key, value);
+        }
+    }
+}
+
+impl<K: Clone + Hash + Eq, V: Clone, H: Hasher + Default> IntoIterator
+    for SeparateChainingHashTable<K, V, H>
+{
+    type Item = (K, V);
+    type IntoIter = SeparateChainingHashTableIntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        SeparateChainingHashTableIntoIter::new(self.buckets.into_vec(), self.entries_len)
+    }
+}
+
+impl<'a, K: Clone + Hash + Eq, V: Clone, H: Hasher + Default> IntoIterator
+    for &'a SeparateChainingHashTable<K, V, H>
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = SeparateChainingHashTableIterator<'a, K, V>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 
-impl<T: Clone + Debug> Debug for SeparateChainingHashTable<T> {
+impl<'a, K: Clone + Hash + Eq, V: Clone, H: Hasher + Default> IntoIterator
+    for &'a mut SeparateChainingHashTable<K, V, H>
+{
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = SeparateChainingHashTableIterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K: Clone + Hash + Eq + Debug, V: Clone + Debug, H: Hasher + Default> Debug
+    for SeparateChainingHashTable<K, V, H>
+{
     fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
         write!(f, "{{")?;
         for (index, (key, value)) in self.iter().enumerate() {
@@ -194,3 +512,480 @@ impl<T: Clone + Debug> Debug for SeparateChainingHashTable<T> {
         write!(f, "}}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Entry, SeparateChainingHashTable, StringHashTable};
+    use exceptions::Exceptions;
+
+    #[test]
+    fn grows_when_max_load_factor_is_exceeded() {
+        let mut table: SeparateChainingHashTable<u32, u32> = SeparateChainingHashTable::new(4);
+        for value in 0..3 {
+            table.insert(value, value).unwrap();
+        }
+        assert_eq!(table.buckets.len(), 4);
+
+        table.insert(3_u32, 3).unwrap();
+        assert_eq!(table.buckets.len(), 8);
+        for value in 0..4 {
+            assert_eq!(table.get(&value), Ok(&value));
+        }
+    }
+
+    #[test]
+    fn duplicate_keys_do_not_trigger_growth() {
+        let mut table: SeparateChainingHashTable<u32, u32> = SeparateChainingHashTable::new(4);
+        for value in 0..3 {
+            table.insert(value, value).unwrap();
+        }
+        assert!(table.insert(0_u32, 0).is_err());
+        assert_eq!(table.buckets.len(), 4);
+    }
+
+    #[test]
+    fn contains_key_follows_inserts_and_removals() {
+        let mut table: SeparateChainingHashTable<String, u32> = SeparateChainingHashTable::new(4);
+        assert!(!table.contains_key("Venus"));
+
+        table.insert("Venus", 2).unwrap();
+        assert!(table.contains_key("Venus"));
+        assert!(!table.contains_key("Marte"));
+
+        table.remove("Venus").unwrap();
+        assert!(!table.contains_key("Venus"));
+    }
+
+    #[test]
+    fn put_inserts_or_overwrites_and_returns_the_previous_value() {
+        let mut table: SeparateChainingHashTable<String, u32> = SeparateChainingHashTable::new(2);
+        assert_eq!(table.put("Venus", 1), None);
+        assert_eq!(table.put("Venus", 2), Some(1));
+        assert_eq!(table.put("Marte", 3), None);
+
+        assert_eq!(table.entries_len(), 2);
+        assert_eq!(table.get("Venus"), Ok(&2));
+        assert_eq!(table.get("Marte"), Ok(&3));
+    }
+
+    #[test]
+    fn get_or_insert_with_only_calls_the_closure_for_missing_keys() {
+        let mut table: SeparateChainingHashTable<u32, u32> = SeparateChainingHashTable::new(1);
+        let mut calls = 0;
+        for key in [1_u32, 2, 1, 3, 2, 1] {
+            *table.get_or_insert_with(key, || {
+                calls += 1;
+                0
+            }) += 1;
+        }
+        assert_eq!(calls, 3);
+        assert_eq!(table.get(&1_u32), Ok(&3));
+        assert_eq!(table.get(&2_u32), Ok(&2));
+        assert_eq!(table.get(&3_u32), Ok(&1));
+    }
+
+    #[test]
+    fn manual_rehashing_keeps_the_requested_capacity() {
+        let mut table: SeparateChainingHashTable<u32, u32> =
+            SeparateChainingHashTable::with_manual_rehashing(2);
+        for value in 0..10 {
+            table.insert(value, value).unwrap();
+        }
+        assert_eq!(table.buckets.len(), 2);
+
+        table.set_max_load_factor(Some(0.75));
+        table.rehashing(1);
+        assert_eq!(table.buckets.len(), 1);
+        assert_eq!(table.max_load_factor(), Some(0.75));
+        assert_eq!(table.entries_len(), 10);
+    }
+
+    #[test]
+    fn rehashing_moves_entries_without_cloning() {
+        use std::cell::Cell;
+
+        thread_local! {
+            static CLONES: Cell<usize> = const { Cell::new(0) };
+        }
+
+        #[derive(Debug, PartialEq)]
+        struct Tracked(u32);
+
+        impl Clone for Tracked {
+            fn clone(&self) -> Self {
+                CLONES.with(|clones| clones.set(clones.get() + 1));
+                Self(self.0)
+            }
+        }
+
+        let mut table: SeparateChainingHashTable<u32, Tracked> = SeparateChainingHashTable::new(1);
+        for value in 0..100 {
+            table.insert(value, Tracked(value)).unwrap();
+        }
+        table.rehashing(7);
+        table.shrink_to_fit();
+
+        assert_eq!(CLONES.with(Cell::get), 0);
+        assert_eq!(table.entries_len(), 100);
+        for value in 0..100 {
+            assert_eq!(table.get(&value), Ok(&Tracked(value)));
+        }
+    }
+
+    #[test]
+    fn lazy_iterators_walk_every_entry_once() {
+        let mut table: SeparateChainingHashTable<u32, u32> =
+            SeparateChainingHashTable::with_manual_rehashing(3);
+        for value in 0..10 {
+            table.insert(value, value * 10).unwrap();
+        }
+
+        assert_eq!(table.iter().len(), 10);
+        let entries: Vec<(u32, u32)> = table
+            .iter_sorted()
+            .map(|(key, value)| (*key, *value))
+            .collect();
+        assert_eq!(
+            entries,
+            (0..10).map(|value| (value, value * 10)).collect::<Vec<_>>()
+        );
+
+        let keys: Vec<u32> = table.keys_sorted().copied().collect();
+        assert_eq!(keys, (0..10).collect::<Vec<_>>());
+        assert_eq!(table.values().sum::<u32>(), 450);
+    }
+
+    #[test]
+    fn iter_buckets_exposes_the_real_layout() {
+        let mut table: SeparateChainingHashTable<usize, &str> =
+            SeparateChainingHashTable::with_manual_rehashing(3);
+        for (key, value) in [(0_usize, "cero"), (3, "tres"), (1, "uno")] {
+            table.insert(key, value).unwrap();
+        }
+
+        let buckets = table.iter_buckets();
+        assert_eq!(buckets.len(), 3);
+        let layout: Vec<(usize, Vec<(&usize, &&str)>)> = buckets
+            .map(|(index, entries)| (index, entries.iter().map(Entry::get_entry).collect()))
+            .collect();
+        let chains: Vec<usize> = layout.iter().map(|(_, entries)| entries.len()).collect();
+        assert_eq!(chains, table.bucket_lengths());
+        for (index, entries) in &layout {
+            for (key, _) in entries {
+                assert_eq!(
+                    SeparateChainingHashTable::<usize, &str>::hash(key) % 3,
+                    *index
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn sorted_iteration_ignores_the_bucket_layout() {
+        let words = ["pera", "kiwi", "uva", "mango", "higo", "lima"];
+        let mut small: StringHashTable<usize> = StringHashTable::with_manual_rehashing(1);
+        let mut large: StringHashTable<usize> = StringHashTable::new(64);
+        for (index, word) in words.into_iter().enumerate() {
+            small.insert(word, index).unwrap();
+            large.insert(word, index).unwrap();
+        }
+
+        let expected = ["higo", "kiwi", "lima", "mango", "pera", "uva"];
+        assert_eq!(small.keys_sorted().collect::<Vec<_>>(), expected);
+        assert_eq!(
+            small.iter_sorted().collect::<Vec<_>>(),
+            large.iter_sorted().collect::<Vec<_>>()
+        );
+        assert_eq!(small.iter_sorted().next(), Some((&"higo".to_string(), &4)));
+    }
+
+    #[test]
+    fn lazy_iterators_on_an_empty_table() {
+        let table: SeparateChainingHashTable<u32, u32> = SeparateChainingHashTable::new(4);
+        assert_eq!(table.iter().next(), None);
+        assert_eq!(table.keys().len(), 0);
+        assert_eq!(table.values().next(), None);
+    }
+
+    #[test]
+    fn mutable_iterators_update_every_value_in_place() {
+        let mut table: SeparateChainingHashTable<u32, u32> = SeparateChainingHashTable::new(4);
+        for value in 0..10 {
+            table.insert(value, value).unwrap();
+        }
+
+        for (key, value) in &mut table {
+            *value += key;
+        }
+        for value in table.values_mut() {
+            *value += 1;
+        }
+
+        assert_eq!(table.iter_mut().len(), 10);
+        for key in 0..10 {
+            assert_eq!(table.get(&key), Ok(&(key * 2 + 1)));
+        }
+    }
+
+    #[test]
+    fn drain_moves_every_entry_out_and_keeps_the_buckets() {
+        let mut table: SeparateChainingHashTable<u32, String> = SeparateChainingHashTable::new(4);
+        for value in 0..10_u32 {
+            table.insert(value, value.to_string()).unwrap();
+        }
+        let capacity = table.buckets.len();
+
+        let mut drained: Vec<(u32, String)> = table.drain().collect();
+        drained.sort_unstable();
+        assert_eq!(
+            drained,
+            (0..10)
+                .map(|value| (value, value.to_string()))
+                .collect::<Vec<_>>()
+        );
+        assert!(table.is_empty());
+        assert_eq!(table.buckets.len(), capacity);
+        assert_eq!(table.buckets_len(), 0);
+        assert_eq!(table.iter().next(), None);
+    }
+
+    #[test]
+    fn dropping_a_partial_drain_still_empties_the_table() {
+        let mut table: SeparateChainingHashTable<u32, u32> = SeparateChainingHashTable::new(4);
+        for value in 0..10 {
+            table.insert(value, value).unwrap();
+        }
+
+        let mut drain = table.drain();
+        assert_eq!(drain.len(), 10);
+        drain.next();
+        drop(drain);
+
+        assert!(table.is_empty());
+        assert!(!table.contains_key(&3_u32));
+        table.insert(3_u32, 30).unwrap();
+        assert_eq!(table.get(&3_u32), Ok(&30));
+    }
+
+    #[test]
+    fn clear_keeps_the_capacity() {
+        let mut table: SeparateChainingHashTable<u32, u32> = SeparateChainingHashTable::new(4);
+        for value in 0..10 {
+            table.insert(value, value).unwrap();
+        }
+        let capacity = table.capacity();
+        assert!(capacity >= table.buckets_len());
+
+        table.clear();
+        assert!(table.is_empty());
+        assert_eq!(table.capacity(), capacity);
+        assert_eq!(table.buckets_len(), 0);
+        assert!(!table.contains_key(&5_u32));
+    }
+
+    #[test]
+    fn collect_rehashes_at_most_once() {
+        let table: SeparateChainingHashTable<u32, u32> =
+            (0..100).map(|value| (value, value * 2)).collect();
+        assert_eq!(table.entries_len(), 100);
+        assert_eq!(table.buckets.len(), 256);
+        for value in 0..100 {
+            assert_eq!(table.get(&value), Ok(&(value * 2)));
+        }
+    }
+
+    #[test]
+    fn extend_overwrites_existing_keys() {
+        let mut table: SeparateChainingHashTable<u32, u32> = SeparateChainingHashTable::new(4);
+        table.insert(1_u32, 10).unwrap();
+        table.extend([(1, 100), (2, 200), (2, 300)]);
+        assert_eq!(table.entries_len(), 2);
+        assert_eq!(table.get(&1_u32), Ok(&100));
+        assert_eq!(table.get(&2_u32), Ok(&300));
+    }
+
+    #[test]
+    fn merge_resolves_duplicate_keys() {
+        let mut left: SeparateChainingHashTable<u32, u32> = [(1, 1), (2, 2)].into_iter().collect();
+        let right: SeparateChainingHashTable<u32, u32> = [(2, 20), (3, 30)].into_iter().collect();
+
+        left.merge(right, |current, incoming| current + incoming);
+        assert_eq!(left.entries_len(), 3);
+        assert_eq!(left.get(&1_u32), Ok(&1));
+        assert_eq!(left.get(&2_u32), Ok(&22));
+        assert_eq!(left.get(&3_u32), Ok(&30));
+    }
+
+    #[test]
+    fn index_reads_existing_keys() {
+        let mut table: StringHashTable<u32> = StringHashTable::new(4);
+        table.insert("clave", 7).unwrap();
+        assert_eq!(table["clave"], 7);
+    }
+
+    #[test]
+    #[should_panic(expected = "key not found: \"ausente\"")]
+    fn index_panics_on_missing_keys() {
+        let table: StringHashTable<u32> = StringHashTable::new(4);
+        let _ = table["ausente"];
+    }
+
+    #[test]
+    fn bucket_inspection_reports_the_distribution() {
+        let mut table: SeparateChainingHashTable<usize, usize> =
+            SeparateChainingHashTable::with_manual_rehashing(4);
+        for key in [0, 4, 8, 1, 5, 2] {
+            table.insert(key, key).unwrap();
+        }
+        let mut lengths = table.bucket_lengths();
+        lengths.sort_unstable();
+        assert_eq!(lengths.iter().sum::<usize>(), 6);
+        assert_eq!(table.max_chain_len(), *lengths.last().unwrap());
+
+        let histogram = table.collision_histogram();
+        assert_eq!(histogram.len(), table.max_chain_len() + 1);
+        assert_eq!(histogram.iter().sum::<usize>(), 4);
+        for (len, count) in histogram.iter().enumerate() {
+            assert_eq!(*count, lengths.iter().filter(|&&l| l == len).count());
+        }
+    }
+
+    #[test]
+    fn bucket_inspection_on_an_empty_table() {
+        let table: SeparateChainingHashTable<u32, u32> = SeparateChainingHashTable::new(3);
+        assert_eq!(table.bucket_lengths(), vec![0, 0, 0]);
+        assert_eq!(table.max_chain_len(), 0);
+        assert_eq!(table.collision_histogram(), vec![3]);
+    }
+
+    #[test]
+    fn remove_entry_returns_the_pair_from_a_shared_bucket() {
+        let mut table: SeparateChainingHashTable<usize, &str> =
+            SeparateChainingHashTable::with_manual_rehashing(1);
+        for (key, value) in [(0_usize, "cero"), (1, "uno"), (2, "dos"), (3, "tres")] {
+            table.insert(key, value).unwrap();
+        }
+
+        assert_eq!(table.remove_entry(&2_usize), Ok((2, "dos")));
+        assert_eq!(
+            table.remove_entry(&2_usize),
+            Err(Exceptions::KeyNotInitialized)
+        );
+        assert_eq!(table.entries_len(), 3);
+        assert_eq!(table.get(&0_usize), Ok(&"cero"));
+        assert_eq!(table.get(&1_usize), Ok(&"uno"));
+        assert_eq!(table.get(&3_usize), Ok(&"tres"));
+    }
+
+    #[test]
+    fn remove_finds_colliding_keys_at_any_position() {
+        let mut table: SeparateChainingHashTable<usize, usize> =
+            SeparateChainingHashTable::with_manual_rehashing(2);
+        for key in 0..8_usize {
+            table.insert(key, key * 10).unwrap();
+        }
+
+        for key in (0..8_usize).rev() {
+            assert_eq!(table.remove(&key), Ok(key * 10));
+            assert!(!table.contains_key(&key));
+        }
+        assert!(table.is_empty());
+        assert_eq!(table.bucket_lengths(), vec![0, 0]);
+    }
+
+    #[test]
+    fn stats_describe_the_table_health() {
+        let mut table: SeparateChainingHashTable<usize, usize> =
+            SeparateChainingHashTable::with_manual_rehashing(4);
+        assert_eq!(table.load_factor(), 0.0);
+        assert_eq!(table.average_chain_len(), 0.0);
+        for key in 0..6 {
+            table.insert(key, key).unwrap();
+        }
+
+        let stats = table.stats();
+        assert_eq!(stats.entries(), 6);
+        assert_eq!(stats.buckets(), 4);
+        assert_eq!(stats.empty_buckets(), 4 - table.buckets_len());
+        assert_eq!(stats.longest_chain(), table.max_chain_len());
+        assert_eq!(stats.load_factor(), 1.5);
+        assert_eq!(table.load_factor(), 1.5);
+        assert_eq!(stats.average_chain_len(), table.average_chain_len());
+    }
+
+    #[test]
+    fn custom_load_factor_controls_growth() {
+        let mut table: SeparateChainingHashTable<u32, u32> =
+            SeparateChainingHashTable::with_capacity_and_load_factor(4, 2.0);
+        assert_eq!(table.max_load_factor(), Some(2.0));
+        for key in 0..8 {
+            table.insert(key, key).unwrap();
+        }
+        assert_eq!(table.capacity(), 4);
+        table.insert(8_u32, 8).unwrap();
+        assert_eq!(table.capacity(), 8);
+    }
+
+    #[test]
+    fn shrink_to_fit_rehashes_sparse_tables_down() {
+        let mut table: SeparateChainingHashTable<u32, u32> = SeparateChainingHashTable::new(64);
+        for key in 0..6 {
+            table.insert(key, key).unwrap();
+        }
+        table.shrink_to_fit();
+        assert_eq!(table.capacity(), 8);
+        assert!(table.load_factor() <= 0.75);
+        for key in 0..6 {
+            assert_eq!(table.get(&key), Ok(&key));
+        }
+
+        table.shrink_to_fit();
+        assert_eq!(table.capacity(), 8);
+        table.clear();
+        table.shrink_to_fit();
+        assert_eq!(table.capacity(), 1);
+    }
+
+    #[test]
+    fn shrink_to_fit_with_manual_rehashing_keeps_one_entry_per_bucket() {
+        let mut table: SeparateChainingHashTable<u32, u32> =
+            SeparateChainingHashTable::with_manual_rehashing(32);
+        for key in 0..5 {
+            table.insert(key, key).unwrap();
+        }
+        table.shrink_to_fit();
+        assert_eq!(table.capacity(), 5);
+        assert_eq!(table.max_load_factor(), None);
+    }
+
+    #[test]
+    fn into_iter_moves_the_entries_out() {
+        let table: SeparateChainingHashTable<u32, String> =
+            (0..10).map(|key| (key, key.to_string())).collect();
+        let iter = table.into_iter();
+        assert_eq!(iter.len(), 10);
+        let mut entries: Vec<(u32, String)> = iter.collect();
+        entries.sort_unstable();
+        assert_eq!(
+            entries,
+            (0..10)
+                .map(|key| (key, key.to_string()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn get_key_value_returns_the_stored_key() {
+        let mut table: StringHashTable<u32> = StringHashTable::new(4);
+        table.insert("clave", 1).unwrap();
+        let stored = table.get_key_value("clave").unwrap().0.as_ptr();
+
+        let (key, value) = table.get_key_value(&String::from("clave")).unwrap();
+        assert_eq!(key.as_ptr(), stored);
+        assert_eq!((key.as_str(), *value), ("clave", 1));
+        assert_eq!(
+            table.get_key_value("otra"),
+            Err(Exceptions::KeyNotInitialized)
+        );
+    }
+}
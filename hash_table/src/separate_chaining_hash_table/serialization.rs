@@ -0,0 +1,102 @@
+use super::SeparateChainingHashTable;
+use serde::de::{MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Formatter, Result as fmtResult};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+// Tope de la reserva inicial: la longitud declarada proviene de la entrada y no es confiable; el rehash automático se
+// encarga del resto.
+const MAX_PREALLOCATED_ENTRIES: usize = 4096;
+
+impl<K, V, H> Serialize for SeparateChainingHashTable<K, V, H>
+where
+    K: Clone + Hash + Eq + Serialize,
+    V: Clone + Serialize,
+    H: Hasher + Default,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.entries_len))?;
+        for (key, value) in self {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+struct SeparateChainingHashTableVisitor<K: Clone + Hash + Eq, V: Clone, H: Hasher + Default> {
+    table: PhantomData<SeparateChainingHashTable<K, V, H>>,
+}
+
+impl<'de, K, V, H> Visitor<'de> for SeparateChainingHashTableVisitor<K, V, H>
+where
+    K: Clone + Hash + Eq + Deserialize<'de>,
+    V: Clone + Deserialize<'de>,
+    H: Hasher + Default,
+{
+    type Value = SeparateChainingHashTable<K, V, H>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmtResult {
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<M: MapAccess<'de>>(self, mut access: M) -> Result<Self::Value, M::Error> {
+        let mut table = SeparateChainingHashTable::new(1);
+        table.reserve(
+            access
+                .size_hint()
+                .unwrap_or(0)
+                .min(MAX_PREALLOCATED_ENTRIES),
+        );
+        while let Some((key, value)) = access.next_entry::<K, V>()? {
+            table.put(key, value);
+        }
+        Ok(table)
+    }
+}
+
+impl<'de, K, V, H> Deserialize<'de> for SeparateChainingHashTable<K, V, H>
+where
+    K: Clone + Hash + Eq + Deserialize<'de>,
+    V: Clone + Deserialize<'de>,
+    H: Hasher + Default,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(SeparateChainingHashTableVisitor { table: PhantomData })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{SeparateChainingHashTable, StringHashTable};
+
+    #[test]
+    fn serializes_as_a_map() {
+        let mut table: StringHashTable<u32> = StringHashTable::new(4);
+        table.insert("uno", 1).unwrap();
+        let json = serde_json::to_string(&table).unwrap();
+        assert_eq!(json, r#"{"uno":1}"#);
+    }
+
+    #[test]
+    fn deserializes_and_rehashes_to_fit() {
+        let json = r#"{"uno": 1, "dos": 2, "tres": 3, "cuatro": 4, "cinco": 5}"#;
+        let table: StringHashTable<u32> = serde_json::from_str(json).unwrap();
+        assert_eq!(table.entries_len(), 5);
+        assert_eq!(table["tres"], 3);
+        assert!(table.capacity() >= 7);
+    }
+
+    #[test]
+    fn round_trips_numeric_keys() {
+        let table: SeparateChainingHashTable<u32, String> =
+            (0..20).map(|value| (value, value.to_string())).collect();
+        let json = serde_json::to_string(&table).unwrap();
+        let restored: SeparateChainingHashTable<u32, String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.entries_len(), 20);
+        for value in 0..20_u32 {
+            assert_eq!(restored.get(&value), Ok(&value.to_string()));
+        }
+    }
+}
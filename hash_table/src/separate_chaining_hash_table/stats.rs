@@ -0,0 +1,62 @@
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TableStats {
+    entries: usize,
+    buckets: usize,
+    empty_buckets: usize,
+    longest_chain: usize,
+}
+
+impl TableStats {
+    pub(crate) const fn new(
+        entries: usize,
+        buckets: usize,
+        empty_buckets: usize,
+        longest_chain: usize,
+    ) -> Self {
+        Self {
+            entries,
+            buckets,
+            empty_buckets,
+            longest_chain,
+        }
+    }
+
+    #[must_use]
+    pub const fn entries(&self) -> usize {
+        self.entries
+    }
+
+    #[must_use]
+    pub const fn buckets(&self) -> usize {
+        self.buckets
+    }
+
+    #[must_use]
+    pub const fn empty_buckets(&self) -> usize {
+        self.empty_buckets
+    }
+
+    #[must_use]
+    pub const fn longest_chain(&self) -> usize {
+        self.longest_chain
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn load_factor(&self) -> f64 {
+        if self.buckets == 0 {
+            return 0.0;
+        }
+        self.entries as f64 / self.buckets as f64
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn average_chain_len(&self) -> f64 {
+        let used_buckets = self.buckets - self.empty_buckets;
+        if used_buckets == 0 {
+            return 0.0;
+        }
+        self.entries as f64 / used_buckets as f64
+    }
+}
@@ -0,0 +1,172 @@
+use crate::hasher::Fnv1aHasher;
+use crate::separate_chaining_hash_table::SeparateChainingHashTable;
+use exceptions::Exceptions;
+use std::borrow::Borrow;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+use std::hash::{Hash, Hasher};
+use std::time::{Duration, Instant};
+
+pub struct TtlCache<K: Clone + Hash + Eq, V: Clone, H: Hasher + Default = Fnv1aHasher> {
+    // `None` marca las entradas que nunca expiran, cuando `now + ttl` no cabe en un `Instant`.
+    table: SeparateChainingHashTable<K, (V, Option<Instant>), H>,
+    ttl: Duration,
+}
+
+impl<K: Clone + Hash + Eq, V: Clone, H: Hasher + Default> TtlCache<K, V, H> {
+    #[must_use]
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            table: SeparateChainingHashTable::new(capacity.max(1)),
+            ttl,
+        }
+    }
+
+    pub fn get<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Result<&V, Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        match self.table.get(key)? {
+            (value, expires_at) if is_live(*expires_at, Instant::now()) => Ok(value),
+            _ => Err(Exceptions::KeyNotInitialized),
+        }
+    }
+
+    pub fn contains_key<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.get(key).is_ok()
+    }
+
+    pub fn put<Q: Into<K>>(&mut self, key: Q, value: V) -> Option<V> {
+        self.put_with_ttl(key, value, self.ttl)
+    }
+
+    pub fn put_with_ttl<Q: Into<K>>(&mut self, key: Q, value: V, ttl: Duration) -> Option<V> {
+        let now = Instant::now();
+        self.table
+            .put(key, (value, now.checked_add(ttl)))
+            .and_then(|(value, expires_at)| is_live(expires_at, now).then_some(value))
+    }
+
+    pub fn remove<Q: ?Sized + Hash + Eq>(&mut self, key: &Q) -> Result<V, Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        match self.table.remove(key)? {
+            (value, expires_at) if is_live(expires_at, Instant::now()) => Ok(value),
+            _ => Err(Exceptions::KeyNotInitialized),
+        }
+    }
+
+    pub fn purge_expired(&mut self) -> usize {
+        let now = Instant::now();
+        let expired: Vec<K> = self
+            .table
+            .iter()
+            .filter(|(_, (_, expires_at))| !is_live(*expires_at, now))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in &expired {
+            let _ = self.table.remove(key);
+        }
+        expired.len()
+    }
+
+    #[must_use]
+    pub const fn entries_len(&self) -> usize {
+        self.table.entries_len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    #[must_use]
+    pub const fn ttl(&self) -> Duration {
+        self.ttl
+    }
+}
+
+fn is_live(expires_at: Option<Instant>, now: Instant) -> bool {
+    expires_at.is_none_or(|expires_at| now < expires_at)
+}
+
+impl<K: Clone + Hash + Eq + Debug, V: Clone + Debug, H: Hasher + Default> Debug
+    for TtlCache<K, V, H>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        let now = Instant::now();
+        write!(f, "{{")?;
+        let live = self
+            .table
+            .iter()
+            .filter(|(_, (_, expires_at))| is_live(*expires_at, now));
+        for (index, (key, (value, _))) in live.enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{key:?}: {value:?}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TtlCache;
+    use exceptions::Exceptions;
+    use std::time::Duration;
+
+    const HOUR: Duration = Duration::from_secs(3600);
+
+    #[test]
+    fn live_entries_behave_like_a_table() {
+        let mut cache: TtlCache<String, u32> = TtlCache::new(4, HOUR);
+        assert_eq!(cache.put("a", 1), None);
+        assert_eq!(cache.put("a", 2), Some(1));
+        assert_eq!(cache.get("a"), Ok(&2));
+        assert_eq!(cache.remove("a"), Ok(2));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn expired_entries_read_as_missing() {
+        let mut cache: TtlCache<String, u32> = TtlCache::new(4, HOUR);
+        cache.put_with_ttl("a", 1, Duration::ZERO);
+        assert_eq!(cache.get("a"), Err(Exceptions::KeyNotInitialized));
+        assert!(!cache.contains_key("a"));
+        assert_eq!(cache.put("a", 2), None);
+        assert_eq!(cache.get("a"), Ok(&2));
+
+        cache.put_with_ttl("b", 1, Duration::ZERO);
+        assert_eq!(cache.remove("b"), Err(Exceptions::KeyNotInitialized));
+        assert_eq!(cache.entries_len(), 1);
+    }
+
+    #[test]
+    fn purge_expired_reclaims_only_expired_entries() {
+        let mut cache: TtlCache<u32, u32> = TtlCache::new(4, Duration::ZERO);
+        for key in 0..6_u32 {
+            cache.put(key, key);
+        }
+        cache.put_with_ttl(10_u32, 10, HOUR);
+        assert_eq!(cache.entries_len(), 7);
+
+        assert_eq!(cache.purge_expired(), 6);
+        assert_eq!(cache.entries_len(), 1);
+        assert_eq!(cache.get(&10_u32), Ok(&10));
+        assert_eq!(format!("{cache:?}"), "{10: 10}");
+    }
+
+    #[test]
+    fn ttls_past_the_clock_range_never_expire() {
+        let mut cache: TtlCache<String, u32> = TtlCache::new(4, Duration::MAX);
+        assert_eq!(cache.put("a", 1), None);
+        assert_eq!(cache.put_with_ttl("a", 2, Duration::MAX), Some(1));
+        assert_eq!(cache.get("a"), Ok(&2));
+        assert_eq!(cache.purge_expired(), 0);
+        assert_eq!(cache.remove("a"), Ok(2));
+    }
+}
@@ -0,0 +1,169 @@
+use exceptions::Exceptions;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Cada arena recibe un identificador propio para rechazar los identificadores de nodos de otras arenas.
+static NEXT_ARENA_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Identificador de un nodo dentro de una `Arena`.
+///
+/// Deja de ser válido cuando su nodo se libera o cuando su arena se absorbe en otra.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Handle {
+    arena: usize,
+    index: usize,
+    generation: usize,
+}
+
+struct Slot<N> {
+    generation: usize,
+    node: Option<N>,
+}
+
+/// Arreglo de nodos enlazados por índice cuyas posiciones libres se reutilizan.
+///
+/// Cada posición guarda una generación que aumenta al liberarla, de modo que un identificador antiguo no alcance al nodo
+/// que la ocupe después.
+pub(crate) struct Arena<N> {
+    id: usize,
+    slots: Vec<Slot<N>>,
+    free: Vec<usize>,
+}
+
+impl<N> Arena<N> {
+    pub(crate) fn new() -> Self {
+        Self {
+            id: NEXT_ARENA_ID.fetch_add(1, Ordering::Relaxed),
+            slots: Vec::new(),
+            free: Vec::new(),
+        }
+    }
+
+    /// Guarda el nodo que construye `make` a partir de su índice y devuelve ese índice.
+    pub(crate) fn insert_with(&mut self, make: impl FnOnce(usize) -> N) -> usize {
+        if let Some(index) = self.free.pop() {
+            self.slots[index].node = Some(make(index));
+            index
+        } else {
+            let index = self.slots.len();
+            self.slots.push(Slot {
+                generation: 0,
+                node: Some(make(index)),
+            });
+            index
+        }
+    }
+
+    pub(crate) fn handle(&self, index: usize) -> Handle {
+        Handle {
+            arena: self.id,
+            index,
+            generation: self.slots[index].generation,
+        }
+    }
+
+    /// Devuelve el índice del nodo de `handle` si sigue vivo en esta arena.
+    pub(crate) fn check(&self, handle: Handle) -> Result<usize, Exceptions> {
+        match self.slots.get(handle.index) {
+            Some(slot)
+                if handle.arena == self.id
+                    && slot.generation == handle.generation
+                    && slot.node.is_some() =>
+            {
+                Ok(handle.index)
+            }
+            _ => Err(Exceptions::NoSuchElement(String::from(
+                "The handle does not belong to a value in the heap",
+            ))),
+        }
+    }
+
+    pub(crate) fn node(&self, index: usize) -> &N {
+        self.slots[index]
+            .node
+            .as_ref()
+            .expect("links point to live nodes")
+    }
+
+    pub(crate) fn node_mut(&mut self, index: usize) -> &mut N {
+        self.slots[index]
+            .node
+            .as_mut()
+            .expect("links point to live nodes")
+    }
+
+    pub(crate) fn release(&mut self, index: usize) -> N {
+        let slot = &mut self.slots[index];
+        slot.generation += 1;
+        self.free.push(index);
+        slot.node.take().expect("links point to live nodes")
+    }
+
+    /// Traslada los nodos de `other` al final de esta arena y devuelve el desplazamiento aplicado a sus índices.
+    ///
+    /// `relink` recibe cada nodo junto con ese desplazamiento para corregir sus enlaces. Los identificadores de `other`
+    /// dejan de ser válidos.
+    pub(crate) fn absorb(&mut self, other: Self, relink: impl Fn(N, usize) -> N) -> usize {
+        let offset = self.slots.len();
+        self.slots.extend(other.slots.into_iter().map(|slot| Slot {
+            generation: slot.generation,
+            node: slot.node.map(|node| relink(node, offset)),
+        }));
+        self.free
+            .extend(other.free.into_iter().map(|index| index + offset));
+        offset
+    }
+}
+
+impl<N: Clone> Clone for Arena<N> {
+    /// La copia recibe un identificador propio, por lo que los identificadores del original no son válidos en ella.
+    fn clone(&self) -> Self {
+        Self {
+            id: NEXT_ARENA_ID.fetch_add(1, Ordering::Relaxed),
+            slots: self
+                .slots
+                .iter()
+                .map(|slot| Slot {
+                    generation: slot.generation,
+                    node: slot.node.clone(),
+                })
+                .collect(),
+            free: self.free.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Arena, Handle};
+
+    fn insert(arena: &mut Arena<u32>, value: u32) -> Handle {
+        let index = arena.insert_with(|_| value);
+        arena.handle(index)
+    }
+
+    #[test]
+    fn stale_handles_are_rejected_after_slot_reuse() {
+        let mut arena = Arena::new();
+        let first = insert(&mut arena, 1);
+        assert_eq!(arena.release(first.index), 1);
+        let second = insert(&mut arena, 2);
+        assert_eq!(first.index, second.index);
+        assert!(arena.check(first).is_err());
+        assert_eq!(arena.check(second).map(|index| *arena.node(index)), Ok(2));
+    }
+
+    #[test]
+    fn handles_of_an_absorbed_arena_are_rejected() {
+        let mut arena = Arena::new();
+        let mine = insert(&mut arena, 10);
+        let mut other = Arena::new();
+        let theirs = insert(&mut other, 20);
+        let copy = arena.clone();
+        assert!(copy.check(mine).is_err());
+
+        assert_eq!(arena.absorb(other, |value, _| value), 1);
+        assert_eq!(arena.absorb(copy, |value, _| value), 2);
+        assert!(arena.check(theirs).is_err());
+        assert_eq!(arena.check(mine).map(|index| *arena.node(index)), Ok(10));
+    }
+}
@@ -0,0 +1,290 @@
+use exceptions::Exceptions;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+use std::mem;
+
+/// Montículo binario de mínimos: el caso `D = 2` de `DaryHeap`.
+pub type BinaryHeap<T> = DaryHeap<T, 2>;
+
+#[derive(Clone)]
+pub struct DaryHeap<T: Clone + Ord, const D: usize> {
+    // Los hijos de la posición `i` ocupan las posiciones `D * i + 1 ..= D * i + D`.
+    values: Vec<T>,
+    comparisons: usize,
+}
+
+impl<T: Clone + Ord, const D: usize> DaryHeap<T, D> {
+    const VALID_BRANCHING: () =
+        assert!(D >= 2, "a d-ary heap needs at least two children per node");
+
+    /// Crea un nuevo montículo de mínimos vacío con `D` hijos por nodo.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use heap::DaryHeap;
+    /// let heap: DaryHeap<u32, 4> = DaryHeap::new();
+    ///
+    /// assert!(heap.is_empty());
+    /// assert_eq!(heap.height(), 0);
+    /// ```
+    ///
+    /// # Notas
+    /// - Un factor de ramificación menor que `2` se rechaza al compilar.
+    #[must_use]
+    pub const fn new() -> Self {
+        let () = Self::VALID_BRANCHING;
+        Self {
+            values: Vec::new(),
+            comparisons: 0,
+        }
+    }
+
+    /// Inserta un valor en el montículo.
+    ///
+    /// # Comportamiento
+    /// - El valor se agrega al final y se hace flotar hacia la raíz mientras sea menor que su padre.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use heap::DaryHeap;
+    /// let mut heap: DaryHeap<u32, 3> = DaryHeap::new();
+    /// heap.push(5);
+    /// heap.push(1);
+    /// heap.push(3);
+    ///
+    /// assert_eq!(heap.peek(), Ok(&1));
+    /// assert_eq!(heap.len(), 3);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo de `O(log_D n)` comparaciones: un factor de ramificación mayor acorta el camino hacia la raíz.
+    pub fn push(&mut self, value: T) {
+        self.values.push(value);
+        self.sift_up(self.values.len() - 1);
+    }
+
+    /// Extrae y devuelve el menor valor del montículo.
+    ///
+    /// # Comportamiento
+    /// - El último valor ocupa el lugar de la raíz y se hunde, intercambiándose con el menor de sus hijos.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use heap::DaryHeap;
+    /// let mut heap: DaryHeap<u32, 4> = [7, 2, 9, 4].into_iter().collect();
+    ///
+    /// assert_eq!(heap.pop(), Ok(2));
+    /// assert_eq!(heap.pop(), Ok(4));
+    /// assert_eq!(heap.len(), 2);
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si el montículo está vacío.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo de `O(D log_D n)` comparaciones: cada nivel compara a los `D` hijos, por lo que un factor
+    ///   de ramificación mayor abarata `push` a cambio de encarecer `pop`.
+    pub fn pop(&mut self) -> Result<T, Exceptions> {
+        let last = self
+            .values
+            .pop()
+            .ok_or_else(|| Exceptions::NoSuchElement(String::from("The heap is empty")))?;
+        if self.values.is_empty() {
+            return Ok(last);
+        }
+        let min = mem::replace(&mut self.values[0], last);
+        self.sift_down(0);
+        Ok(min)
+    }
+
+    /// Obtiene una referencia al menor valor del montículo sin extraerlo.
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si el montículo está vacío.
+    pub fn peek(&self) -> Result<&T, Exceptions> {
+        self.values
+            .first()
+            .ok_or_else(|| Exceptions::NoSuchElement(String::from("The heap is empty")))
+    }
+
+    /// Devuelve la cantidad de valores almacenados.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Verifica si el montículo está vacío.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Devuelve la cantidad de niveles del montículo.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use heap::{BinaryHeap, DaryHeap};
+    /// let binary: BinaryHeap<u32> = (0..15).collect();
+    /// let quaternary: DaryHeap<u32, 4> = (0..15).collect();
+    ///
+    /// assert_eq!(binary.height(), 4);
+    /// assert_eq!(quaternary.height(), 3);
+    /// ```
+    #[must_use]
+    pub fn height(&self) -> usize {
+        let mut height = 0;
+        let mut level_end = 0;
+        let mut level_len = 1;
+        while level_end < self.values.len() {
+            height += 1;
+            level_end += level_len;
+            level_len *= D;
+        }
+        height
+    }
+
+    /// Devuelve la cantidad de comparaciones realizadas al reordenar el montículo desde su creación o desde el último
+    /// `reset_comparisons`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use heap::DaryHeap;
+    /// let mut heap: DaryHeap<u32, 2> = DaryHeap::new();
+    /// heap.push(2);
+    /// heap.push(1);
+    ///
+    /// // El segundo valor se comparó una vez con la raíz antes de reemplazarla.
+    /// assert_eq!(heap.comparisons(), 1);
+    /// heap.reset_comparisons();
+    /// assert_eq!(heap.comparisons(), 0);
+    /// ```
+    #[must_use]
+    pub const fn comparisons(&self) -> usize {
+        self.comparisons
+    }
+
+    /// Reinicia el contador de comparaciones.
+    pub fn reset_comparisons(&mut self) {
+        self.comparisons = 0;
+    }
+
+    /// Consume el montículo y devuelve sus valores en orden ascendente.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use heap::DaryHeap;
+    /// let heap: DaryHeap<i32, 3> = [3, -1, 8, 0, 5].into_iter().collect();
+    ///
+    /// assert_eq!(heap.into_sorted_vec(), vec![-1, 0, 3, 5, 8]);
+    /// ```
+    #[must_use]
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut sorted = Vec::with_capacity(self.values.len());
+        while let Ok(value) = self.pop() {
+            sorted.push(value);
+        }
+        sorted
+    }
+
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / D;
+            self.comparisons += 1;
+            if self.values[index] >= self.values[parent] {
+                break;
+            }
+            self.values.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut index: usize) {
+        loop {
+            let first_child = D * index + 1;
+            if first_child >= self.values.len() {
+                break;
+            }
+            let last_child = (first_child + D).min(self.values.len());
+            let mut min_child = first_child;
+            for child in first_child + 1..last_child {
+                self.comparisons += 1;
+                if self.values[child] < self.values[min_child] {
+                    min_child = child;
+                }
+            }
+            self.comparisons += 1;
+            if self.values[min_child] >= self.values[index] {
+                break;
+            }
+            self.values.swap(index, min_child);
+            index = min_child;
+        }
+    }
+}
+
+impl<T: Clone + Ord, const D: usize> Default for DaryHeap<T, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone + Ord, const D: usize> FromIterator<T> for DaryHeap<T, D> {
+    /// Construye el montículo en tiempo lineal, hundiendo cada nodo interno desde el último hasta la raíz.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut heap = Self::new();
+        heap.values = iter.into_iter().collect();
+        if heap.values.len() > 1 {
+            for index in (0..=(heap.values.len() - 2) / D).rev() {
+                heap.sift_down(index);
+            }
+        }
+        heap
+    }
+}
+
+impl<T: Clone + Ord + Debug, const D: usize> Debug for DaryHeap<T, D> {
+    /// Muestra los valores en el orden en que se almacenan, nivel por nivel.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "{:?}", self.values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DaryHeap;
+
+    fn sorts<const D: usize>() {
+        // Secuencia pseudoaleatoria determinista con valores repetidos.
+        let values: Vec<u64> = (0..500_u64).map(|i| i * 7919 % 257).collect();
+        let mut expected = values.clone();
+        expected.sort_unstable();
+
+        let mut pushed: DaryHeap<u64, D> = DaryHeap::new();
+        for value in &values {
+            pushed.push(*value);
+        }
+        let collected: DaryHeap<u64, D> = values.into_iter().collect();
+        assert_eq!(pushed.into_sorted_vec(), expected);
+        assert_eq!(collected.into_sorted_vec(), expected);
+    }
+
+    #[test]
+    fn every_branching_factor_sorts() {
+        sorts::<2>();
+        sorts::<3>();
+        sorts::<4>();
+        sorts::<8>();
+        sorts::<16>();
+    }
+
+    #[test]
+    fn pop_on_an_empty_heap_fails() {
+        let mut heap: DaryHeap<u32, 4> = DaryHeap::new();
+        assert!(heap.pop().is_err());
+        assert!(heap.peek().is_err());
+        heap.push(1);
+        assert_eq!(heap.pop(), Ok(1));
+        assert!(heap.is_empty());
+    }
+}
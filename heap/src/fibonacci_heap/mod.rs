@@ -0,0 +1,488 @@
+mod stats;
+
+use crate::arena::{Arena, Handle};
+use exceptions::Exceptions;
+pub use stats::FibonacciHeapStats;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+
+/// Identificador de un valor insertado en un `FibonacciHeap`, necesario para `decrease_key`.
+///
+/// Deja de ser válido cuando su valor se extrae del montículo o cuando su montículo se combina con otro: los métodos que lo
+/// reciben lo detectan y retornan un error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FibonacciHeapHandle(Handle);
+
+#[derive(Clone)]
+struct Node<T> {
+    value: T,
+    parent: Option<usize>,
+    child: Option<usize>,
+    // Hermanos en una lista circular doblemente enlazada; un nodo solo se enlaza consigo mismo.
+    left: usize,
+    right: usize,
+    degree: usize,
+    marked: bool,
+}
+
+#[derive(Clone)]
+pub struct FibonacciHeap<T: Clone + Ord> {
+    nodes: Arena<Node<T>>,
+    // Raíz mínima; el resto de las raíces se alcanza recorriendo su lista circular.
+    min: Option<usize>,
+    len: usize,
+    stats: FibonacciHeapStats,
+}
+
+impl<T: Clone + Ord> FibonacciHeap<T> {
+    /// Crea un nuevo montículo de Fibonacci (de mínimos) vacío.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use heap::FibonacciHeap;
+    /// let heap: FibonacciHeap<u32> = FibonacciHeap::new();
+    ///
+    /// assert!(heap.is_empty());
+    /// assert_eq!(heap.stats().operations(), 0);
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            nodes: Arena::new(),
+            min: None,
+            len: 0,
+            stats: FibonacciHeapStats::default(),
+        }
+    }
+
+    /// Inserta un valor como una nueva raíz y devuelve su identificador.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use heap::FibonacciHeap;
+    /// let mut heap = FibonacciHeap::new();
+    /// let seven = heap.push(7);
+    /// heap.push(3);
+    ///
+    /// assert_eq!(heap.peek(), Ok(&3));
+    /// assert_eq!(heap.get(seven), Ok(&7));
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante (`O(1)`): la consolidación de raíces se pospone hasta el siguiente `pop`.
+    pub fn push(&mut self, value: T) -> FibonacciHeapHandle {
+        let index = self.allocate(value);
+        self.add_root(index);
+        self.len += 1;
+        self.stats.inserts += 1;
+        FibonacciHeapHandle(self.nodes.handle(index))
+    }
+
+    /// Extrae y devuelve el menor valor del montículo.
+    ///
+    /// # Comportamiento
+    /// - Los hijos del mínimo pasan a la lista de raíces y luego se consolidan: las raíces del mismo grado se enlazan
+    ///   hasta que no quedan dos con el mismo grado.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use heap::FibonacciHeap;
+    /// let mut heap: FibonacciHeap<u32> = [4, 1, 3].into_iter().collect();
+    ///
+    /// assert_eq!(heap.pop(), Ok(1));
+    /// assert_eq!(heap.pop(), Ok(3));
+    /// assert_eq!(heap.pop(), Ok(4));
+    /// assert!(heap.pop().is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si el montículo está vacío.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo amortizado logarítmico (`O(log n)`), aunque una extracción aislada puede recorrer `O(n)` raíces.
+    pub fn pop(&mut self) -> Result<T, Exceptions> {
+        let min = self
+            .min
+            .ok_or_else(|| Exceptions::NoSuchElement(String::from("The heap is empty")))?;
+        for child in self.siblings(self.node(min).child) {
+            self.node_mut(child).parent = None;
+            self.node_mut(child).marked = false;
+            self.splice(min, child);
+        }
+        let right = self.node(min).right;
+        self.unlink(min);
+        let node = self.nodes.release(min);
+        self.min = (right != min).then_some(right);
+        if let Some(start) = self.min {
+            self.consolidate(start);
+        }
+        self.len -= 1;
+        self.stats.extractions += 1;
+        Ok(node.value)
+    }
+
+    /// Obtiene una referencia al menor valor del montículo sin extraerlo.
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si el montículo está vacío.
+    pub fn peek(&self) -> Result<&T, Exceptions> {
+        self.min
+            .map(|min| &self.node(min).value)
+            .ok_or_else(|| Exceptions::NoSuchElement(String::from("The heap is empty")))
+    }
+
+    /// Obtiene una referencia al valor asociado a un identificador.
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si el valor del identificador ya se extrajo del montículo.
+    pub fn get(&self, handle: FibonacciHeapHandle) -> Result<&T, Exceptions> {
+        let index = self.nodes.check(handle.0)?;
+        Ok(&self.node(index).value)
+    }
+
+    /// Reemplaza el valor asociado a un identificador por otro menor o igual y restaura el orden del montículo.
+    ///
+    /// # Comportamiento
+    /// - Si el nuevo valor es menor que el de su padre, el nodo se corta y pasa a la lista de raíces.
+    /// - Un padre que pierde su segundo hijo también se corta (corte en cascada), lo que mantiene los grados acotados por
+    ///   `O(log n)`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use heap::FibonacciHeap;
+    /// let mut heap: FibonacciHeap<u32> = FibonacciHeap::new();
+    /// let handles: Vec<_> = (10..20).map(|value| heap.push(value)).collect();
+    /// heap.pop().unwrap();
+    ///
+    /// heap.decrease_key(handles[9], 1).unwrap();
+    /// assert_eq!(heap.peek(), Ok(&1));
+    /// assert!(heap.decrease_key(handles[0], 0).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si el valor del identificador ya se extrajo del montículo.
+    ///
+    /// # Panics
+    /// Entra en pánico si `value` es mayor que el valor actual.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo amortizado constante (`O(1)`).
+    pub fn decrease_key(
+        &mut self,
+        handle: FibonacciHeapHandle,
+        value: T,
+    ) -> Result<(), Exceptions> {
+        let index = self.nodes.check(handle.0)?;
+        assert!(
+            value <= self.node(index).value,
+            "decrease_key cannot increase a value"
+        );
+        self.node_mut(index).value = value;
+        self.stats.decreases += 1;
+        if let Some(parent) = self.node(index).parent {
+            if self.node(index).value < self.node(parent).value {
+                self.cut(index, parent);
+                self.stats.cuts += 1;
+                self.cascading_cut(parent);
+            }
+        }
+        if let Some(min) = self.min {
+            if self.node(index).value < self.node(min).value {
+                self.min = Some(index);
+            }
+        }
+        Ok(())
+    }
+
+    /// Combina otro montículo con este, vaciándolo.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use heap::FibonacciHeap;
+    /// let mut odd: FibonacciHeap<u32> = [5, 1, 3].into_iter().collect();
+    /// let even: FibonacciHeap<u32> = [4, 2].into_iter().collect();
+    ///
+    /// odd.merge(even);
+    /// assert_eq!(odd.len(), 5);
+    /// assert_eq!(odd.stats().inserts(), 5);
+    /// assert_eq!(odd.into_sorted_vec(), vec![1, 2, 3, 4, 5]);
+    /// ```
+    ///
+    /// # Notas
+    /// - Los identificadores de `self` siguen siendo válidos; los de `other` no, porque sus nodos se trasladan a otras posiciones
+    ///   y pasan a pertenecer a `self`.
+    /// - Las listas de raíces se unen en tiempo constante, pero trasladar los nodos cuesta `O(m)` en el tamaño de `other`.
+    pub fn merge(&mut self, other: Self) {
+        let shift = |link: Option<usize>, offset| link.map(|index| index + offset);
+        let offset = self.nodes.absorb(other.nodes, |node, offset| Node {
+            parent: shift(node.parent, offset),
+            child: shift(node.child, offset),
+            left: node.left + offset,
+            right: node.right + offset,
+            ..node
+        });
+        self.len += other.len;
+        let stats = other.stats;
+        self.stats.inserts += stats.inserts;
+        self.stats.extractions += stats.extractions;
+        self.stats.decreases += stats.decreases;
+        self.stats.roots_visited += stats.roots_visited;
+        self.stats.links += stats.links;
+        self.stats.cuts += stats.cuts;
+        self.stats.cascading_cuts += stats.cascading_cuts;
+        match (self.min, shift(other.min, offset)) {
+            (Some(min), Some(other_min)) => {
+                // Unir dos listas circulares consiste en intercambiar los sucesores de un nodo de cada una.
+                let (min_right, other_right) = (self.node(min).right, self.node(other_min).right);
+                self.node_mut(min).right = other_right;
+                self.node_mut(other_right).left = min;
+                self.node_mut(other_min).right = min_right;
+                self.node_mut(min_right).left = other_min;
+                if self.node(other_min).value < self.node(min).value {
+                    self.min = Some(other_min);
+                }
+            }
+            (None, other_min) => self.min = other_min,
+            (Some(_), None) => {}
+        }
+    }
+
+    /// Devuelve la cantidad de valores almacenados.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Verifica si el montículo está vacío.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Devuelve los contadores de operaciones acumulados.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use heap::FibonacciHeap;
+    /// let mut heap: FibonacciHeap<u32> = (0..8).collect();
+    /// heap.pop().unwrap();
+    ///
+    /// // Las siete raíces restantes se consolidan en árboles binomiales de grados 0, 1 y 2.
+    /// let stats = heap.stats();
+    /// assert_eq!((stats.inserts(), stats.extractions()), (8, 1));
+    /// assert_eq!(stats.roots_visited(), 7);
+    /// assert_eq!(stats.links(), 4);
+    /// ```
+    #[must_use]
+    pub const fn stats(&self) -> FibonacciHeapStats {
+        self.stats
+    }
+
+    /// Reinicia los contadores de operaciones.
+    pub fn reset_stats(&mut self) {
+        self.stats = FibonacciHeapStats::default();
+    }
+
+    /// Consume el montículo y devuelve sus valores en orden ascendente.
+    #[must_use]
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut sorted = Vec::with_capacity(self.len);
+        while let Ok(value) = self.pop() {
+            sorted.push(value);
+        }
+        sorted
+    }
+
+    fn node(&self, index: usize) -> &Node<T> {
+        self.nodes.node(index)
+    }
+
+    fn node_mut(&mut self, index: usize) -> &mut Node<T> {
+        self.nodes.node_mut(index)
+    }
+
+    fn allocate(&mut self, value: T) -> usize {
+        self.nodes.insert_with(|index| Node {
+            value,
+            parent: None,
+            child: None,
+            left: index,
+            right: index,
+            degree: 0,
+            marked: false,
+        })
+    }
+
+    // Nodos de la lista circular que empieza en `start`, recolectados antes de modificarla.
+    fn siblings(&self, start: Option<usize>) -> Vec<usize> {
+        let mut siblings = Vec::new();
+        if let Some(start) = start {
+            let mut current = start;
+            loop {
+                siblings.push(current);
+                current = self.node(current).right;
+                if current == start {
+                    break;
+                }
+            }
+        }
+        siblings
+    }
+
+    // Inserta `index`, que debe estar solo, a la derecha de `anchor` en su lista circular.
+    fn splice(&mut self, anchor: usize, index: usize) {
+        let right = self.node(anchor).right;
+        self.node_mut(index).left = anchor;
+        self.node_mut(index).right = right;
+        self.node_mut(right).left = index;
+        self.node_mut(anchor).right = index;
+    }
+
+    // Saca `index` de su lista circular y lo deja enlazado consigo mismo.
+    fn unlink(&mut self, index: usize) {
+        let Node { left, right, .. } = *self.node(index);
+        self.node_mut(left).right = right;
+        self.node_mut(right).left = left;
+        self.node_mut(index).left = index;
+        self.node_mut(index).right = index;
+    }
+
+    fn add_root(&mut self, index: usize) {
+        match self.min {
+            Some(min) => {
+                self.splice(min, index);
+                if self.node(index).value < self.node(min).value {
+                    self.min = Some(index);
+                }
+            }
+            None => self.min = Some(index),
+        }
+    }
+
+    fn consolidate(&mut self, start: usize) {
+        let mut by_degree: Vec<Option<usize>> = Vec::new();
+        for root in self.siblings(Some(start)) {
+            self.stats.roots_visited += 1;
+            self.unlink(root);
+            let mut root = root;
+            loop {
+                let degree = self.node(root).degree;
+                if degree >= by_degree.len() {
+                    by_degree.resize(degree + 1, None);
+                }
+                let Some(other) = by_degree[degree].take() else {
+                    by_degree[degree] = Some(root);
+                    break;
+                };
+                let (parent, child) = if self.node(other).value < self.node(root).value {
+                    (other, root)
+                } else {
+                    (root, other)
+                };
+                self.link(parent, child);
+                root = parent;
+            }
+        }
+        self.min = None;
+        for root in by_degree.into_iter().flatten() {
+            self.add_root(root);
+        }
+    }
+
+    // Convierte la raíz `child` en hija de la raíz `parent`.
+    fn link(&mut self, parent: usize, child: usize) {
+        match self.node(parent).child {
+            Some(first) => self.splice(first, child),
+            None => self.node_mut(parent).child = Some(child),
+        }
+        let child_node = self.node_mut(child);
+        child_node.parent = Some(parent);
+        child_node.marked = false;
+        self.node_mut(parent).degree += 1;
+        self.stats.links += 1;
+    }
+
+    // Separa `index` de los hijos de `parent` y lo convierte en raíz.
+    fn cut(&mut self, index: usize, parent: usize) {
+        let right = self.node(index).right;
+        if self.node(parent).child == Some(index) {
+            self.node_mut(parent).child = (right != index).then_some(right);
+        }
+        self.unlink(index);
+        self.node_mut(parent).degree -= 1;
+        let node = self.node_mut(index);
+        node.parent = None;
+        node.marked = false;
+        self.add_root(index);
+    }
+
+    fn cascading_cut(&mut self, mut index: usize) {
+        while let Some(parent) = self.node(index).parent {
+            if !self.node(index).marked {
+                self.node_mut(index).marked = true;
+                return;
+            }
+            self.cut(index, parent);
+            self.stats.cascading_cuts += 1;
+            index = parent;
+        }
+    }
+}
+
+impl<T: Clone + Ord> Default for FibonacciHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone + Ord> FromIterator<T> for FibonacciHeap<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut heap = Self::new();
+        for value in iter {
+            heap.push(value);
+        }
+        heap
+    }
+}
+
+impl<T: Clone + Ord + Debug> Debug for FibonacciHeap<T> {
+    /// Muestra los valores en orden ascendente.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "{:?}", self.clone().into_sorted_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FibonacciHeap;
+
+    #[test]
+    fn decrease_key_with_cascading_cuts_keeps_the_heap_order() {
+        let mut heap = FibonacciHeap::new();
+        let handles: Vec<_> = (0..300_u64)
+            .map(|i| heap.push(10_000 + i * 7919 % 601))
+            .collect();
+        assert_eq!(heap.pop(), Ok(10_000));
+
+        let mut expected: Vec<u64> = Vec::new();
+        for (i, handle) in handles.iter().enumerate().skip(1) {
+            let current = *heap.get(*handle).unwrap();
+            let value = if i % 2 == 0 {
+                current - 5_000 - i as u64
+            } else {
+                current
+            };
+            heap.decrease_key(*handle, value).unwrap();
+            expected.push(value);
+        }
+        expected.sort_unstable();
+
+        let stats = heap.stats();
+        assert!(stats.cuts() > 0);
+        assert!(stats.cascading_cuts() > 0);
+        assert_eq!(heap.into_sorted_vec(), expected);
+    }
+}
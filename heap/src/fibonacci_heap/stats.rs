@@ -0,0 +1,72 @@
+/// Contadores de las operaciones de un `FibonacciHeap` y del trabajo estructural que provocaron.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FibonacciHeapStats {
+    pub(super) inserts: usize,
+    pub(super) extractions: usize,
+    pub(super) decreases: usize,
+    pub(super) roots_visited: usize,
+    pub(super) links: usize,
+    pub(super) cuts: usize,
+    pub(super) cascading_cuts: usize,
+}
+
+impl FibonacciHeapStats {
+    /// Cantidad de inserciones, incluidas las de los montículos combinados.
+    #[must_use]
+    pub const fn inserts(&self) -> usize {
+        self.inserts
+    }
+
+    /// Cantidad de extracciones del mínimo.
+    #[must_use]
+    pub const fn extractions(&self) -> usize {
+        self.extractions
+    }
+
+    /// Cantidad de llamadas a `decrease_key`.
+    #[must_use]
+    pub const fn decreases(&self) -> usize {
+        self.decreases
+    }
+
+    /// Raíces recorridas durante las consolidaciones: el trabajo real de `pop`, que el análisis amortizado reparte.
+    #[must_use]
+    pub const fn roots_visited(&self) -> usize {
+        self.roots_visited
+    }
+
+    /// Enlaces entre raíces del mismo grado realizados al consolidar.
+    #[must_use]
+    pub const fn links(&self) -> usize {
+        self.links
+    }
+
+    /// Cortes provocados directamente por `decrease_key`.
+    #[must_use]
+    pub const fn cuts(&self) -> usize {
+        self.cuts
+    }
+
+    /// Cortes en cascada sobre ancestros que ya habían perdido un hijo.
+    #[must_use]
+    pub const fn cascading_cuts(&self) -> usize {
+        self.cascading_cuts
+    }
+
+    /// Operaciones públicas realizadas: inserciones, extracciones y disminuciones.
+    #[must_use]
+    pub const fn operations(&self) -> usize {
+        self.inserts + self.extractions + self.decreases
+    }
+
+    /// Trabajo estructural total (raíces recorridas, enlaces y cortes) dividido entre las operaciones realizadas.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn amortized_work(&self) -> f64 {
+        if self.operations() == 0 {
+            return 0.0;
+        }
+        let work = self.roots_visited + self.links + self.cuts + self.cascading_cuts;
+        work as f64 / self.operations() as f64
+    }
+}
@@ -0,0 +1,117 @@
+mod arena;
+pub mod dary_heap;
+pub mod fibonacci_heap;
+pub mod pairing_heap;
+
+pub use dary_heap::{BinaryHeap, DaryHeap};
+use exceptions::Exceptions;
+pub use fibonacci_heap::{FibonacciHeap, FibonacciHeapHandle, FibonacciHeapStats};
+pub use pairing_heap::{PairingHeap, PairingHeapHandle};
+
+pub fn dh_implementation() -> Result<(), Exceptions> {
+    println!("Montículo d-ario (montículo de mínimos)");
+    let mut heap: DaryHeap<u32, 3> = DaryHeap::new();
+    for value in [42, 7, 19, 3, 25, 11, 8, 30] {
+        heap.push(value);
+    }
+    let height = heap.height();
+    println!("  1. Insertar (D = 3, altura: {height}):\n    {heap:?}");
+    let min = heap.pop()?;
+    println!("  2. Extraer el mínimo ({min}):\n    {heap:?}");
+    let sorted = heap.into_sorted_vec();
+    println!("  3. Ordenar extrayendo mínimos:\n    {sorted:?}");
+    println!(
+        "  4. Comparaciones según el factor de ramificación (10000 inserciones y extracciones):"
+    );
+    compare_branching::<2>()?;
+    compare_branching::<3>()?;
+    compare_branching::<4>()?;
+    compare_branching::<8>()?;
+    compare_branching::<16>()?;
+    Ok(())
+}
+
+pub fn ph_implementation() -> Result<(), Exceptions> {
+    println!("Montículo de emparejamiento");
+    let mut tasks: PairingHeap<(u32, &str)> = PairingHeap::new();
+    tasks.push((3, "escribir pruebas"));
+    let deploy = tasks.push((5, "desplegar"));
+    tasks.push((2, "revisar cambios"));
+    println!("  1. Insertar (prioridad, tarea):\n    {tasks:?}");
+    tasks.decrease_key(deploy, (1, "desplegar"))?;
+    println!("  2. Disminuir la prioridad de \"desplegar\" a 1:\n    {tasks:?}");
+    let mut urgent: PairingHeap<(u32, &str)> = PairingHeap::new();
+    urgent.push((0, "corregir la caída"));
+    tasks.merge(urgent);
+    let len = tasks.len();
+    println!("  3. Combinar con otro montículo ({len} tareas):\n    {tasks:?}");
+    let (priority, task) = tasks.pop()?;
+    println!("  4. Extraer el mínimo ({priority}, {task:?}):\n    {tasks:?}");
+    Ok(())
+}
+
+pub fn fh_implementation() -> Result<(), Exceptions> {
+    println!("Montículo de Fibonacci");
+    let mut heap: FibonacciHeap<u64> = FibonacciHeap::new();
+    let handles: Vec<FibonacciHeapHandle> =
+        (0..10_u64).map(|value| heap.push(value * 10)).collect();
+    println!("  1. Insertar 0..100 de 10 en 10:\n    {heap:?}");
+    let min = heap.pop()?;
+    let stats = heap.stats();
+    let (roots_visited, links) = (stats.roots_visited(), stats.links());
+    println!("  2. Extraer el mínimo ({min}, raíces recorridas: {roots_visited}, enlaces: {links}):\n    {heap:?}");
+    heap.decrease_key(handles[9], 5)?;
+    heap.decrease_key(handles[8], 4)?;
+    let stats = heap.stats();
+    let (cuts, cascading_cuts) = (stats.cuts(), stats.cascading_cuts());
+    println!("  3. Disminuir 90 a 5 y 80 a 4 (cortes: {cuts}, en cascada: {cascading_cuts}):\n    {heap:?}");
+    println!("  4. Trabajo amortizado por operación:");
+    for len in [1_000_u64, 10_000, 100_000] {
+        let mut heap: FibonacciHeap<u64> = FibonacciHeap::new();
+        let handles: Vec<FibonacciHeapHandle> = (0..len)
+            .map(|i| heap.push(len + i.wrapping_mul(2_654_435_761) % len))
+            .collect();
+        for (i, handle) in handles.iter().enumerate().step_by(2) {
+            // Los valores ya extraídos invalidan su identificador y se omiten.
+            if let Ok(&value) = heap.get(*handle) {
+                heap.decrease_key(*handle, value - i as u64 / 2)?;
+            }
+            if i % 8 == 0 {
+                heap.pop()?;
+            }
+        }
+        while !heap.is_empty() {
+            heap.pop()?;
+        }
+        let stats = heap.stats();
+        println!(
+            "    n = {len:>6}: {:>7} operaciones, {:>7} raíces recorridas, {:>6} cortes en cascada, {:.2} de trabajo por operación (log2 n = {})",
+            stats.operations(),
+            stats.roots_visited(),
+            stats.cascading_cuts(),
+            stats.amortized_work(),
+            len.ilog2()
+        );
+    }
+    Ok(())
+}
+
+fn compare_branching<const D: usize>() -> Result<(), Exceptions> {
+    // Secuencia pseudoaleatoria determinista para que todas las variantes reciban la misma entrada.
+    let values = (0..10_000_u64).map(|i| i.wrapping_mul(2_654_435_761) % 100_000);
+    let mut heap: DaryHeap<u64, D> = DaryHeap::new();
+    for value in values {
+        heap.push(value);
+    }
+    let (pushes, height) = (heap.comparisons(), heap.height());
+    heap.reset_comparisons();
+    while !heap.is_empty() {
+        heap.pop()?;
+    }
+    let pops = heap.comparisons();
+    println!(
+        "    D = {D:>2}: altura {height:>2}, {pushes:>6} comparaciones al insertar, {pops:>6} al extraer, {:>6} en total",
+        pushes + pops
+    );
+    Ok(())
+}
@@ -0,0 +1,376 @@
+use crate::arena::{Arena, Handle};
+use exceptions::Exceptions;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+
+/// Identificador de un valor insertado en un `PairingHeap`, necesario para `decrease_key`.
+///
+/// Deja de ser válido cuando su valor se extrae del montículo o cuando su montículo se combina con otro: los métodos que lo
+/// reciben lo detectan y retornan un error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PairingHeapHandle(Handle);
+
+#[derive(Clone)]
+struct Node<T> {
+    value: T,
+    child: Option<usize>,
+    sibling: Option<usize>,
+    // Padre si el nodo es el primer hijo, o hermano izquierdo en caso contrario.
+    prev: Option<usize>,
+}
+
+#[derive(Clone)]
+pub struct PairingHeap<T: Clone + Ord> {
+    // Los nodos viven en un arreglo y se enlazan por índice; las posiciones libres se reutilizan.
+    nodes: Arena<Node<T>>,
+    root: Option<usize>,
+    len: usize,
+}
+
+impl<T: Clone + Ord> PairingHeap<T> {
+    /// Crea un nuevo montículo de emparejamiento (de mínimos) vacío.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use heap::PairingHeap;
+    /// let heap: PairingHeap<u32> = PairingHeap::new();
+    ///
+    /// assert!(heap.is_empty());
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            nodes: Arena::new(),
+            root: None,
+            len: 0,
+        }
+    }
+
+    /// Inserta un valor y devuelve el identificador con el que se puede consultar o disminuir más adelante.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use heap::PairingHeap;
+    /// let mut heap = PairingHeap::new();
+    /// let five = heap.push(5);
+    /// heap.push(3);
+    ///
+    /// assert_eq!(heap.peek(), Ok(&3));
+    /// assert_eq!(heap.get(five), Ok(&5));
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante (`O(1)`): el nuevo nodo solo se empareja con la raíz.
+    pub fn push(&mut self, value: T) -> PairingHeapHandle {
+        let node = Node {
+            value,
+            child: None,
+            sibling: None,
+            prev: None,
+        };
+        let index = self.nodes.insert_with(|_| node);
+        self.root = Some(match self.root {
+            Some(root) => self.meld(root, index),
+            None => index,
+        });
+        self.len += 1;
+        PairingHeapHandle(self.nodes.handle(index))
+    }
+
+    /// Extrae y devuelve el menor valor del montículo.
+    ///
+    /// # Comportamiento
+    /// - Los hijos de la raíz se emparejan de izquierda a derecha y luego se combinan de derecha a izquierda (dos pasadas).
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use heap::PairingHeap;
+    /// let mut heap: PairingHeap<u32> = [4, 1, 3].into_iter().collect();
+    ///
+    /// assert_eq!(heap.pop(), Ok(1));
+    /// assert_eq!(heap.pop(), Ok(3));
+    /// assert_eq!(heap.pop(), Ok(4));
+    /// assert!(heap.pop().is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si el montículo está vacío.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo amortizado logarítmico (`O(log n)`).
+    pub fn pop(&mut self) -> Result<T, Exceptions> {
+        let root = self
+            .root
+            .ok_or_else(|| Exceptions::NoSuchElement(String::from("The heap is empty")))?;
+        let node = self.nodes.release(root);
+        self.root = node.child.map(|child| self.merge_pairs(child));
+        self.len -= 1;
+        Ok(node.value)
+    }
+
+    /// Obtiene una referencia al menor valor del montículo sin extraerlo.
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si el montículo está vacío.
+    pub fn peek(&self) -> Result<&T, Exceptions> {
+        self.root
+            .map(|root| &self.node(root).value)
+            .ok_or_else(|| Exceptions::NoSuchElement(String::from("The heap is empty")))
+    }
+
+    /// Obtiene una referencia al valor asociado a un identificador.
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si el valor del identificador ya se extrajo del montículo.
+    pub fn get(&self, handle: PairingHeapHandle) -> Result<&T, Exceptions> {
+        let index = self.nodes.check(handle.0)?;
+        Ok(&self.node(index).value)
+    }
+
+    /// Reemplaza el valor asociado a un identificador por otro menor o igual y restaura el orden del montículo.
+    ///
+    /// # Parámetros
+    /// - `handle`: El identificador devuelto por `push`.
+    /// - `value`: El nuevo valor, que no puede ser mayor que el actual.
+    ///
+    /// # Comportamiento
+    /// - El subárbol del nodo se separa de su padre y se empareja con la raíz, sin recorrer el resto del montículo.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use heap::PairingHeap;
+    /// let mut heap = PairingHeap::new();
+    /// heap.push(10);
+    /// let twenty = heap.push(20);
+    ///
+    /// heap.decrease_key(twenty, 5).unwrap();
+    /// assert_eq!(heap.peek(), Ok(&5));
+    /// assert_eq!(heap.pop(), Ok(5));
+    /// assert!(heap.decrease_key(twenty, 1).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si el valor del identificador ya se extrajo del montículo.
+    ///
+    /// # Panics
+    /// Entra en pánico si `value` es mayor que el valor actual.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante (`O(1)`); su costo amortizado real está acotado por `O(log n)`.
+    pub fn decrease_key(&mut self, handle: PairingHeapHandle, value: T) -> Result<(), Exceptions> {
+        let index = self.nodes.check(handle.0)?;
+        assert!(
+            value <= self.node(index).value,
+            "decrease_key cannot increase a value"
+        );
+        self.node_mut(index).value = value;
+        if self.root == Some(index) {
+            return Ok(());
+        }
+        self.detach(index);
+        let root = self.root.expect("a non-root node implies a root");
+        self.root = Some(self.meld(root, index));
+        Ok(())
+    }
+
+    /// Combina otro montículo con este, vaciándolo.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use heap::PairingHeap;
+    /// let mut odd: PairingHeap<u32> = [5, 1, 3].into_iter().collect();
+    /// let even: PairingHeap<u32> = [4, 2].into_iter().collect();
+    ///
+    /// odd.merge(even);
+    /// assert_eq!(odd.len(), 5);
+    /// assert_eq!(odd.into_sorted_vec(), vec![1, 2, 3, 4, 5]);
+    /// ```
+    ///
+    /// # Notas
+    /// - Los identificadores de `self` siguen siendo válidos; los de `other` no, porque sus nodos se trasladan a otras posiciones
+    ///   y pasan a pertenecer a `self`.
+    /// - Este método tiene un costo lineal en el tamaño de `other` (`O(m)`) por ese traslado; el emparejamiento de raíces es constante.
+    pub fn merge(&mut self, other: Self) {
+        let shift = |link: Option<usize>, offset| link.map(|index| index + offset);
+        let offset = self.nodes.absorb(other.nodes, |node, offset| Node {
+            child: shift(node.child, offset),
+            sibling: shift(node.sibling, offset),
+            prev: shift(node.prev, offset),
+            ..node
+        });
+        self.len += other.len;
+        self.root = match (self.root, shift(other.root, offset)) {
+            (Some(root), Some(other_root)) => Some(self.meld(root, other_root)),
+            (root, other_root) => root.or(other_root),
+        };
+    }
+
+    /// Devuelve la cantidad de valores almacenados.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Verifica si el montículo está vacío.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Consume el montículo y devuelve sus valores en orden ascendente.
+    #[must_use]
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut sorted = Vec::with_capacity(self.len);
+        while let Ok(value) = self.pop() {
+            sorted.push(value);
+        }
+        sorted
+    }
+
+    fn node(&self, index: usize) -> &Node<T> {
+        self.nodes.node(index)
+    }
+
+    fn node_mut(&mut self, index: usize) -> &mut Node<T> {
+        self.nodes.node_mut(index)
+    }
+
+    // Empareja dos raíces sin hermanos: la mayor pasa a ser el primer hijo de la menor.
+    fn meld(&mut self, first: usize, second: usize) -> usize {
+        let (parent, child) = if self.node(second).value < self.node(first).value {
+            (second, first)
+        } else {
+            (first, second)
+        };
+        let old_child = self.node(parent).child;
+        if let Some(old_child) = old_child {
+            self.node_mut(old_child).prev = Some(child);
+        }
+        let child_node = self.node_mut(child);
+        child_node.sibling = old_child;
+        child_node.prev = Some(parent);
+        self.node_mut(parent).child = Some(child);
+        parent
+    }
+
+    fn merge_pairs(&mut self, first: usize) -> usize {
+        let mut roots = Vec::new();
+        let mut current = Some(first);
+        while let Some(index) = current {
+            let node = self.node_mut(index);
+            current = node.sibling.take();
+            node.prev = None;
+            roots.push(index);
+        }
+        let mut paired: Vec<usize> = roots
+            .chunks(2)
+            .map(|pair| match *pair {
+                [first, second] => self.meld(first, second),
+                [single] => single,
+                _ => unreachable!(),
+            })
+            .collect();
+        let mut root = paired.pop().expect("a child list is never empty");
+        while let Some(previous) = paired.pop() {
+            root = self.meld(previous, root);
+        }
+        root
+    }
+
+    // Separa el subárbol de `index` de su padre o de su hermano izquierdo.
+    fn detach(&mut self, index: usize) {
+        let Node { sibling, prev, .. } = *self.node(index);
+        if let Some(prev) = prev {
+            let prev_node = self.node_mut(prev);
+            if prev_node.child == Some(index) {
+                prev_node.child = sibling;
+            } else {
+                prev_node.sibling = sibling;
+            }
+        }
+        if let Some(sibling) = sibling {
+            self.node_mut(sibling).prev = prev;
+        }
+        let node = self.node_mut(index);
+        node.sibling = None;
+        node.prev = None;
+    }
+}
+
+impl<T: Clone + Ord> Default for PairingHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone + Ord> FromIterator<T> for PairingHeap<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut heap = Self::new();
+        for value in iter {
+            heap.push(value);
+        }
+        heap
+    }
+}
+
+impl<T: Clone + Ord + Debug> Debug for PairingHeap<T> {
+    /// Muestra los valores en orden ascendente.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "{:?}", self.clone().into_sorted_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PairingHeap;
+
+    #[test]
+    fn decrease_key_keeps_the_heap_order() {
+        let mut heap = PairingHeap::new();
+        let handles: Vec<_> = (0..200_u64)
+            .map(|i| heap.push(1000 + i * 7919 % 503))
+            .collect();
+        for (i, handle) in handles.iter().enumerate().step_by(3) {
+            let current = *heap.get(*handle).unwrap();
+            heap.decrease_key(*handle, current - (i as u64 % 900))
+                .unwrap();
+        }
+        let mut expected: Vec<u64> = handles
+            .iter()
+            .map(|handle| *heap.get(*handle).unwrap())
+            .collect();
+        expected.sort_unstable();
+
+        let mut popped = Vec::new();
+        for round in 0..200 {
+            popped.push(heap.pop().unwrap());
+            if round % 10 == 0 {
+                heap.push(0);
+                popped.push(heap.pop().unwrap());
+                expected.insert(popped.len() - 1, 0);
+            }
+        }
+        assert_eq!(popped, expected);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn handles_of_a_merged_heap_are_rejected() {
+        let mut heap = PairingHeap::new();
+        let mine = heap.push(10);
+        let mut other = PairingHeap::new();
+        let theirs = other.push(20);
+        heap.merge(other);
+        assert!(heap.get(theirs).is_err());
+        assert!(heap.decrease_key(theirs, 0).is_err());
+        assert_eq!(heap.get(mine), Ok(&10));
+
+        let copy = heap.clone();
+        heap.merge(copy);
+        assert_eq!(heap.len(), 4);
+        assert_eq!(heap.get(mine), Ok(&10));
+    }
+}
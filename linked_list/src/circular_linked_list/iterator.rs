@@ -0,0 +1,43 @@
+use super::node::Node;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+#[derive(Clone)]
+pub struct CircularLinkedListIterator<'a, T: Clone> {
+    current: Option<NonNull<Node<T>>>,
+    remaining: usize,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+impl<T: Clone> CircularLinkedListIterator<'_, T> {
+    pub const fn new(head_node: Option<NonNull<Node<T>>>, len: usize) -> Self {
+        Self {
+            current: head_node,
+            remaining: len,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone> Iterator for CircularLinkedListIterator<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.current.map(|node| {
+            // SAFETY: el iterador toma prestada la lista, por lo que sus nodos siguen vivos.
+            let node = unsafe { node.as_ref() };
+            self.current = node.get_next();
+            self.remaining -= 1;
+            node.get().to_owned()
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for CircularLinkedListIterator<'_, T> {}
@@ -0,0 +1,463 @@
+mod iterator;
+mod node;
+
+use exceptions::Exceptions;
+use iterator::CircularLinkedListIterator;
+use node::Node;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+pub struct CircularLinkedList<T: Clone> {
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<Box<Node<T>>>,
+}
+
+impl<T: Clone> CircularLinkedList<T> {
+    /// Crea una nueva lista enlazada circular vacía.
+    ///
+    /// # Retornos
+    /// - Devuelve una instancia vacía de `CircularLinkedList` sin nodos y con longitud inicial de `0`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::CircularLinkedList;
+    /// let list: CircularLinkedList<i32> = CircularLinkedList::new();
+    ///
+    /// assert!(list.is_empty());
+    /// assert_eq!(list.len(), 0);
+    /// ```
+    ///
+    /// # Notas
+    /// - La lista solo guarda una referencia a la cola (`tail`); la cabeza es siempre el sucesor de la cola.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            tail: None,
+            len: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Crea una nueva lista enlazada circular con un único nodo, enlazado consigo mismo.
+    ///
+    /// # Parámetros
+    /// - `data`: El valor inicial con el que se creará el primer nodo de la lista.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::CircularLinkedList;
+    /// let list = CircularLinkedList::with_data(42);
+    ///
+    /// assert_eq!(list.len(), 1);
+    /// assert_eq!(list.get(0), Ok(&42));
+    /// ```
+    pub fn with_data(data: T) -> Self {
+        let mut list = Self::new();
+        list.push(data);
+        list
+    }
+
+    /// Obtiene una referencia inmutable al elemento en el índice especificado, contando desde la cabeza.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del elemento que se desea obtener. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Retornos
+    /// - `Ok(&T)`: Si el índice es válido, devuelve una referencia inmutable al elemento en ese índice.
+    /// - `Err(Exceptions::IndexOutOfBounds)`: Si el índice está fuera del rango válido de la lista.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::CircularLinkedList;
+    /// let list = CircularLinkedList::from(&[10, 20, 30]);
+    ///
+    /// assert_eq!(list.get(2), Ok(&30));
+    /// assert!(list.get(3).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::IndexOutOfBounds` si `index` es mayor o igual a la longitud de la lista (`len`).
+    ///
+    /// # Notas
+    /// - Aunque la lista es circular, los índices no dan la vuelta: se validan contra `len`.
+    pub fn get(&self, index: usize) -> Result<&T, Exceptions> {
+        let node = self.node_at(index).ok_or(Exceptions::IndexOutOfBounds)?;
+        // SAFETY: `node_at` solo devuelve nodos vivos de la lista, prestados junto con `self`.
+        Ok(unsafe { node.as_ref() }.get())
+    }
+
+    /// Obtiene una referencia mutable al elemento en el índice especificado, contando desde la cabeza.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del elemento que se desea obtener. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::CircularLinkedList;
+    /// let mut list = CircularLinkedList::from(&[10, 20]);
+    ///
+    /// if let Ok(value) = list.get_mut(1) {
+    ///     *value = 25;
+    /// }
+    /// assert_eq!(list.get(1), Ok(&25));
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::IndexOutOfBounds` si `index` es mayor o igual a la longitud de la lista (`len`).
+    pub fn get_mut(&mut self, index: usize) -> Result<&mut T, Exceptions> {
+        let mut node = self.node_at(index).ok_or(Exceptions::IndexOutOfBounds)?;
+        // SAFETY: el nodo pertenece a la lista y el préstamo mutable de `self` garantiza exclusividad.
+        Ok(unsafe { node.as_mut() }.get_mut())
+    }
+
+    /// Establece un nuevo valor en el índice especificado, contando desde la cabeza.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del elemento que se desea modificar. Debe estar en el rango `0..self.len`.
+    /// - `value`: El nuevo valor que se asignará al nodo.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::CircularLinkedList;
+    /// let mut list = CircularLinkedList::with_data(42);
+    ///
+    /// assert_eq!(list.set(0, 100), Ok(()));
+    /// assert_eq!(list.get(0), Ok(&100));
+    /// assert!(list.set(1, 200).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::IndexOutOfBounds` si `index` es mayor o igual a la longitud de la lista (`len`).
+    pub fn set(&mut self, index: usize, value: T) -> Result<(), Exceptions> {
+        let mut node = self.node_at(index).ok_or(Exceptions::IndexOutOfBounds)?;
+        // SAFETY: el nodo pertenece a la lista y el préstamo mutable de `self` garantiza exclusividad.
+        unsafe { node.as_mut() }.set(value);
+        Ok(())
+    }
+
+    /// Inserta un nuevo elemento al inicio de la lista enlazada circular.
+    ///
+    /// # Parámetros
+    /// - `value`: El valor que se desea agregar al inicio de la lista.
+    ///
+    /// # Comportamiento
+    /// - El nuevo nodo se enlaza como sucesor de la cola, por lo que pasa a ser la nueva cabeza.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::CircularLinkedList;
+    /// let mut list = CircularLinkedList::new();
+    ///
+    /// list.unshift(10);
+    /// list.unshift(20);
+    ///
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![20, 10]);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante (`O(1)`).
+    pub fn unshift(&mut self, value: T) {
+        let node = self.link_after_tail(value);
+        if self.tail.is_none() {
+            self.tail = Some(node);
+        }
+    }
+
+    /// Inserta un nuevo elemento al final de la lista enlazada circular.
+    ///
+    /// # Parámetros
+    /// - `value`: El valor que se desea agregar al final de la lista.
+    ///
+    /// # Comportamiento
+    /// - El nuevo nodo se enlaza como sucesor de la cola y pasa a ser la nueva cola, cerrando el ciclo con la cabeza.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::CircularLinkedList;
+    /// let mut list = CircularLinkedList::new();
+    ///
+    /// list.push(10);
+    /// list.push(20);
+    ///
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![10, 20]);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante (`O(1)`).
+    pub fn push(&mut self, value: T) {
+        let node = self.link_after_tail(value);
+        self.tail = Some(node);
+    }
+
+    /// Elimina y devuelve el primer elemento de la lista enlazada circular.
+    ///
+    /// # Retornos
+    /// - `Ok(T)`: Si la lista no está vacía, devuelve el valor de la cabeza eliminada.
+    /// - `Err(Exceptions::NoSuchElement)`: Si la lista está vacía.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::CircularLinkedList;
+    /// let mut list = CircularLinkedList::from(&[1, 2]);
+    ///
+    /// assert_eq!(list.shift(), Ok(1));
+    /// assert_eq!(list.shift(), Ok(2));
+    /// assert!(list.shift().is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si la lista está vacía.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante (`O(1)`), ya que la cola conoce a la cabeza.
+    pub fn shift(&mut self) -> Result<T, Exceptions> {
+        let Some(mut tail) = self.tail else {
+            return Err(Exceptions::NoSuchElement(String::from("The list is empty")));
+        };
+        // SAFETY: la cola está viva y siempre tiene un sucesor (ella misma si es el único nodo).
+        let head = unsafe { tail.as_ref() }.get_next().unwrap_or(tail);
+        if head == tail {
+            self.tail = None;
+        } else {
+            // SAFETY: `head` y `tail` son nodos vivos y distintos de la lista.
+            unsafe { tail.as_mut().set_next(head.as_ref().get_next()) };
+        }
+        self.len -= 1;
+        // SAFETY: `head` ya fue desenlazado y fue creado con `Box::leak` por esta lista.
+        Ok(unsafe { Box::from_raw(head.as_ptr()) }.into_data())
+    }
+
+    /// Elimina y devuelve el último elemento de la lista enlazada circular.
+    ///
+    /// # Retornos
+    /// - `Ok(T)`: Si la lista no está vacía, devuelve el valor de la cola eliminada.
+    /// - `Err(Exceptions::NoSuchElement)`: Si la lista está vacía.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::CircularLinkedList;
+    /// let mut list = CircularLinkedList::from(&[1, 2]);
+    ///
+    /// assert_eq!(list.pop(), Ok(2));
+    /// assert_eq!(list.pop(), Ok(1));
+    /// assert!(list.pop().is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si la lista está vacía.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`), ya que necesita recorrer la lista para encontrar al predecesor de la cola.
+    pub fn pop(&mut self) -> Result<T, Exceptions> {
+        let Some(tail) = self.tail else {
+            return Err(Exceptions::NoSuchElement(String::from("The list is empty")));
+        };
+        if self.len == 1 {
+            self.tail = None;
+        } else {
+            let mut pred = self
+                .node_at(self.len - 2)
+                .ok_or_else(|| Exceptions::NoSuchElement(String::from("Element not found")))?;
+            // SAFETY: `pred` y `tail` son nodos vivos y distintos de la lista.
+            unsafe { pred.as_mut().set_next(tail.as_ref().get_next()) };
+            self.tail = Some(pred);
+        }
+        self.len -= 1;
+        // SAFETY: `tail` ya fue desenlazado y fue creado con `Box::leak` por esta lista.
+        Ok(unsafe { Box::from_raw(tail.as_ptr()) }.into_data())
+    }
+
+    /// Rota la lista una posición: la cabeza actual pasa a ser la cola.
+    ///
+    /// # Comportamiento
+    /// - Solo se mueve la referencia a la cola; ningún nodo se crea, libera ni reenlaza.
+    /// - En una lista vacía o con un solo elemento no tiene efecto visible.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::CircularLinkedList;
+    /// let mut turns = CircularLinkedList::from(&["Ana", "Luis", "Eva"]);
+    ///
+    /// // Planificación round-robin: atender a la cabeza y pasarla al final.
+    /// assert_eq!(turns.get(0), Ok(&"Ana"));
+    /// turns.rotate();
+    /// assert_eq!(turns.get(0), Ok(&"Luis"));
+    /// turns.rotate();
+    /// turns.rotate();
+    /// assert_eq!(turns.get(0), Ok(&"Ana"));
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante (`O(1)`).
+    pub fn rotate(&mut self) {
+        if let Some(tail) = self.tail {
+            // SAFETY: la cola está viva y siempre tiene un sucesor.
+            self.tail = unsafe { tail.as_ref() }.get_next();
+        }
+    }
+
+    /// Devuelve la cantidad de elementos almacenados actualmente en la lista enlazada circular.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::CircularLinkedList;
+    /// let list = CircularLinkedList::from(&[1, 2, 3]);
+    /// assert_eq!(list.len(), 3);
+    /// ```
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Verifica si la lista enlazada circular está vacía.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::CircularLinkedList;
+    /// let mut list = CircularLinkedList::default();
+    /// assert!(list.is_empty());
+    ///
+    /// list.push(10);
+    /// assert!(!list.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Crea un iterador acotado que recorre cada elemento exactamente una vez, desde la cabeza hasta la cola.
+    ///
+    /// # Comportamiento
+    /// - Aunque los nodos forman un ciclo, el iterador se detiene después de `len` elementos.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::CircularLinkedList;
+    /// let list = CircularLinkedList::from(&[1, 2, 3]);
+    ///
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    ///
+    /// // Para dar varias vueltas, se puede combinar con `cycle` y `take`.
+    /// let laps: Vec<i32> = list.iter().cycle().take(7).collect();
+    /// assert_eq!(laps, vec![1, 2, 3, 1, 2, 3, 1]);
+    /// ```
+    #[must_use]
+    pub fn iter(&self) -> CircularLinkedListIterator<'_, T> {
+        CircularLinkedListIterator::new(self.head(), self.len)
+    }
+
+    fn head(&self) -> Option<NonNull<Node<T>>> {
+        // SAFETY: la cola está viva mientras la lista exista.
+        self.tail
+            .and_then(|tail| unsafe { tail.as_ref() }.get_next())
+    }
+
+    fn node_at(&self, index: usize) -> Option<NonNull<Node<T>>> {
+        if index >= self.len {
+            return None;
+        }
+        let mut current = self.head();
+        for _ in 0..index {
+            // SAFETY: `index < len`, por lo que todos los nodos recorridos existen.
+            current = current.and_then(|node| unsafe { node.as_ref() }.get_next());
+        }
+        current
+    }
+
+    fn link_after_tail(&mut self, value: T) -> NonNull<Node<T>> {
+        let mut node = NonNull::from(Box::leak(Box::new(Node::new(value))));
+        match self.tail {
+            // SAFETY: `tail` y `node` son nodos vivos; `node` aún no está enlazado.
+            Some(mut tail) => unsafe {
+                node.as_mut().set_next(tail.as_ref().get_next());
+                tail.as_mut().set_next(Some(node));
+            },
+            // SAFETY: `node` acaba de crearse y nadie más lo referencia.
+            None => unsafe { node.as_mut() }.set_next(Some(node)),
+        }
+        self.len += 1;
+        node
+    }
+}
+
+impl<T: Clone, const N: usize> From<&[T; N]> for CircularLinkedList<T> {
+    fn from(values: &[T; N]) -> Self {
+        Self::from(values.as_slice())
+    }
+}
+
+impl<T: Clone> From<&[T]> for CircularLinkedList<T> {
+    fn from(values: &[T]) -> Self {
+        let mut list: Self = Self::default();
+        for value in values {
+            list.push(value.to_owned());
+        }
+        list
+    }
+}
+
+impl<T: Clone> From<Vec<T>> for CircularLinkedList<T> {
+    fn from(values: Vec<T>) -> Self {
+        let mut list: Self = Self::default();
+        for value in values {
+            list.push(value);
+        }
+        list
+    }
+}
+
+impl<T: Clone> Default for CircularLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for CircularLinkedList<T> {
+    fn clone(&self) -> Self {
+        let mut list: Self = Self::default();
+        for value in self {
+            list.push(value);
+        }
+        list
+    }
+}
+
+impl<T: Clone> Drop for CircularLinkedList<T> {
+    fn drop(&mut self) {
+        while self.shift().is_ok() {}
+    }
+}
+
+// SAFETY: la lista es dueña exclusiva de sus nodos, igual que un `Box<Node<T>>`.
+unsafe impl<T: Clone + Send> Send for CircularLinkedList<T> {}
+// SAFETY: los accesos a través de `&CircularLinkedList` solo producen `&T`.
+unsafe impl<T: Clone + Sync> Sync for CircularLinkedList<T> {}
+
+impl<'a, T: Clone> IntoIterator for &'a CircularLinkedList<T> {
+    type Item = T;
+    type IntoIter = CircularLinkedListIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Clone + Debug> Debug for CircularLinkedList<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "[")?;
+        for (index, value) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{value:?}")?;
+        }
+        write!(f, "]")
+    }
+}
@@ -0,0 +1,63 @@
+use super::node::Node;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+pub struct DoublyLinkedListIterator<'a, T: Clone> {
+    front: Option<NonNull<Node<T>>>,
+    back: Option<NonNull<Node<T>>>,
+    remaining: usize,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+impl<T: Clone> DoublyLinkedListIterator<'_, T> {
+    pub const fn new(
+        head_node: Option<NonNull<Node<T>>>,
+        tail_node: Option<NonNull<Node<T>>>,
+        len: usize,
+    ) -> Self {
+        Self {
+            front: head_node,
+            back: tail_node,
+            remaining: len,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone> Iterator for DoublyLinkedListIterator<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.front.map(|node| {
+            // SAFETY: el iterador toma prestada la lista, por lo que sus nodos siguen vivos.
+            let node = unsafe { node.as_ref() };
+            self.front = node.get_next();
+            self.remaining -= 1;
+            node.get().to_owned()
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for DoublyLinkedListIterator<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.back.map(|node| {
+            // SAFETY: el iterador toma prestada la lista, por lo que sus nodos siguen vivos.
+            let node = unsafe { node.as_ref() };
+            self.back = node.get_prev();
+            self.remaining -= 1;
+            node.get().to_owned()
+        })
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for DoublyLinkedListIterator<'_, T> {}
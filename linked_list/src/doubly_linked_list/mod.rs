@@ -0,0 +1,960 @@
+mod iterator;
+mod node;
+
+use exceptions::Exceptions;
+use iterator::DoublyLinkedListIterator;
+use node::Node;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+pub struct DoublyLinkedList<T: Clone> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<Box<Node<T>>>,
+}
+
+impl<T: Clone> DoublyLinkedList<T> {
+    /// Crea una nueva lista doblemente enlazada vacía.
+    ///
+    /// # Retornos
+    /// - Devuelve una instancia vacía de `DoublyLinkedList` sin nodos y con longitud inicial de `0`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::DoublyLinkedList;
+    /// let list: DoublyLinkedList<i32> = DoublyLinkedList::new();
+    ///
+    /// // La lista está vacía.
+    /// assert!(list.is_empty());
+    /// assert_eq!(list.len(), 0);
+    /// ```
+    ///
+    /// # Notas
+    /// - Esta función es una operación de tiempo constante (`O(1)`).
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            head: None,
+            tail: None,
+            len: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Crea una nueva lista doblemente enlazada con un único nodo inicializado con el valor proporcionado.
+    ///
+    /// # Parámetros
+    /// - `data`: El valor inicial con el que se creará el primer nodo de la lista.
+    ///
+    /// # Retornos
+    /// - Devuelve una instancia de `DoublyLinkedList` con un único nodo, que es a la vez cabeza (`head`) y cola (`tail`).
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::DoublyLinkedList;
+    /// let list = DoublyLinkedList::with_data(42);
+    ///
+    /// assert_eq!(list.len(), 1);
+    /// assert_eq!(list.get(0), Ok(&42));
+    /// ```
+    pub fn with_data(data: T) -> Self {
+        let mut list = Self::new();
+        list.push_back(data);
+        list
+    }
+
+    /// Obtiene una referencia inmutable al elemento en el índice especificado de la lista doblemente enlazada.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del elemento que se desea obtener. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Retornos
+    /// - `Ok(&T)`: Si el índice es válido, devuelve una referencia inmutable al elemento en ese índice.
+    /// - `Err(Exceptions::IndexOutOfBounds)`: Si el índice está fuera del rango válido de la lista.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::DoublyLinkedList;
+    /// let list = DoublyLinkedList::from(&[10, 20, 30]);
+    ///
+    /// assert_eq!(list.get(0), Ok(&10));
+    /// assert_eq!(list.get(2), Ok(&30));
+    /// assert!(list.get(3).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::IndexOutOfBounds` si `index` es mayor o igual a la longitud de la lista (`len`).
+    ///
+    /// # Notas
+    /// - El recorrido comienza desde el extremo más cercano al índice, por lo que visita como máximo `len / 2` nodos.
+    pub fn get(&self, index: usize) -> Result<&T, Exceptions> {
+        let node = self.node_at(index).ok_or(Exceptions::IndexOutOfBounds)?;
+        // SAFETY: `node_at` solo devuelve nodos vivos de la lista, prestados junto con `self`.
+        Ok(unsafe { node.as_ref() }.get())
+    }
+
+    /// Obtiene una referencia mutable al elemento en el índice especificado de la lista doblemente enlazada.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del elemento que se desea obtener. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Retornos
+    /// - `Ok(&mut T)`: Si el índice es válido, devuelve una referencia mutable al elemento en ese índice.
+    /// - `Err(Exceptions::IndexOutOfBounds)`: Si el índice está fuera del rango válido de la lista.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::DoublyLinkedList;
+    /// let mut list = DoublyLinkedList::from(&[10, 20, 30]);
+    ///
+    /// if let Ok(value) = list.get_mut(1) {
+    ///     *value = 25;
+    /// }
+    /// assert_eq!(list.get(1), Ok(&25));
+    /// assert!(list.get_mut(3).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::IndexOutOfBounds` si `index` es mayor o igual a la longitud de la lista (`len`).
+    pub fn get_mut(&mut self, index: usize) -> Result<&mut T, Exceptions> {
+        let mut node = self.node_at(index).ok_or(Exceptions::IndexOutOfBounds)?;
+        // SAFETY: el nodo pertenece a la lista y el préstamo mutable de `self` garantiza exclusividad.
+        Ok(unsafe { node.as_mut() }.get_mut())
+    }
+
+    /// Establece un nuevo valor en el índice especificado de la lista doblemente enlazada.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del elemento que se desea modificar. Debe estar en el rango `0..self.len`.
+    /// - `value`: El nuevo valor que se asignará al nodo en el índice especificado.
+    ///
+    /// # Retornos
+    /// - `Ok(())`: Si el índice es válido, el valor se actualiza correctamente.
+    /// - `Err(Exceptions::IndexOutOfBounds)`: Si el índice está fuera del rango válido de la lista.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::DoublyLinkedList;
+    /// let mut list = DoublyLinkedList::with_data(42);
+    ///
+    /// assert_eq!(list.set(0, 100), Ok(()));
+    /// assert_eq!(list.get(0), Ok(&100));
+    /// assert!(list.set(1, 200).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::IndexOutOfBounds` si `index` es mayor o igual a la longitud de la lista (`len`).
+    pub fn set(&mut self, index: usize, value: T) -> Result<(), Exceptions> {
+        let mut node = self.node_at(index).ok_or(Exceptions::IndexOutOfBounds)?;
+        // SAFETY: el nodo pertenece a la lista y el préstamo mutable de `self` garantiza exclusividad.
+        unsafe { node.as_mut() }.set(value);
+        Ok(())
+    }
+
+    /// Inserta un nuevo elemento al inicio de la lista doblemente enlazada.
+    ///
+    /// # Parámetros
+    /// - `value`: El valor que se desea agregar al inicio de la lista.
+    ///
+    /// # Comportamiento
+    /// - El nuevo nodo se convierte en la cabeza (`head`) y enlaza hacia atrás al nodo que antes era el primero.
+    /// - Si la lista estaba vacía, el nuevo nodo también se convierte en la cola (`tail`).
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::DoublyLinkedList;
+    /// let mut list = DoublyLinkedList::new();
+    ///
+    /// list.push_front(10);
+    /// list.push_front(20);
+    ///
+    /// // La lista ahora contiene [20, 10].
+    /// assert_eq!(list.get(0), Ok(&20));
+    /// assert_eq!(list.get(1), Ok(&10));
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante (`O(1)`).
+    pub fn push_front(&mut self, value: T) {
+        self.push_front_handle(value);
+    }
+
+    /// Inserta un nuevo elemento al final de la lista doblemente enlazada.
+    ///
+    /// # Parámetros
+    /// - `value`: El valor que se desea agregar al final de la lista.
+    ///
+    /// # Comportamiento
+    /// - El nuevo nodo se enlaza directamente después de la cola (`tail`), sin recorrer la lista.
+    /// - Si la lista estaba vacía, el nuevo nodo también se convierte en la cabeza (`head`).
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::DoublyLinkedList;
+    /// let mut list = DoublyLinkedList::new();
+    ///
+    /// list.push_back(10);
+    /// list.push_back(20);
+    ///
+    /// // La lista ahora contiene [10, 20].
+    /// assert_eq!(list.get(0), Ok(&10));
+    /// assert_eq!(list.get(1), Ok(&20));
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante (`O(1)`), a diferencia de `SinglyLinkedList::push`.
+    pub fn push_back(&mut self, value: T) {
+        let mut node = Box::new(Node::new(value));
+        node.set_prev(self.tail);
+        let node = NonNull::from(Box::leak(node));
+        match self.tail {
+            // SAFETY: `tail` apunta a un nodo vivo propiedad de la lista.
+            Some(mut tail) => unsafe { tail.as_mut() }.set_next(Some(node)),
+            None => self.head = Some(node),
+        }
+        self.tail = Some(node);
+        self.len += 1;
+    }
+
+    /// Elimina y devuelve el primer elemento de la lista doblemente enlazada.
+    ///
+    /// # Retornos
+    /// - `Ok(T)`: Si la lista no está vacía, devuelve el valor del primer elemento eliminado.
+    /// - `Err(Exceptions::NoSuchElement)`: Si la lista está vacía.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::DoublyLinkedList;
+    /// let mut list = DoublyLinkedList::from(&[1, 2]);
+    ///
+    /// assert_eq!(list.pop_front(), Ok(1));
+    /// assert_eq!(list.pop_front(), Ok(2));
+    /// assert!(list.pop_front().is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si la lista está vacía.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante (`O(1)`).
+    pub fn pop_front(&mut self) -> Result<T, Exceptions> {
+        match self.head {
+            Some(node) => Ok(self.unlink(node)),
+            None => Err(Exceptions::NoSuchElement(String::from("The list is empty"))),
+        }
+    }
+
+    /// Elimina y devuelve el último elemento de la lista doblemente enlazada.
+    ///
+    /// # Retornos
+    /// - `Ok(T)`: Si la lista no está vacía, devuelve el valor del último elemento eliminado.
+    /// - `Err(Exceptions::NoSuchElement)`: Si la lista está vacía.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::DoublyLinkedList;
+    /// let mut list = DoublyLinkedList::from(&[1, 2]);
+    ///
+    /// assert_eq!(list.pop_back(), Ok(2));
+    /// assert_eq!(list.pop_back(), Ok(1));
+    /// assert!(list.pop_back().is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si la lista está vacía.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante (`O(1)`) gracias al enlace `prev` de la cola, a diferencia de `SinglyLinkedList::pop`.
+    pub fn pop_back(&mut self) -> Result<T, Exceptions> {
+        match self.tail {
+            Some(node) => Ok(self.unlink(node)),
+            None => Err(Exceptions::NoSuchElement(String::from("The list is empty"))),
+        }
+    }
+
+    /// Inserta un nuevo elemento al inicio de la lista. Equivalente a `push_front`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::DoublyLinkedList;
+    /// let mut list = DoublyLinkedList::with_data(10);
+    /// list.unshift(5);
+    /// assert_eq!(list.get(0), Ok(&5));
+    /// ```
+    ///
+    /// # Notas
+    /// - Se mantiene el mismo nombre que en `SinglyLinkedList` para que ambas listas sean intercambiables.
+    pub fn unshift(&mut self, value: T) {
+        self.push_front(value);
+    }
+
+    /// Inserta un nuevo elemento al final de la lista. Equivalente a `push_back`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::DoublyLinkedList;
+    /// let mut list = DoublyLinkedList::with_data(10);
+    /// list.push(15);
+    /// assert_eq!(list.get(1), Ok(&15));
+    /// ```
+    ///
+    /// # Notas
+    /// - Se mantiene el mismo nombre que en `SinglyLinkedList` para que ambas listas sean intercambiables.
+    pub fn push(&mut self, value: T) {
+        self.push_back(value);
+    }
+
+    /// Elimina y devuelve el primer elemento de la lista. Equivalente a `pop_front`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::DoublyLinkedList;
+    /// let mut list = DoublyLinkedList::with_data(42);
+    /// assert_eq!(list.shift(), Ok(42));
+    /// assert!(list.shift().is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si la lista está vacía.
+    pub fn shift(&mut self) -> Result<T, Exceptions> {
+        self.pop_front()
+    }
+
+    /// Elimina y devuelve el último elemento de la lista. Equivalente a `pop_back`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::DoublyLinkedList;
+    /// let mut list = DoublyLinkedList::from(&[42, 100]);
+    /// assert_eq!(list.pop(), Ok(100));
+    /// assert_eq!(list.len(), 1);
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si la lista está vacía.
+    pub fn pop(&mut self) -> Result<T, Exceptions> {
+        self.pop_back()
+    }
+
+    /// Inserta un nuevo elemento en la lista doblemente enlazada en un índice específico.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice donde se desea insertar el nuevo elemento. Debe estar en el rango `0..self.len`.
+    /// - `value`: El valor que se desea insertar en el índice especificado.
+    ///
+    /// # Retornos
+    /// - `Ok(())`: Si el índice es válido, el valor se inserta correctamente.
+    /// - `Err(Exceptions::IndexOutOfBounds)`: Si el índice está fuera del rango permitido.
+    ///
+    /// # Comportamiento
+    /// - Si `index` es `0`, se delega en `push_front`.
+    /// - En otro caso, el nuevo nodo se enlaza entre el nodo en `index - 1` y el nodo en `index`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::DoublyLinkedList;
+    /// let mut list = DoublyLinkedList::from(&[10, 30]);
+    ///
+    /// assert_eq!(list.insert(1, 20), Ok(()));
+    /// assert_eq!(list.get(1), Ok(&20));
+    ///
+    /// // Igual que en `SinglyLinkedList`, insertar en `len` retorna un error: para agregar al final se usa `push_back`.
+    /// assert!(list.insert(3, 40).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::IndexOutOfBounds` si `index` es mayor o igual a `self.len`, incluso en una lista vacía.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`), aunque solo recorre desde el extremo más cercano al índice.
+    pub fn insert(&mut self, index: usize, value: T) -> Result<(), Exceptions> {
+        if index >= self.len {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        if index == 0 {
+            self.push_front(value);
+            return Ok(());
+        }
+        let mut next = self.node_at(index).ok_or(Exceptions::IndexOutOfBounds)?;
+        // SAFETY: `next` no es la cabeza, por lo que tiene un predecesor vivo.
+        let mut prev = unsafe { next.as_ref() }
+            .get_prev()
+            .ok_or(Exceptions::IndexOutOfBounds)?;
+        let mut node = Box::new(Node::new(value));
+        node.set_prev(Some(prev));
+        node.set_next(Some(next));
+        let node = NonNull::from(Box::leak(node));
+        // SAFETY: `prev` y `next` son nodos vivos y adyacentes de la lista.
+        unsafe {
+            prev.as_mut().set_next(Some(node));
+            next.as_mut().set_prev(Some(node));
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Inserta un nuevo elemento después del primer nodo cuyo valor cumple el predicado.
+    ///
+    /// # Parámetros
+    /// - `pred`: La condición que identifica al nodo predecesor.
+    /// - `value`: El valor que se desea insertar en la lista.
+    ///
+    /// # Retornos
+    /// - `Ok(())`: Si algún nodo cumple `pred` y el nuevo valor se inserta correctamente.
+    /// - `Err(Exceptions::NoSuchElement)`: Si ningún nodo cumple `pred`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::DoublyLinkedList;
+    /// let mut list = DoublyLinkedList::from(vec![String::from("Venus"), String::from("Marte")]);
+    ///
+    /// assert_eq!(list.insert_after_by(|planet| planet == "Marte", String::from("Jupiter")), Ok(()));
+    /// assert_eq!(list.get(2), Ok(&String::from("Jupiter")));
+    ///
+    /// // Intentar insertar después de un valor que no existe.
+    /// assert!(list.insert_after_by(|planet| planet.is_empty(), String::new()).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si ningún nodo cumple `pred`.
+    pub fn insert_after_by<F: Fn(&T) -> bool>(
+        &mut self,
+        pred: F,
+        value: T,
+    ) -> Result<(), Exceptions> {
+        let mut current = self.head;
+        let mut position: usize = 0;
+        while let Some(node) = current {
+            // SAFETY: `current` recorre nodos vivos de la lista, prestada de forma exclusiva.
+            if pred(unsafe { node.as_ref() }.get()) {
+                if position + 1 == self.len {
+                    self.push_back(value);
+                    return Ok(());
+                }
+                return self.insert(position + 1, value);
+            }
+            current = Self::next_of(current);
+            position += 1;
+        }
+        Err(Exceptions::NoSuchElement(String::from(
+            "Predecessor not found",
+        )))
+    }
+
+    /// Elimina y devuelve el elemento en el índice especificado de la lista doblemente enlazada.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del elemento que se desea eliminar. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Retornos
+    /// - `Ok(T)`: Si el índice es válido, devuelve el valor del elemento eliminado.
+    /// - `Err(Exceptions::NoSuchElement)`: Si la lista está vacía.
+    /// - `Err(Exceptions::IndexOutOfBounds)`: Si el índice está fuera del rango válido de la lista.
+    ///
+    /// # Comportamiento
+    /// - El nodo se desenlaza conectando directamente a su predecesor con su sucesor, sin reconstruir la lista.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::DoublyLinkedList;
+    /// # use exceptions::Exceptions;
+    /// let mut list = DoublyLinkedList::from(&[10, 20, 30, 40]);
+    ///
+    /// assert_eq!(list.remove(3), Ok(40));
+    /// assert_eq!(list.remove(1), Ok(20));
+    /// assert_eq!(list.remove(0), Ok(10));
+    /// assert_eq!(list.get(0), Ok(&30));
+    /// assert_eq!(list.len(), 1);
+    ///
+    /// // Intentar eliminar un índice fuera de rango.
+    /// assert_eq!(list.remove(1), Err(Exceptions::IndexOutOfBounds));
+    ///
+    /// // En una lista vacía, el error indica que no hay elementos.
+    /// list.remove(0).unwrap();
+    /// assert!(matches!(list.remove(0), Err(Exceptions::NoSuchElement(_))));
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si la lista está vacía.
+    /// - `Exceptions::IndexOutOfBounds` si `index` es mayor o igual a la longitud de la lista (`len`).
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`) por la búsqueda del nodo; el desenlace en sí es `O(1)`.
+    pub fn remove(&mut self, index: usize) -> Result<T, Exceptions> {
+        if self.is_empty() {
+            return Err(Exceptions::NoSuchElement(String::from("The list is empty")));
+        }
+        let node = self.node_at(index).ok_or(Exceptions::IndexOutOfBounds)?;
+        Ok(self.unlink(node))
+    }
+
+    /// Devuelve la cantidad de elementos almacenados actualmente en la lista doblemente enlazada.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::DoublyLinkedList;
+    /// let mut list = DoublyLinkedList::from(&[1, 2, 3]);
+    /// assert_eq!(list.len(), 3);
+    ///
+    /// list.push_back(4);
+    /// assert_eq!(list.len(), 4);
+    /// ```
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Verifica si la lista doblemente enlazada está vacía.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::DoublyLinkedList;
+    /// let mut list = DoublyLinkedList::default();
+    /// assert!(list.is_empty());
+    ///
+    /// list.push_back(10);
+    /// assert!(!list.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Crea un iterador para recorrer los elementos de la lista doblemente enlazada.
+    ///
+    /// # Retornos
+    /// - `DoublyLinkedListIterator<T>`: Un iterador que recorre los elementos desde la cabeza hasta la cola.
+    ///
+    /// # Comportamiento
+    /// - El iterador también implementa `DoubleEndedIterator`, por lo que `rev()` recorre la lista desde la cola sin costo adicional.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::DoublyLinkedList;
+    /// let list = DoublyLinkedList::from(&[1, 2, 3]);
+    ///
+    /// let values: Vec<i32> = list.iter().collect();
+    /// assert_eq!(values, vec![1, 2, 3]);
+    ///
+    /// let reversed: Vec<i32> = list.iter().rev().collect();
+    /// assert_eq!(reversed, vec![3, 2, 1]);
+    /// ```
+    #[must_use]
+    pub const fn iter(&self) -> DoublyLinkedListIterator<'_, T> {
+        DoublyLinkedListIterator::new(self.head, self.tail, self.len)
+    }
+
+    /// Verifica si al seguir los enlaces `next` desde la cabeza se vuelve a visitar algún nodo.
+    ///
+    /// # Retornos
+    /// - `true`: Si los enlaces `next` forman un ciclo.
+    /// - `false`: Si el recorrido termina en un enlace vacío.
+    ///
+    /// # Comportamiento
+    /// - Utiliza el algoritmo de la liebre y la tortuga de Floyd: un puntero avanza de a un nodo y otro de a dos; si existe un ciclo, terminan apuntando al mismo nodo.
+    /// - Los punteros se comparan por dirección de memoria, no por el valor de los nodos.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::DoublyLinkedList;
+    /// let list = DoublyLinkedList::from(&[1, 2, 3, 1, 2, 3]);
+    ///
+    /// // Los valores repetidos no forman un ciclo: los nodos son distintos.
+    /// assert!(!list.has_cycle());
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`) y utiliza memoria constante (`O(1)`).
+    /// - La API pública nunca produce listas cíclicas; el método existe para ilustrar el algoritmo y validar la integridad de los enlaces.
+    #[must_use]
+    pub fn has_cycle(&self) -> bool {
+        self.meeting_point().is_some()
+    }
+
+    /// Devuelve el índice del nodo donde comienza el ciclo formado por los enlaces `next`, si existe.
+    ///
+    /// # Retornos
+    /// - `Some(usize)`: La posición, contando desde la cabeza, del primer nodo que pertenece al ciclo.
+    /// - `None`: Si los enlaces `next` no forman un ciclo.
+    ///
+    /// # Comportamiento
+    /// - Tras encontrar el punto de encuentro con `has_cycle`, un puntero vuelve a la cabeza y ambos avanzan de a un nodo; se encuentran justo al inicio del ciclo.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::DoublyLinkedList;
+    /// let list = DoublyLinkedList::from(&["Venus", "Tierra", "Marte"]);
+    ///
+    /// assert_eq!(list.find_cycle_start(), None);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`) y utiliza memoria constante (`O(1)`).
+    #[must_use]
+    pub fn find_cycle_start(&self) -> Option<usize> {
+        let mut slow = self.head;
+        let mut fast = Some(self.meeting_point()?);
+        let mut index: usize = 0;
+        while slow != fast {
+            slow = Self::next_of(slow);
+            fast = Self::next_of(fast);
+            index += 1;
+        }
+        Some(index)
+    }
+
+    fn meeting_point(&self) -> Option<NonNull<Node<T>>> {
+        let mut slow = self.head;
+        let mut fast = self.head;
+        while fast.is_some() {
+            fast = Self::next_of(Self::next_of(fast));
+            slow = Self::next_of(slow);
+            if fast.is_some() && fast == slow {
+                return fast;
+            }
+        }
+        None
+    }
+
+    fn next_of(node: Option<NonNull<Node<T>>>) -> Option<NonNull<Node<T>>> {
+        // SAFETY: solo se siguen enlaces `next` de nodos vivos pertenecientes a la lista.
+        node.and_then(|node| unsafe { node.as_ref() }.get_next())
+    }
+
+    fn node_at(&self, index: usize) -> Option<NonNull<Node<T>>> {
+        if index >= self.len {
+            return None;
+        }
+        if index < self.len / 2 {
+            let mut current = self.head;
+            for _ in 0..index {
+                // SAFETY: `index < len`, por lo que todos los nodos recorridos existen.
+                current = current.and_then(|node| unsafe { node.as_ref() }.get_next());
+            }
+            current
+        } else {
+            let mut current = self.tail;
+            for _ in index..(self.len - 1) {
+                // SAFETY: `index < len`, por lo que todos los nodos recorridos existen.
+                current = current.and_then(|node| unsafe { node.as_ref() }.get_prev());
+            }
+            current
+        }
+    }
+
+    /// Inserta un valor al inicio de la lista y devuelve un identificador de su nodo.
+    ///
+    /// # Notas
+    /// - El identificador permite acceder al nodo en `O(1)` mediante los métodos `*_by_handle` y `move_to_front` mientras el nodo siga en la lista.
+    pub(crate) fn push_front_handle(&mut self, value: T) -> NodeHandle<T> {
+        let node = NonNull::from(Box::leak(Box::new(Node::new(value))));
+        self.link_front(node);
+        NodeHandle(node)
+    }
+
+    /// Devuelve una referencia al valor del nodo identificado por `handle`.
+    ///
+    /// # Safety
+    /// `handle` debe provenir de `push_front_handle` sobre esta misma lista y su nodo no debe haber sido eliminado.
+    pub(crate) const unsafe fn get_by_handle(&self, handle: NodeHandle<T>) -> &T {
+        // SAFETY: el llamador garantiza que el nodo sigue vivo y pertenece a esta lista.
+        unsafe { handle.0.as_ref() }.get()
+    }
+
+    /// Devuelve una referencia mutable al valor del nodo identificado por `handle`.
+    ///
+    /// # Safety
+    /// Las mismas condiciones que `get_by_handle`.
+    pub(crate) const unsafe fn get_mut_by_handle(&mut self, mut handle: NodeHandle<T>) -> &mut T {
+        // SAFETY: el llamador garantiza que el nodo sigue vivo y pertenece a esta lista.
+        unsafe { handle.0.as_mut() }.get_mut()
+    }
+
+    /// Mueve el nodo identificado por `handle` al inicio de la lista sin reasignarlo.
+    ///
+    /// # Safety
+    /// Las mismas condiciones que `get_by_handle`.
+    pub(crate) unsafe fn move_to_front(&mut self, handle: NodeHandle<T>) {
+        if self.head != Some(handle.0) {
+            self.detach(handle.0);
+            self.link_front(handle.0);
+        }
+    }
+
+    /// Elimina el nodo identificado por `handle` y devuelve su valor.
+    ///
+    /// # Safety
+    /// Las mismas condiciones que `get_by_handle`. Después de la llamada, `handle` deja de ser válido.
+    pub(crate) unsafe fn remove_by_handle(&mut self, handle: NodeHandle<T>) -> T {
+        self.unlink(handle.0)
+    }
+
+    fn link_front(&mut self, node: NonNull<Node<T>>) {
+        // SAFETY: `node` está vivo y desenlazado, por lo que nadie más lo referencia.
+        let node_ref = unsafe { &mut *node.as_ptr() };
+        node_ref.set_prev(None);
+        node_ref.set_next(self.head);
+        match self.head {
+            // SAFETY: `head` apunta a un nodo vivo propiedad de la lista.
+            Some(mut head) => unsafe { head.as_mut() }.set_prev(Some(node)),
+            None => self.tail = Some(node),
+        }
+        self.head = Some(node);
+        self.len += 1;
+    }
+
+    fn detach(&mut self, node: NonNull<Node<T>>) {
+        // SAFETY: `node` pertenece a esta lista y sigue vivo.
+        let node = unsafe { node.as_ref() };
+        match node.get_prev() {
+            // SAFETY: el predecesor sigue vivo mientras `node` esté enlazado.
+            Some(mut prev) => unsafe { prev.as_mut() }.set_next(node.get_next()),
+            None => self.head = node.get_next(),
+        }
+        match node.get_next() {
+            // SAFETY: el sucesor sigue vivo mientras `node` esté enlazado.
+            Some(mut next) => unsafe { next.as_mut() }.set_prev(node.get_prev()),
+            None => self.tail = node.get_prev(),
+        }
+        self.len -= 1;
+    }
+
+    fn unlink(&mut self, node: NonNull<Node<T>>) -> T {
+        self.detach(node);
+        // SAFETY: `node` fue creado con `Box::leak` por esta lista y ya no está enlazado.
+        let node = unsafe { Box::from_raw(node.as_ptr()) };
+        node.into_data()
+    }
+}
+
+pub(crate) struct NodeHandle<T: Clone>(NonNull<Node<T>>);
+
+impl<T: Clone> Clone for NodeHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Clone> Copy for NodeHandle<T> {}
+
+impl<T: Copy + PartialEq> DoublyLinkedList<T> {
+    /// Inserta un nuevo elemento después del primer nodo que contiene el valor especificado.
+    ///
+    /// # Parámetros
+    /// - `pred_value`: El valor del nodo predecesor del cual se insertará el nuevo elemento.
+    /// - `value`: El valor que se desea insertar en la lista.
+    ///
+    /// # Retornos
+    /// - `Ok(())`: Si se encuentra el nodo con `pred_value` y se inserta el nuevo valor correctamente.
+    /// - `Err(Exceptions::NoSuchElement)`: Si no se encuentra ningún nodo con el valor `pred_value`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # #![allow(deprecated)]
+    /// # use linked_list::DoublyLinkedList;
+    /// let mut list = DoublyLinkedList::from(&[10, 20]);
+    ///
+    /// assert_eq!(list.insert_after(10, 15), Ok(()));
+    /// assert_eq!(list.insert_after(20, 25), Ok(()));
+    /// assert_eq!(list.get(1), Ok(&15));
+    /// assert_eq!(list.get(3), Ok(&25));
+    ///
+    /// // Intentar insertar después de un valor que no existe.
+    /// assert!(list.insert_after(4, 30).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si no se encuentra ningún nodo con el valor `pred_value`.
+    ///
+    /// # Notas
+    /// - Obsoleto: la restricción `T: Copy` impide usarlo con listas de `String`; `insert_after_by` cubre el mismo caso para cualquier `T: Clone`.
+    #[deprecated(note = "usa `insert_after_by`, que no requiere `T: Copy`")]
+    pub fn insert_after(&mut self, pred_value: T, value: T) -> Result<(), Exceptions> {
+        self.insert_after_by(|current| *current == pred_value, value)
+    }
+}
+
+impl<T: Clone, const N: usize> From<&[T; N]> for DoublyLinkedList<T> {
+    fn from(values: &[T; N]) -> Self {
+        Self::from(values.as_slice())
+    }
+}
+
+impl<T: Clone> From<&[T]> for DoublyLinkedList<T> {
+    fn from(values: &[T]) -> Self {
+        let mut list: Self = Self::default();
+        for value in values {
+            list.push_back(value.to_owned());
+        }
+        list
+    }
+}
+
+impl<T: Clone> From<Vec<T>> for DoublyLinkedList<T> {
+    fn from(values: Vec<T>) -> Self {
+        let mut list: Self = Self::default();
+        for value in values {
+            list.push_back(value);
+        }
+        list
+    }
+}
+
+impl<T: Clone> Default for DoublyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for DoublyLinkedList<T> {
+    fn clone(&self) -> Self {
+        let mut list: Self = Self::default();
+        for value in self {
+            list.push_back(value);
+        }
+        list
+    }
+}
+
+impl<T: Clone> Drop for DoublyLinkedList<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_ok() {}
+    }
+}
+
+// SAFETY: la lista es dueña exclusiva de sus nodos, igual que un `Box<Node<T>>`.
+unsafe impl<T: Clone + Send> Send for DoublyLinkedList<T> {}
+// SAFETY: los accesos a través de `&DoublyLinkedList` solo producen `&T`.
+unsafe impl<T: Clone + Sync> Sync for DoublyLinkedList<T> {}
+
+impl<'a, T: Clone> IntoIterator for &'a DoublyLinkedList<T> {
+    type Item = T;
+    type IntoIter = DoublyLinkedListIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Clone + Debug> Debug for DoublyLinkedList<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "[")?;
+        for (index, value) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{value:?}")?;
+        }
+        write!(f, "]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DoublyLinkedList;
+    use crate::SinglyLinkedList;
+    use std::ops::Deref;
+
+    /// Lista cuya cola apunta de vuelta a uno de sus nodos; el ciclo se deshace al soltarla, aunque una aserción falle antes.
+    struct CyclicList<T: Clone>(DoublyLinkedList<T>);
+
+    impl<T: Clone> CyclicList<T> {
+        fn new(values: &[T], start: usize) -> Self {
+            let list = DoublyLinkedList::from(values);
+            if let (Some(mut tail), Some(node)) = (list.tail, list.node_at(start)) {
+                // SAFETY: ambos nodos pertenecen a la lista; el ciclo se deshace en `drop`.
+                unsafe { tail.as_mut() }.set_next(Some(node));
+            }
+            Self(list)
+        }
+
+        fn break_cycle(&mut self) {
+            if let Some(mut tail) = self.0.tail {
+                // SAFETY: la cola pertenece a la lista.
+                unsafe { tail.as_mut() }.set_next(None);
+            }
+        }
+    }
+
+    impl<T: Clone> Deref for CyclicList<T> {
+        type Target = DoublyLinkedList<T>;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl<T: Clone> Drop for CyclicList<T> {
+        fn drop(&mut self) {
+            self.break_cycle();
+        }
+    }
+
+    #[test]
+    fn detects_cycle_and_its_start() {
+        for start in 0..5 {
+            let mut list = CyclicList::new(&[1, 2, 3, 4, 5], start);
+            assert!(list.has_cycle());
+            assert_eq!(list.find_cycle_start(), Some(start));
+            list.break_cycle();
+            assert!(!list.has_cycle());
+        }
+    }
+
+    #[test]
+    fn single_node_pointing_to_itself_is_a_cycle() {
+        let list = CyclicList::new(&["Venus"], 0);
+        assert!(list.has_cycle());
+        assert_eq!(list.find_cycle_start(), Some(0));
+    }
+
+    #[test]
+    fn acyclic_lists_have_no_cycle() {
+        let empty: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        assert!(!empty.has_cycle());
+        assert_eq!(empty.find_cycle_start(), None);
+        let list = DoublyLinkedList::from(&[1, 2, 3, 4]);
+        assert!(!list.has_cycle());
+        assert_eq!(list.find_cycle_start(), None);
+    }
+
+    #[test]
+    fn indexed_access_matches_singly_linked_list() {
+        let mut doubly: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        let mut singly: SinglyLinkedList<i32> = SinglyLinkedList::new();
+        assert_eq!(doubly.insert(0, 1), singly.insert(0, 1));
+        assert_eq!(doubly.remove(0), singly.remove(0));
+        assert_eq!(doubly.get(0), singly.get(0));
+        assert_eq!(doubly.set(0, 1), singly.set(0, 1));
+
+        for value in [10, 20, 30] {
+            doubly.push_back(value);
+            singly.push(value);
+        }
+        for index in 0..=4 {
+            assert_eq!(doubly.insert(index, 15), singly.insert(index, 15));
+            assert_eq!(doubly.set(index, 25), singly.set(index, 25));
+            assert_eq!(doubly.get(index), singly.get(index));
+        }
+        for index in [5, 1, 0] {
+            assert_eq!(doubly.remove(index), singly.remove(index));
+        }
+        assert_eq!(
+            doubly.iter().collect::<Vec<_>>(),
+            singly.iter().collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use std::ptr::NonNull;
+
+#[derive(Debug)]
+pub struct Node<T: Clone> {
+    data: T,
+    prev: Option<NonNull<Node<T>>>,
+    next: Option<NonNull<Node<T>>>,
+}
+
+impl<T: Clone> Node<T> {
+    pub const fn new(data: T) -> Self {
+        Self {
+            data,
+            prev: None,
+            next: None,
+        }
+    }
+
+    pub const fn get(&self) -> &T {
+        &self.data
+    }
+
+    pub const fn get_mut(&mut self) -> &mut T {
+        &mut self.data
+    }
+
+    pub const fn get_prev(&self) -> Option<NonNull<Self>> {
+        self.prev
+    }
+
+    pub const fn get_next(&self) -> Option<NonNull<Self>> {
+        self.next
+    }
+
+    pub fn into_data(self) -> T {
+        self.data
+    }
+
+    pub fn set(&mut self, data: T) {
+        self.data = data;
+    }
+
+    pub const fn set_prev(&mut self, prev: Option<NonNull<Self>>) {
+        self.prev = prev;
+    }
+
+    pub const fn set_next(&mut self, next: Option<NonNull<Self>>) {
+        self.next = next;
+    }
+}
@@ -1,7 +1,30 @@
+pub mod circular_linked_list;
+pub mod doubly_linked_list;
+pub mod lru_cache;
 pub mod singly_linked_list;
+pub mod skip_list;
+pub mod unrolled_linked_list;
+pub mod xor_linked_list;
 
+pub use circular_linked_list::CircularLinkedList;
+pub use doubly_linked_list::DoublyLinkedList;
 use exceptions::Exceptions;
+pub use lru_cache::LruCache;
 pub use singly_linked_list::SinglyLinkedList;
+pub use skip_list::SkipList;
+pub use unrolled_linked_list::UnrolledLinkedList;
+pub use xor_linked_list::XorLinkedList;
+
+/// Alias de compatibilidad para `SinglyLinkedList`, la única implementación de lista enlazada simple del crate.
+///
+/// # Ejemplo
+/// ```
+/// # use linked_list::{LinkedList, SinglyLinkedList};
+/// let list: LinkedList<i32> = SinglyLinkedList::from(&[1, 2, 3]);
+///
+/// assert_eq!(list.len(), 3);
+/// ```
+pub type LinkedList<T> = SinglyLinkedList<T>;
 
 pub fn ll_implementation() -> Result<(), Exceptions> {
     println!("Lista enlazada");
@@ -21,7 +44,7 @@ pub fn ll_implementation() -> Result<(), Exceptions> {
     list.unshift("Plutón");
     println!("  2.2 Insertar al inicio:\n    {list:?}");
     let pred_value = "Plutón";
-    list.insert_after(pred_value, "Marte")?;
+    list.insert_after_by(|planet| *planet == pred_value, "Marte")?;
     println!("  3.1 Después de un valor (predecesor: {pred_value}):\n    {list:?}");
     let position = 2;
     list.insert(position, "Jupiter")?;
@@ -41,3 +64,151 @@ pub fn ll_implementation() -> Result<(), Exceptions> {
     println!("  8. Eliminar en una posición (index: {position}, valor: {planet:?}):\n    {list:?}");
     Ok(())
 }
+
+pub fn dll_implementation() -> Result<(), Exceptions> {
+    println!("Lista doblemente enlazada");
+    let mut list: DoublyLinkedList<&str> = DoublyLinkedList::new();
+    println!(
+        "  1. Inicialización (está vacío: {0}):\n    {list:?}",
+        list.is_empty()
+    );
+    list.push_front("Saturno");
+    list.push_front("Plutón");
+    println!("  2. Insertar al inicio:\n    {list:?}");
+    list.push_back("Urano");
+    list.push_back("Neptuno");
+    println!("  3. Insertar al final:\n    {list:?}");
+    let position = 2;
+    list.insert(position, "Jupiter")?;
+    println!("  4. Insertar en una posición (index: {position}):\n    {list:?}");
+    let reversed: Vec<&str> = list.iter().rev().collect();
+    println!("  5. Recorrer desde el final:\n    {reversed:?}");
+    let planet = list.pop_front()?;
+    println!("  6. Eliminar el primer elemento (valor: {planet:?}):\n    {list:?}");
+    let planet = list.pop_back()?;
+    println!("  7. Eliminar el ultimo elemento (valor: {planet:?}):\n    {list:?}");
+    let position = 1;
+    let planet = list.remove(position)?;
+    println!("  8. Eliminar en una posición (index: {position}, valor: {planet:?}):\n    {list:?}");
+    Ok(())
+}
+
+pub fn cll_implementation() -> Result<(), Exceptions> {
+    println!("Lista enlazada circular");
+    let mut list: CircularLinkedList<&str> = CircularLinkedList::from(&["Mercurio", "Venus"]);
+    println!("  1. Inicialización:\n    {list:?}");
+    list.push("Tierra");
+    println!("  2. Insertar al final:\n    {list:?}");
+    list.unshift("Sol");
+    println!("  3. Insertar al inicio:\n    {list:?}");
+    println!("  4. Turnos round-robin:");
+    for turn in 1..=6 {
+        let planet = list.get(0)?;
+        println!("    turno {turn}: {planet:?}");
+        list.rotate();
+    }
+    let planet = list.shift()?;
+    println!("  5. Eliminar el primer elemento (valor: {planet:?}):\n    {list:?}");
+    let planet = list.pop()?;
+    println!("  6. Eliminar el ultimo elemento (valor: {planet:?}):\n    {list:?}");
+    Ok(())
+}
+
+pub fn sl_implementation() -> Result<(), Exceptions> {
+    println!("Skip list");
+    let mut list: SkipList<u32> = SkipList::new();
+    println!(
+        "  1. Inicialización (está vacío: {0}):\n    {list:?}",
+        list.is_empty()
+    );
+    for value in [42, 7, 19, 3, 88, 25, 61, 12, 70, 33, 5, 50] {
+        list.insert(value)?;
+    }
+    println!("  2. Insertar en orden (niveles: {0}):", list.height());
+    for (level, values) in list.levels().iter().enumerate().rev() {
+        println!("    nivel {level}: {values:?}");
+    }
+    let value = 25;
+    let found = list.contains(&value);
+    println!("  3. Buscar (valor: {value}, encontrado: {found})");
+    let range: Vec<&u32> = list.range(10..50).collect();
+    println!("  4. Rango (10..50):\n    {range:?}");
+    let value = list.remove(&42)?;
+    println!(
+        "  5. Eliminar (valor: {value}, niveles: {0}):",
+        list.height()
+    );
+    for (level, values) in list.levels().iter().enumerate().rev() {
+        println!("    nivel {level}: {values:?}");
+    }
+    Ok(())
+}
+
+pub fn ull_implementation() -> Result<(), Exceptions> {
+    println!("Lista enlazada desenrollada");
+    let mut list: UnrolledLinkedList<&str, 4> = UnrolledLinkedList::from(&["Mercurio", "Venus"]);
+    println!("  1. Inicialización:\n    {list:?}");
+    print_occupancy(&list);
+    for planet in ["Tierra", "Marte", "Jupiter", "Saturno"] {
+        list.push(planet);
+    }
+    println!(
+        "  2. Insertar al final (nodos: {0}):\n    {list:?}",
+        list.node_count()
+    );
+    print_occupancy(&list);
+    let position = 1;
+    list.insert(position, "Ceres")?;
+    println!("  3. Insertar en una posición (index: {position}):\n    {list:?}");
+    print_occupancy(&list);
+    let position = 0;
+    let planet = list.remove(position)?;
+    println!("  4. Eliminar en una posición (index: {position}, valor: {planet:?}):\n    {list:?}");
+    print_occupancy(&list);
+    Ok(())
+}
+
+pub fn xll_implementation() -> Result<(), Exceptions> {
+    println!("Lista enlazada XOR");
+    let mut list: XorLinkedList<&str> = XorLinkedList::from(&["Venus", "Tierra"]);
+    println!("  1. Inicialización:\n    {list:?}");
+    list.push_front("Mercurio");
+    println!("  2. Insertar al inicio:\n    {list:?}");
+    list.push_back("Marte");
+    println!("  3. Insertar al final:\n    {list:?}");
+    let planets: Vec<&str> = list.iter().rev().collect();
+    println!("  4. Recorrer desde la cola:\n    {planets:?}");
+    list.reverse();
+    println!("  5. Invertir (O(1)):\n    {list:?}");
+    let planet = list.pop_front()?;
+    println!("  6. Eliminar el primer elemento (valor: {planet:?}):\n    {list:?}");
+    let planet = list.pop_back()?;
+    println!("  7. Eliminar el ultimo elemento (valor: {planet:?}):\n    {list:?}");
+    Ok(())
+}
+
+fn print_occupancy<T: Clone + std::fmt::Debug, const N: usize>(list: &UnrolledLinkedList<T, N>) {
+    for (index, elements) in list.nodes().iter().enumerate() {
+        let free = list.node_capacity() - elements.len();
+        println!("      nodo {index}: {elements:?} (libres: {free})");
+    }
+}
+
+pub fn lru_implementation() -> Result<(), Exceptions> {
+    println!("Caché LRU (tabla hash + lista doblemente enlazada)");
+    let mut cache: LruCache<String, &str> = LruCache::new(3);
+    println!(
+        "  1. Inicialización (capacidad: {0}):\n    {cache:?}",
+        cache.capacity()
+    );
+    cache.put("Mercurio", "☿");
+    cache.put("Venus", "♀");
+    cache.put("Tierra", "♁");
+    println!("  2. Insertar (la más reciente al inicio):\n    {cache:?}");
+    let venus = *cache.get("Venus")?;
+    println!("  3. Consultar (valor: {venus}, pasa al inicio):\n    {cache:?}");
+    if let Some((key, _)) = cache.put("Marte", "♂") {
+        println!("  4. Insertar con la caché llena (expulsado: {key:?}):\n    {cache:?}");
+    }
+    Ok(())
+}
@@ -0,0 +1,237 @@
+use crate::doubly_linked_list::{DoublyLinkedList, NodeHandle};
+use exceptions::Exceptions;
+use hash_table::{Fnv1aHasher, SeparateChainingHashTable};
+use std::borrow::Borrow;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+use std::hash::{Hash, Hasher};
+
+pub struct LruCache<K: Clone + Hash + Eq, V: Clone, H: Hasher + Default = Fnv1aHasher> {
+    // Invariante: cada identificador de la tabla apunta a un nodo vivo de `order`.
+    table: SeparateChainingHashTable<K, NodeHandle<(K, V)>, H>,
+    order: DoublyLinkedList<(K, V)>,
+    capacity: usize,
+}
+
+impl<K: Clone + Hash + Eq, V: Clone, H: Hasher + Default> LruCache<K, V, H> {
+    /// Crea una nueva caché LRU vacía con la capacidad especificada.
+    ///
+    /// # Parámetros
+    /// - `capacity`: La cantidad máxima de entradas que puede contener la caché.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::LruCache;
+    /// let cache: LruCache<String, u32> = LruCache::new(2);
+    ///
+    /// assert!(cache.is_empty());
+    /// assert_eq!(cache.capacity(), 2);
+    /// ```
+    ///
+    /// # Panics
+    /// Entra en pánico si `capacity` es `0`.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "an LRU cache needs a positive capacity");
+        Self {
+            table: SeparateChainingHashTable::new(capacity),
+            order: DoublyLinkedList::new(),
+            capacity,
+        }
+    }
+
+    /// Obtiene el valor asociado a la clave y la marca como la usada más recientemente.
+    ///
+    /// # Retornos
+    /// - `Ok(&V)`: Una referencia al valor asociado a la clave.
+    /// - `Err(Exceptions::KeyNotInitialized)`: Si la caché no contiene la clave.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::LruCache;
+    /// let mut cache: LruCache<String, u32> = LruCache::new(2);
+    /// cache.put("a", 1);
+    /// cache.put("b", 2);
+    ///
+    /// // Consultar "a" la vuelve la más reciente, así que la siguiente inserción expulsa a "b".
+    /// assert_eq!(cache.get("a"), Ok(&1));
+    /// cache.put("c", 3);
+    /// assert!(!cache.contains_key("b"));
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::KeyNotInitialized` si la caché no contiene la clave.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante esperado (`O(1)`).
+    /// - `key` puede ser cualquier forma prestada de la clave (por ejemplo, `&str` para claves `String`), por lo que la consulta no reserva memoria.
+    pub fn get<Q: ?Sized + Hash + Eq>(&mut self, key: &Q) -> Result<&V, Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        let handle = *self.table.get(key)?;
+        // SAFETY: por la invariante de `table`, `handle` apunta a un nodo vivo de `order`.
+        unsafe { self.order.move_to_front(handle) };
+        // SAFETY: el nodo sigue vivo después de moverlo al inicio.
+        Ok(&unsafe { self.order.get_by_handle(handle) }.1)
+    }
+
+    /// Obtiene el valor asociado a la clave sin alterar el orden de uso.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::LruCache;
+    /// let mut cache: LruCache<String, u32> = LruCache::new(2);
+    /// cache.put("a", 1);
+    /// cache.put("b", 2);
+    ///
+    /// assert_eq!(cache.peek("a"), Ok(&1));
+    /// cache.put("c", 3);
+    /// assert!(!cache.contains_key("a"));
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::KeyNotInitialized` si la caché no contiene la clave.
+    pub fn peek<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Result<&V, Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        let handle = *self.table.get(key)?;
+        // SAFETY: por la invariante de `table`, `handle` apunta a un nodo vivo de `order`.
+        Ok(&unsafe { self.order.get_by_handle(handle) }.1)
+    }
+
+    /// Verifica si la caché contiene la clave, sin alterar el orden de uso.
+    pub fn contains_key<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.table.contains_key(key)
+    }
+
+    /// Inserta o actualiza una entrada y la marca como la usada más recientemente.
+    ///
+    /// # Parámetros
+    /// - `key`: La clave de la entrada, o cualquier valor convertible en ella.
+    /// - `value`: El valor asociado a la clave.
+    ///
+    /// # Retornos
+    /// - `Some((K, V))`: La entrada expulsada, si la caché estaba llena y la clave era nueva.
+    /// - `None`: Si no fue necesario expulsar ninguna entrada.
+    ///
+    /// # Comportamiento
+    /// - Si la clave ya existe, reemplaza su valor y la mueve al inicio del orden de uso.
+    /// - Si la clave es nueva y la caché está llena, expulsa la entrada usada hace más tiempo (el final de la lista).
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::LruCache;
+    /// let mut cache: LruCache<String, u32> = LruCache::new(2);
+    ///
+    /// assert_eq!(cache.put("a", 1), None);
+    /// assert_eq!(cache.put("b", 2), None);
+    /// assert_eq!(cache.put("a", 10), None);
+    /// assert_eq!(cache.put("c", 3), Some((String::from("b"), 2)));
+    /// assert_eq!(cache.len(), 2);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante esperado (`O(1)`).
+    pub fn put<Q: Into<K>>(&mut self, key: Q, value: V) -> Option<(K, V)> {
+        let key: K = key.into();
+        if let Ok(&handle) = self.table.get(&key) {
+            // SAFETY: por la invariante de `table`, `handle` apunta a un nodo vivo de `order`.
+            unsafe {
+                self.order.get_mut_by_handle(handle).1 = value;
+                self.order.move_to_front(handle);
+            }
+            return None;
+        }
+        let evicted = if self.order.len() == self.capacity {
+            self.order.pop_back().ok().inspect(|(evicted, _)| {
+                let _ = self.table.remove(evicted);
+            })
+        } else {
+            None
+        };
+        let handle = self.order.push_front_handle((key.clone(), value));
+        self.table.put(key, handle);
+        evicted
+    }
+
+    /// Elimina la entrada asociada a la clave y devuelve su valor.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::LruCache;
+    /// let mut cache: LruCache<String, u32> = LruCache::new(2);
+    /// cache.put("a", 1);
+    ///
+    /// assert_eq!(cache.remove("a"), Ok(1));
+    /// assert!(cache.remove("a").is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::KeyNotInitialized` si la caché no contiene la clave.
+    pub fn remove<Q: ?Sized + Hash + Eq>(&mut self, key: &Q) -> Result<V, Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        let handle = self.table.remove(key)?;
+        // SAFETY: el identificador acaba de salir de `table`, por lo que su nodo sigue vivo y no se volverá a usar.
+        let (_, value) = unsafe { self.order.remove_by_handle(handle) };
+        Ok(value)
+    }
+
+    /// Devuelve las claves desde la usada más recientemente hasta la usada hace más tiempo.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::LruCache;
+    /// let mut cache: LruCache<u32, char> = LruCache::new(3);
+    /// cache.put(1_u32, 'a');
+    /// cache.put(2_u32, 'b');
+    /// cache.get(&1).unwrap();
+    ///
+    /// assert_eq!(cache.get_keys(), vec![1, 2]);
+    /// ```
+    #[must_use]
+    pub fn get_keys(&self) -> Vec<K> {
+        self.order.iter().map(|(key, _)| key).collect()
+    }
+
+    /// Devuelve la cantidad de entradas almacenadas.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Verifica si la caché está vacía.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Devuelve la cantidad máxima de entradas que puede contener la caché.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl<K: Clone + Hash + Eq + Debug, V: Clone + Debug, H: Hasher + Default> Debug
+    for LruCache<K, V, H>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "{{")?;
+        for (index, (key, value)) in self.order.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{key:?}: {value:?}")?;
+        }
+        write!(f, "}}")
+    }
+}
@@ -1,4 +1,5 @@
 use super::node::Node;
+use super::SinglyLinkedList;
 
 pub struct SinglyLinkedListIterator<T: Clone> {
     current: Option<Box<Node<T>>>,
@@ -24,3 +25,125 @@ impl<T: Clone> Iterator for SinglyLinkedListIterator<T> {
         }
     }
 }
+
+pub struct SinglyLinkedListIterMut<'a, T: Clone> {
+    current: Option<&'a mut Node<T>>,
+}
+
+impl<'a, T: Clone> SinglyLinkedListIterMut<'a, T> {
+    pub fn new(head_node: &'a mut Option<Box<Node<T>>>) -> Self {
+        Self {
+            current: head_node.as_deref_mut(),
+        }
+    }
+}
+
+impl<'a, T: Clone> Iterator for SinglyLinkedListIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.current.take().map(|node| {
+            let (data, next) = node.get_parts_mut();
+            self.current = next.as_deref_mut();
+            data
+        })
+    }
+}
+
+pub struct SinglyLinkedListIntoIter<T: Clone> {
+    list: SinglyLinkedList<T>,
+}
+
+impl<T: Clone> SinglyLinkedListIntoIter<T> {
+    pub const fn new(list: SinglyLinkedList<T>) -> Self {
+        Self { list }
+    }
+}
+
+impl<T: Clone> Iterator for SinglyLinkedListIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.shift().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for SinglyLinkedListIntoIter<T> {}
+
+pub struct SinglyLinkedListDrain<'a, T: Clone> {
+    list: &'a mut SinglyLinkedList<T>,
+}
+
+impl<'a, T: Clone> SinglyLinkedListDrain<'a, T> {
+    pub fn new(list: &'a mut SinglyLinkedList<T>) -> Self {
+        Self { list }
+    }
+}
+
+impl<T: Clone> Iterator for SinglyLinkedListDrain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.shift().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for SinglyLinkedListDrain<'_, T> {}
+
+impl<T: Clone> Drop for SinglyLinkedListDrain<'_, T> {
+    fn drop(&mut self) {
+        while self.list.shift().is_ok() {}
+    }
+}
+
+pub struct SinglyLinkedListDrainFilter<'a, T: Clone, F: FnMut(&T) -> bool> {
+    tail: Option<&'a mut Option<Box<Node<T>>>>,
+    rest: Option<Box<Node<T>>>,
+    len: &'a mut usize,
+    predicate: F,
+}
+
+impl<'a, T: Clone, F: FnMut(&T) -> bool> SinglyLinkedListDrainFilter<'a, T, F> {
+    pub fn new(head: &'a mut Option<Box<Node<T>>>, len: &'a mut usize, predicate: F) -> Self {
+        Self {
+            rest: head.take(),
+            tail: Some(head),
+            len,
+            predicate,
+        }
+    }
+}
+
+impl<T: Clone, F: FnMut(&T) -> bool> Iterator for SinglyLinkedListDrainFilter<'_, T, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(mut node) = self.rest.take() {
+            self.rest = node.get_next_mut().take();
+            if (self.predicate)(node.get()) {
+                *self.len -= 1;
+                return Some(node.into_data());
+            }
+            if let Some(tail) = self.tail.take() {
+                self.tail = Some(tail.insert(node).get_next_mut());
+            }
+        }
+        None
+    }
+}
+
+impl<T: Clone, F: FnMut(&T) -> bool> Drop for SinglyLinkedListDrainFilter<'_, T, F> {
+    fn drop(&mut self) {
+        if let Some(tail) = self.tail.take() {
+            *tail = self.rest.take();
+        }
+    }
+}
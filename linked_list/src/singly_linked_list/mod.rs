@@ -2,9 +2,16 @@ mod iterator;
 mod node;
 
 use exceptions::Exceptions;
-use iterator::SinglyLinkedListIterator;
+use hash_table::SeparateChainingHashTable;
+use iterator::{
+    SinglyLinkedListDrain, SinglyLinkedListDrainFilter, SinglyLinkedListIntoIter,
+    SinglyLinkedListIterMut, SinglyLinkedListIterator,
+};
 use node::Node;
+use std::cmp::Ordering;
 use std::fmt::{Debug, Formatter, Result as fmtResult};
+use std::hash::{Hash, Hasher};
+use std::iter::successors;
 
 #[derive(Clone)]
 pub struct SinglyLinkedList<T: Clone> {
@@ -163,6 +170,142 @@ impl<T: Clone> SinglyLinkedList<T> {
         Err(Exceptions::NoSuchElement(String::from("Element not found")))
     }
 
+    /// Obtiene una referencia inmutable al `k`-ésimo elemento contando desde el final de la lista.
+    ///
+    /// # Parámetros
+    /// - `k`: La distancia desde el último elemento; `0` corresponde al último, `1` al penúltimo, etc.
+    ///
+    /// # Retornos
+    /// - `Ok(&T)`: Si `k` es válido, devuelve una referencia inmutable al elemento correspondiente.
+    /// - `Err(Exceptions::IndexOutOfBounds)`: Si `k` es mayor o igual a la cantidad de elementos.
+    ///
+    /// # Comportamiento
+    /// - Utiliza la técnica de dos punteros: un puntero adelantado avanza `k + 1` nodos y luego ambos avanzan juntos.
+    /// - Cuando el puntero adelantado llega al final, el puntero rezagado queda exactamente en el elemento buscado.
+    /// - El recorrido es de una sola pasada y no utiliza la longitud almacenada (`len`).
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let list = SinglyLinkedList::from(&[10, 20, 30, 40]);
+    ///
+    /// assert_eq!(list.nth_from_end(0), Ok(&40));
+    /// assert_eq!(list.nth_from_end(1), Ok(&30));
+    /// assert_eq!(list.nth_from_end(3), Ok(&10));
+    /// assert!(list.nth_from_end(4).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::IndexOutOfBounds` si `k` es mayor o igual a la cantidad de elementos de la lista.
+    /// - `Exceptions::NoSuchElement` si ocurre un error inesperado al intentar encontrar el elemento.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`) y utiliza memoria constante (`O(1)`).
+    pub fn nth_from_end(&self, k: usize) -> Result<&T, Exceptions> {
+        let mut lead: Option<&Node<T>> = self.head.as_deref();
+        for _ in 0..=k {
+            match lead {
+                Some(node) => lead = node.get_next().as_deref(),
+                None => return Err(Exceptions::IndexOutOfBounds),
+            }
+        }
+        let mut trail: Option<&Node<T>> = self.head.as_deref();
+        while let Some(node) = lead {
+            lead = node.get_next().as_deref();
+            trail = trail.and_then(|current| current.get_next().as_deref());
+        }
+        trail
+            .map(Node::get)
+            .ok_or_else(|| Exceptions::NoSuchElement(String::from("Element not found")))
+    }
+
+    /// Obtiene una referencia inmutable al primer elemento de la lista, si existe.
+    ///
+    /// # Retornos
+    /// - `Some(&T)`: Una referencia al valor de la cabeza (`head`).
+    /// - `None`: Si la lista está vacía.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let mut list = SinglyLinkedList::new();
+    /// assert_eq!(list.front(), None);
+    ///
+    /// list.push(10);
+    /// list.push(20);
+    /// assert_eq!(list.front(), Some(&10));
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante (`O(1)`).
+    #[must_use]
+    pub fn front(&self) -> Option<&T> {
+        self.head.as_deref().map(Node::get)
+    }
+
+    /// Obtiene una referencia mutable al primer elemento de la lista, si existe.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let mut list = SinglyLinkedList::from(&[10, 20]);
+    ///
+    /// if let Some(value) = list.front_mut() {
+    ///     *value = 5;
+    /// }
+    /// assert_eq!(list.front(), Some(&5));
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante (`O(1)`).
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.head.as_deref_mut().map(Node::get_mut)
+    }
+
+    /// Obtiene una referencia inmutable al último elemento de la lista, si existe.
+    ///
+    /// # Retornos
+    /// - `Some(&T)`: Una referencia al valor del último nodo.
+    /// - `None`: Si la lista está vacía.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let mut list = SinglyLinkedList::new();
+    /// assert_eq!(list.back(), None);
+    ///
+    /// list.push(10);
+    /// list.push(20);
+    /// assert_eq!(list.back(), Some(&20));
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`), ya que la lista no mantiene una referencia al último nodo.
+    #[must_use]
+    pub fn back(&self) -> Option<&T> {
+        self.values().last()
+    }
+
+    /// Obtiene una referencia mutable al último elemento de la lista, si existe.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let mut list = SinglyLinkedList::from(&[10, 20]);
+    ///
+    /// if let Some(value) = list.back_mut() {
+    ///     *value = 25;
+    /// }
+    /// assert_eq!(list.back(), Some(&25));
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`), ya que la lista no mantiene una referencia al último nodo.
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.iter_mut().last()
+    }
+
     /// Establece un nuevo valor en el índice especificado de la lista enlazada simple.
     ///
     /// # Parámetros
@@ -358,6 +501,124 @@ impl<T: Clone> SinglyLinkedList<T> {
         Err(Exceptions::IndexOutOfBounds)
     }
 
+    /// Inserta un nuevo elemento después del primer nodo cuyo valor cumple el predicado.
+    ///
+    /// # Parámetros
+    /// - `pred`: La condición que identifica al nodo predecesor.
+    /// - `value`: El valor que se desea insertar en la lista.
+    ///
+    /// # Retornos
+    /// - `Ok(())`: Si algún nodo cumple `pred` y el nuevo valor se inserta correctamente.
+    /// - `Err(Exceptions::NoSuchElement)`: Si ningún nodo cumple `pred`.
+    ///
+    /// # Comportamiento
+    /// - Recorre la lista en busca del primer nodo para el que `pred` devuelve `true`.
+    /// - Crea un nuevo nodo con `value` y lo enlaza inmediatamente después del nodo encontrado.
+    /// - Incrementa la longitud de la lista (`len`) en 1.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let mut list = SinglyLinkedList::from(vec![String::from("Venus"), String::from("Marte")]);
+    ///
+    /// // Funciona con cualquier `T: Clone`, no solo con tipos `Copy`.
+    /// assert_eq!(list.insert_after_by(|planet| planet == "Venus", String::from("Tierra")), Ok(()));
+    /// assert_eq!(list.get(1), Ok(&String::from("Tierra")));
+    ///
+    /// // Intentar insertar después de un valor que no existe.
+    /// assert!(list.insert_after_by(|planet| planet.is_empty(), String::new()).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si ningún nodo cumple `pred`.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`), ya que requiere recorrer la lista para buscar el nodo especificado.
+    pub fn insert_after_by<F: Fn(&T) -> bool>(
+        &mut self,
+        pred: F,
+        value: T,
+    ) -> Result<(), Exceptions> {
+        let mut link: &mut Option<Box<Node<T>>> = &mut self.head;
+        while link.as_ref().is_some_and(|node| !pred(node.get())) {
+            if let Some(node) = link {
+                link = node.get_next_mut();
+            }
+        }
+        let Some(node) = link else {
+            return Err(Exceptions::NoSuchElement(String::from(
+                "Predecessor not found",
+            )));
+        };
+        let mut new_node = Node::new(value);
+        new_node.set_next(node.get_next_mut().take());
+        node.set_next(Some(Box::new(new_node)));
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Inserta un nuevo elemento antes del primer nodo cuyo valor cumple el predicado.
+    ///
+    /// # Parámetros
+    /// - `pred`: La condición que identifica al nodo sucesor.
+    /// - `value`: El valor que se desea insertar en la lista.
+    ///
+    /// # Retornos
+    /// - `Ok(())`: Si algún nodo cumple `pred` y el nuevo valor se inserta correctamente.
+    /// - `Err(Exceptions::NoSuchElement)`: Si ningún nodo cumple `pred`.
+    ///
+    /// # Comportamiento
+    /// - Recorre los enlaces de la lista hasta encontrar el que apunta al primer nodo para el que `pred` devuelve `true`.
+    /// - El nuevo nodo se coloca en ese enlace y apunta al nodo encontrado; si el nodo encontrado es la cabeza (`head`), el nuevo nodo pasa a ser la cabeza.
+    /// - Incrementa la longitud de la lista (`len`) en 1.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let mut list = SinglyLinkedList::from(vec![String::from("Venus"), String::from("Marte")]);
+    ///
+    /// // Funciona con cualquier `T: Clone`, no solo con tipos `Copy`.
+    /// assert_eq!(list.insert_before_by(|planet| planet == "Marte", String::from("Tierra")), Ok(()));
+    /// assert_eq!(list.get(1), Ok(&String::from("Tierra")));
+    ///
+    /// // Insertar antes de la cabeza.
+    /// assert_eq!(list.insert_before_by(|planet| planet == "Venus", String::from("Mercurio")), Ok(()));
+    /// assert_eq!(list.get(0), Ok(&String::from("Mercurio")));
+    ///
+    /// // Intentar insertar antes de un valor que no existe.
+    /// assert!(list.insert_before_by(|planet| planet.is_empty(), String::new()).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si ningún nodo cumple `pred`.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`) y realiza un único recorrido de la lista.
+    pub fn insert_before_by<F: Fn(&T) -> bool>(
+        &mut self,
+        pred: F,
+        value: T,
+    ) -> Result<(), Exceptions> {
+        let mut link: &mut Option<Box<Node<T>>> = &mut self.head;
+        while link.as_ref().is_some_and(|node| !pred(node.get())) {
+            if let Some(node) = link {
+                link = node.get_next_mut();
+            }
+        }
+        if link.is_none() {
+            return Err(Exceptions::NoSuchElement(String::from(
+                "Successor not found",
+            )));
+        }
+        let mut new_node = Node::new(value);
+        new_node.set_next(link.take());
+        *link = Some(Box::new(new_node));
+        self.len += 1;
+        Ok(())
+    }
+
     /// Elimina y devuelve el primer elemento de la lista enlazada simple.
     ///
     /// # Retornos
@@ -393,10 +654,10 @@ impl<T: Clone> SinglyLinkedList<T> {
     /// - Es útil para operar sobre listas como colas (`FIFO`), donde los elementos se eliminan del frente.
     pub fn shift(&mut self) -> Result<T, Exceptions> {
         match self.head.take() {
-            Some(node) => {
-                node.get_next().clone_into(&mut self.head);
+            Some(mut node) => {
+                self.head = node.get_next_mut().take();
                 self.len -= 1;
-                Ok(node.get().to_owned())
+                Ok(node.into_data())
             }
             None => Err(Exceptions::NoSuchElement(String::from("The list is empty"))),
         }
@@ -410,7 +671,8 @@ impl<T: Clone> SinglyLinkedList<T> {
     ///
     /// # Comportamiento
     /// - Si la lista contiene un solo nodo, este se elimina, y la lista queda vacía.
-    /// - Si la lista tiene múltiples nodos, recorre los nodos hasta el penúltimo, elimina el último nodo y ajusta el enlace del penúltimo para que apunte a `None`.
+    /// - Si la lista tiene múltiples nodos, recorre los enlaces hasta el del penúltimo nodo, desenlaza el último nodo y deja ese enlace en `None`.
+    /// - Ningún otro nodo se modifica ni se clona.
     /// - La longitud de la lista (`len`) se reduce en 1.
     ///
     /// # Ejemplo
@@ -441,31 +703,10 @@ impl<T: Clone> SinglyLinkedList<T> {
     /// - Este método tiene un costo lineal (`O(n)`), ya que requiere recorrer la lista para encontrar el penúltimo nodo si la lista tiene múltiples elementos.
     /// - Es útil para operar sobre listas como pilas (`LIFO`), donde los elementos se eliminan del final.
     pub fn pop(&mut self) -> Result<T, Exceptions> {
-        match self.head.take() {
-            Some(mut node) => {
-                if node.get_next().is_none() {
-                    self.head = None;
-                    self.len -= 1;
-                    Ok(node.get().to_owned())
-                } else {
-                    let mut list: Self = Self::default();
-                    list.push(node.get().to_owned());
-                    let mut pred = &mut node;
-                    while let Some(ref mut current) = pred.get_next_mut() {
-                        if current.get_next().is_none() {
-                            let last_node = current;
-                            self.head = list.head;
-                            self.len -= 1;
-                            return Ok(last_node.get().to_owned());
-                        }
-                        list.push(current.get().to_owned());
-                        pred = current;
-                    }
-                    Err(Exceptions::NoSuchElement(String::from("Element not found")))
-                }
-            }
-            None => Err(Exceptions::NoSuchElement(String::from("The list is empty"))),
+        if self.is_empty() {
+            return Err(Exceptions::NoSuchElement(String::from("The list is empty")));
         }
+        self.remove(self.len - 1)
     }
 
     /// Elimina y devuelve el elemento en el índice especificado de la lista enlazada simple.
@@ -479,8 +720,8 @@ impl<T: Clone> SinglyLinkedList<T> {
     /// - `Err(Exceptions::NoSuchElement)`: Si ocurre un error inesperado al intentar encontrar o eliminar el elemento.
     ///
     /// # Comportamiento
-    /// - Si `index` es `0`, se elimina el primer nodo utilizando el método `shift`.
-    /// - Para cualquier otro índice, recorre la lista hasta el nodo previo al índice, ajusta los enlaces para saltarse el nodo eliminado y lo desvincula de la lista.
+    /// - Recorre los enlaces de la lista hasta el que apunta al nodo en `index` (la cabeza si `index` es `0`).
+    /// - Ese enlace pasa a apuntar al sucesor del nodo eliminado, que se desvincula de la lista sin clonar ningún valor.
     /// - La longitud de la lista (`len`) se reduce en 1.
     ///
     /// # Ejemplo
@@ -489,10 +730,14 @@ impl<T: Clone> SinglyLinkedList<T> {
     /// # use exceptions::Exceptions;
     /// let mut list = SinglyLinkedList::from(&[10, 20, 30, 40]);
     ///
-    /// // Eliminar el elemento en el índice 1.
+    /// // Eliminar el elemento en el índice 3.
     /// let removed = list.remove(3);
-    /// assert_eq!(removed, Ok(40)); // El valor eliminado es 20.
-    /// assert_eq!(list.len(), 3); // La lista ahora tiene 2 elementos.
+    /// assert_eq!(removed, Ok(40)); // El valor eliminado es 40.
+    /// assert_eq!(list.len(), 3); // La lista ahora tiene 3 elementos.
+    ///
+    /// // Un índice fuera de rango no modifica la lista.
+    /// assert_eq!(list.remove(3), Err(Exceptions::IndexOutOfBounds));
+    /// assert_eq!(list.len(), 3);
     ///
     /// // Eliminar el elemento en el índice 1.
     /// let removed = list.remove(1);
@@ -521,40 +766,29 @@ impl<T: Clone> SinglyLinkedList<T> {
     /// - `Exceptions::NoSuchElement` si ocurre un error inesperado al intentar acceder o eliminar el nodo.
     ///
     /// # Notas
-    /// - Este método tiene un costo lineal (`O(n)`), ya que requiere recorrer la lista hasta el índice especificado.
+    /// - Este método tiene un costo lineal (`O(n)`), ya que requiere recorrer la lista hasta el índice especificado; el desenlace en sí es `O(1)`.
     /// - Es útil para listas donde las operaciones de eliminación no son frecuentes.
     pub fn remove(&mut self, index: usize) -> Result<T, Exceptions> {
-        match self.head.take() {
+        if self.is_empty() {
+            return Err(Exceptions::NoSuchElement(String::from("The list is empty")));
+        }
+        if index >= self.len {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        let mut link: &mut Option<Box<Node<T>>> = &mut self.head;
+        for _ in 0..index {
+            match link {
+                Some(node) => link = node.get_next_mut(),
+                None => return Err(Exceptions::NoSuchElement(String::from("Element not found"))),
+            }
+        }
+        match link.take() {
             Some(mut node) => {
-                if index == 0 {
-                    self.head = Some(node);
-                    self.shift()
-                } else {
-                    let mut list: Self = Self::default();
-                    list.push(node.get().to_owned());
-                    let mut pred = &mut node;
-                    let mut i: usize = 0;
-                    let mut last_node: Option<Box<Node<T>>> = None;
-                    while let Some(ref mut current) = pred.get_next_mut() {
-                        if i == index - 1 {
-                            last_node = Some(current.clone());
-                        } else {
-                            list.push(current.get().to_owned());
-                        }
-                        i += 1;
-                        pred = current;
-                    }
-                    match last_node {
-                        Some(last_node) => {
-                            self.head = list.head;
-                            self.len -= 1;
-                            Ok(last_node.get().to_owned())
-                        }
-                        None => Err(Exceptions::NoSuchElement(String::from("Element not found"))),
-                    }
-                }
+                *link = node.get_next_mut().take();
+                self.len -= 1;
+                Ok(node.into_data())
             }
-            None => Err(Exceptions::NoSuchElement(String::from("The list is empty"))),
+            None => Err(Exceptions::NoSuchElement(String::from("Element not found"))),
         }
     }
 
@@ -651,88 +885,919 @@ impl<T: Clone> SinglyLinkedList<T> {
     ///
     /// # Notas
     /// - El iterador es inmutable, por lo que no permite modificar los elementos de la lista.
-    /// - Si necesitas iterar y modificar los valores, deberás implementar o usar un iterador mutable adicional.
+    /// - Si necesitas iterar y modificar los valores, utiliza `iter_mut`.
     /// - La creación del iterador es una operación de tiempo constante (`O(1)`).
     /// - Este método está marcado como `#[must_use]`, lo que indica que su valor de retorno debe ser utilizado; de lo contrario, se generará una advertencia.
     #[must_use]
     pub fn iter(&self) -> SinglyLinkedListIterator<T> {
         SinglyLinkedListIterator::new(self.head.clone())
     }
-}
 
-impl<T: Copy + PartialEq> SinglyLinkedList<T> {
-    /// Inserta un nuevo elemento después del primer nodo que contiene el valor especificado.
-    ///
-    /// # Parámetros
-    /// - `pred_value`: El valor del nodo predecesor del cual se insertará el nuevo elemento.
-    /// - `value`: El valor que se desea insertar en la lista.
+    /// Crea un iterador mutable para recorrer y modificar los elementos de la lista enlazada simple.
     ///
     /// # Retornos
-    /// - `Ok(())`: Si se encuentra el nodo con `pred_value` y se inserta el nuevo valor correctamente.
-    /// - `Err(Exceptions::NoSuchElement)`: Si no se encuentra ningún nodo con el valor `pred_value`.
+    /// - `SinglyLinkedListIterMut<T>`: Un iterador que devuelve referencias mutables (`&mut T`) a los valores de cada nodo, desde el primero hasta el último.
     ///
     /// # Comportamiento
-    /// - Recorre la lista en busca del primer nodo que contenga el valor `pred_value`.
-    /// - Crea un nuevo nodo con `value` y ajusta los enlaces para colocarlo inmediatamente después del nodo encontrado.
-    /// - Incrementa la longitud de la lista (`len`) en 1.
+    /// - Cada paso del iterador avanza al siguiente nodo sin volver a recorrer la lista desde el inicio.
+    /// - Los valores se pueden modificar en su lugar, sin clonarlos ni reconstruir la lista.
     ///
     /// # Ejemplo
     /// ```
     /// # use linked_list::SinglyLinkedList;
-    /// # use exceptions::Exceptions;
-    /// let mut list = SinglyLinkedList::from(&[10, 20]);
+    /// let mut list = SinglyLinkedList::from(&[1, 2, 3]);
     ///
-    /// // Insertar un elemento después del nodo con valor 10.
-    /// assert_eq!(list.insert_after(10, 15), Ok(()));
-    /// assert_eq!(list.get(1), Ok(&15));
-    /// assert_eq!(list.len(), 3);
+    /// // Duplicar cada valor en una sola pasada.
+    /// for value in list.iter_mut() {
+    ///     *value *= 2;
+    /// }
+    /// assert_eq!(list.get(0), Ok(&2));
+    /// assert_eq!(list.get(1), Ok(&4));
+    /// assert_eq!(list.get(2), Ok(&6));
     ///
-    /// // Intentar insertar después de un valor que no existe.
-    /// assert!(list.insert_after(4, 30).is_err());
+    /// // También funciona con `for` sobre una referencia mutable.
+    /// for value in &mut list {
+    ///     *value += 1;
+    /// }
+    /// assert_eq!(list.get(2), Ok(&7));
     /// ```
     ///
-    /// # Errors
-    /// Este método retornará:
-    /// - `Exceptions::NoSuchElement` si no se encuentra ningún nodo con el valor `pred_value`.
+    /// # Notas
+    /// - Recorrer toda la lista con este iterador tiene un costo lineal (`O(n)`), a diferencia de llamar a `get_mut` en un bucle, que es cuadrático (`O(n²)`).
+    /// - Este método está marcado como `#[must_use]`, lo que indica que su valor de retorno debe ser utilizado; de lo contrario, se generará una advertencia.
+    #[must_use]
+    pub fn iter_mut(&mut self) -> SinglyLinkedListIterMut<'_, T> {
+        SinglyLinkedListIterMut::new(&mut self.head)
+    }
+
+    /// Busca el primer elemento de la lista que cumple con el predicado especificado.
+    ///
+    /// # Parámetros
+    /// - `predicate`: Una función que recibe una referencia a cada elemento y devuelve `true` si es el buscado.
+    ///
+    /// # Retornos
+    /// - `Some(&T)`: Una referencia al primer elemento que cumple con el predicado.
+    /// - `None`: Si ningún elemento cumple con el predicado.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let list = SinglyLinkedList::from(&[1, 4, 6, 7]);
+    ///
+    /// assert_eq!(list.find(|value| value % 2 == 0), Some(&4));
+    /// assert_eq!(list.find(|value| *value > 10), None);
+    /// ```
     ///
     /// # Notas
-    /// - Este método tiene un costo lineal (`O(n)`), ya que requiere recorrer la lista para buscar el nodo especificado.
-    /// - Es útil para listas donde es necesario insertar elementos relativos a un valor específico.
-    pub fn insert_after(&mut self, pred_value: T, value: T) -> Result<(), Exceptions> {
-        let mut pred: &mut Option<Box<Node<T>>> = &mut self.head;
-        while let Some(ref mut node) = pred {
-            if *node.get() == pred_value {
-                let mut new_node = Node::new(value);
-                new_node.set_next(node.get_next_mut().take());
-                node.set_next(Some(Box::new(new_node)));
-                self.len += 1;
-                return Ok(());
+    /// - Este método tiene un costo lineal (`O(n)`) y se detiene en la primera coincidencia.
+    pub fn find<F: FnMut(&T) -> bool>(&self, mut predicate: F) -> Option<&T> {
+        let mut pred: &Option<Box<Node<T>>> = &self.head;
+        while let Some(ref node) = pred {
+            if predicate(node.get()) {
+                return Some(node.get());
             }
-            pred = node.get_next_mut();
+            pred = node.get_next();
         }
-        Err(Exceptions::NoSuchElement(String::from(
-            "Predecessor not found",
-        )))
+        None
     }
-}
 
-impl<T: Clone, const N: usize> From<&[T; N]> for SinglyLinkedList<T> {
-    fn from(values: &[T; N]) -> Self {
-        let mut list: Self = Self::default();
-        for value in values.iter().rev() {
-            list.unshift(value.to_owned());
+    /// Busca el índice del primer elemento de la lista que cumple con el predicado especificado.
+    ///
+    /// # Parámetros
+    /// - `predicate`: Una función que recibe una referencia a cada elemento y devuelve `true` si es el buscado.
+    ///
+    /// # Retornos
+    /// - `Some(usize)`: El índice del primer elemento que cumple con el predicado.
+    /// - `None`: Si ningún elemento cumple con el predicado.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let list = SinglyLinkedList::from(&["Venus", "Tierra", "Marte"]);
+    ///
+    /// assert_eq!(list.position(|planet| planet.starts_with('T')), Some(1));
+    /// assert_eq!(list.position(|planet| planet.is_empty()), None);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`) y se detiene en la primera coincidencia.
+    pub fn position<F: FnMut(&T) -> bool>(&self, mut predicate: F) -> Option<usize> {
+        let mut pred: &Option<Box<Node<T>>> = &self.head;
+        let mut i: usize = 0;
+        while let Some(ref node) = pred {
+            if predicate(node.get()) {
+                return Some(i);
+            }
+            i += 1;
+            pred = node.get_next();
         }
-        list
+        None
     }
-}
 
-impl<T: Clone> From<&[T]> for SinglyLinkedList<T> {
-    fn from(values: &[T]) -> Self {
-        let mut list: Self = Self::default();
-        for value in values.iter().rev() {
-            list.unshift(value.to_owned());
+    /// Mueve todos los nodos de otra lista al final de esta lista, dejando la otra lista vacía.
+    ///
+    /// # Parámetros
+    /// - `other`: La lista cuyos nodos se moverán al final de `self`.
+    ///
+    /// # Comportamiento
+    /// - Recorre la lista hasta el último enlace y lo conecta con el primer nodo de `other`.
+    /// - Los nodos de `other` se reutilizan tal cual, sin clonar sus valores.
+    /// - La longitud de `self` aumenta en `other.len()` y `other` queda vacía.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let mut list = SinglyLinkedList::from(&[1, 2]);
+    /// let mut other = SinglyLinkedList::from(&[3, 4]);
+    ///
+    /// list.append(&mut other);
+    ///
+    /// assert_eq!(list.len(), 4);
+    /// assert_eq!(list.get(3), Ok(&4));
+    /// assert!(other.is_empty());
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`) en la longitud de `self`, ya que la lista no mantiene una referencia al último nodo; el tamaño de `other` no influye.
+    pub fn append(&mut self, other: &mut Self) {
+        if other.is_empty() {
+            return;
         }
-        list
+        *self.last_link() = other.head.take();
+        self.len += other.len;
+        other.len = 0;
+    }
+
+    /// Ordena la lista usando la función de comparación especificada, mediante un merge sort ascendente (`bottom-up`).
+    ///
+    /// # Parámetros
+    /// - `compare`: Una función que recibe dos elementos y devuelve su `Ordering`.
+    ///
+    /// # Comportamiento
+    /// - En cada pasada separa la lista en tramos de tamaño `1, 2, 4, ...` y combina cada par de tramos vecinos reenlazando sus nodos.
+    /// - El proceso termina cuando el tamaño del tramo alcanza la longitud de la lista.
+    /// - El ordenamiento es estable: los elementos iguales conservan su orden relativo.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let mut list = SinglyLinkedList::from(&["Marte", "Venus", "Tierra", "Jupiter"]);
+    ///
+    /// // Ordenar por longitud del nombre, de mayor a menor.
+    /// list.sort_by(|a, b| b.len().cmp(&a.len()));
+    ///
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec!["Jupiter", "Tierra", "Marte", "Venus"]);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo de `O(n log n)` comparaciones y no utiliza memoria auxiliar: no crea un `Vec` ni clona valores.
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        let mut width: usize = 1;
+        while width < self.len {
+            let mut rest: Option<Box<Node<T>>> = self.head.take();
+            let mut tail: &mut Option<Box<Node<T>>> = &mut self.head;
+            while rest.is_some() {
+                let mut left: Option<Box<Node<T>>> = rest;
+                let mut right: Option<Box<Node<T>>> = Self::split_link(&mut left, width);
+                rest = Self::split_link(&mut right, width);
+                *tail = Self::merge_links(left, right, &mut compare);
+                while let Some(node) = tail {
+                    tail = node.get_next_mut();
+                }
+            }
+            width *= 2;
+        }
+    }
+
+    fn split_link(link: &mut Option<Box<Node<T>>>, count: usize) -> Option<Box<Node<T>>> {
+        let mut current: &mut Option<Box<Node<T>>> = link;
+        for _ in 0..count {
+            match current {
+                Some(node) => current = node.get_next_mut(),
+                None => return None,
+            }
+        }
+        current.take()
+    }
+
+    /// Conserva únicamente los elementos que cumplen con el predicado especificado, eliminando el resto.
+    ///
+    /// # Parámetros
+    /// - `keep`: Una función que recibe una referencia a cada elemento y devuelve `true` si debe conservarse.
+    ///
+    /// # Comportamiento
+    /// - Recorre la lista una sola vez; cada nodo que no cumple con el predicado se desenlaza y se libera en el momento.
+    /// - El orden de los elementos conservados no cambia.
+    /// - La longitud de la lista (`len`) se reduce en la cantidad de elementos eliminados.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let mut list = SinglyLinkedList::from(&[1, 2, 3, 4, 5, 6]);
+    ///
+    /// // Conservar solo los números pares.
+    /// list.retain(|value| value % 2 == 0);
+    ///
+    /// assert_eq!(list.len(), 3);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![2, 4, 6]);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`), a diferencia de llamar a `remove` repetidamente, que es cuadrático (`O(n²)`).
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut keep: F) {
+        let mut rest: Option<Box<Node<T>>> = self.head.take();
+        let mut tail: &mut Option<Box<Node<T>>> = &mut self.head;
+        while let Some(mut node) = rest {
+            rest = node.get_next_mut().take();
+            if keep(node.get()) {
+                tail = tail.insert(node).get_next_mut();
+            } else {
+                self.len -= 1;
+            }
+        }
+    }
+
+    /// Elimina los elementos consecutivos que la función especificada considera duplicados.
+    ///
+    /// # Parámetros
+    /// - `same_bucket`: Una función que recibe el elemento candidato y el último elemento conservado, y devuelve `true` si el candidato debe eliminarse.
+    ///
+    /// # Comportamiento
+    /// - Recorre la lista una sola vez; mientras el sucesor de un nodo sea considerado duplicado, se desenlaza y se libera.
+    /// - Solo se eliminan duplicados consecutivos, igual que `Vec::dedup_by`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let mut list = SinglyLinkedList::from(&["Marte", "marte", "Venus", "MARTE"]);
+    ///
+    /// list.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    ///
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec!["Marte", "Venus", "MARTE"]);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`).
+    pub fn dedup_by<F: FnMut(&T, &T) -> bool>(&mut self, mut same_bucket: F) {
+        let mut link: &mut Option<Box<Node<T>>> = &mut self.head;
+        while let Some(node) = link {
+            while node
+                .get_next()
+                .as_ref()
+                .is_some_and(|next| same_bucket(next.get(), node.get()))
+            {
+                if let Some(mut removed) = node.get_next_mut().take() {
+                    node.set_next(removed.get_next_mut().take());
+                    self.len -= 1;
+                }
+            }
+            link = node.get_next_mut();
+        }
+    }
+
+    /// Elimina los elementos consecutivos que producen la misma clave.
+    ///
+    /// # Parámetros
+    /// - `key`: Una función que calcula la clave de cada elemento.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let mut list = SinglyLinkedList::from(&[10, 11, 20, 21, 22, 10]);
+    ///
+    /// // Eliminar los consecutivos que pertenecen a la misma decena.
+    /// list.dedup_by_key(|value| value / 10);
+    ///
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![10, 20, 10]);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`).
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Vacía la lista y devuelve un iterador que entrega sus elementos por propiedad.
+    ///
+    /// # Retornos
+    /// - `SinglyLinkedListDrain<T>`: Un iterador que desenlaza y devuelve los elementos desde el primero hasta el último.
+    ///
+    /// # Comportamiento
+    /// - Cada llamada a `next` elimina el primer nodo y devuelve su valor sin clonarlo.
+    /// - Si el iterador se descarta antes de consumirse por completo, los elementos restantes también se eliminan.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let mut list = SinglyLinkedList::from(vec![String::from("Venus"), String::from("Marte")]);
+    ///
+    /// let planets: Vec<String> = list.drain().collect();
+    ///
+    /// assert_eq!(planets, vec![String::from("Venus"), String::from("Marte")]);
+    /// assert!(list.is_empty());
+    ///
+    /// // La lista sigue siendo utilizable.
+    /// list.push(String::from("Tierra"));
+    /// assert_eq!(list.len(), 1);
+    /// ```
+    ///
+    /// # Notas
+    /// - Consumir el iterador completo tiene un costo lineal (`O(n)`).
+    pub fn drain(&mut self) -> SinglyLinkedListDrain<'_, T> {
+        SinglyLinkedListDrain::new(self)
+    }
+
+    /// Devuelve un iterador que elimina y entrega por propiedad los elementos que cumplen con el predicado.
+    ///
+    /// # Parámetros
+    /// - `predicate`: Una función que recibe una referencia a cada elemento y devuelve `true` si debe extraerse.
+    ///
+    /// # Retornos
+    /// - `SinglyLinkedListDrainFilter<T, F>`: Un iterador sobre los elementos extraídos, en el orden de la lista.
+    ///
+    /// # Comportamiento
+    /// - Los elementos que no cumplen con el predicado permanecen en la lista, en su orden original.
+    /// - Si el iterador se descarta antes de consumirse por completo, los elementos aún no visitados permanecen en la lista.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let mut list = SinglyLinkedList::from(&[1, 2, 3, 4, 5, 6]);
+    ///
+    /// let evens: Vec<i32> = list.drain_filter(|value| value % 2 == 0).collect();
+    ///
+    /// assert_eq!(evens, vec![2, 4, 6]);
+    /// assert_eq!(list.len(), 3);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![1, 3, 5]);
+    /// ```
+    ///
+    /// # Notas
+    /// - Consumir el iterador completo tiene un costo lineal (`O(n)`).
+    pub fn drain_filter<F: FnMut(&T) -> bool>(
+        &mut self,
+        predicate: F,
+    ) -> SinglyLinkedListDrainFilter<'_, T, F> {
+        let Self { head, len } = self;
+        SinglyLinkedListDrainFilter::new(head, len, predicate)
+    }
+
+    /// Copia los elementos de la lista en un nuevo `Vec`, en el mismo orden.
+    ///
+    /// # Retornos
+    /// - `Vec<T>`: Un vector con un clon de cada elemento, desde el primero hasta el último.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let list = SinglyLinkedList::from(&[1, 2, 3]);
+    ///
+    /// assert_eq!(list.to_vec(), vec![1, 2, 3]);
+    /// assert_eq!(list.len(), 3); // La lista no se modifica.
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`) y clona cada elemento; para mover los valores sin clonarlos, utiliza `Vec::from(list)`.
+    #[must_use]
+    pub fn to_vec(&self) -> Vec<T> {
+        self.values().cloned().collect()
+    }
+
+    /// Construye una nueva lista aplicando una función a cada elemento, en el mismo orden.
+    ///
+    /// # Parámetros
+    /// - `f`: La función que transforma una referencia a cada elemento en el valor de la nueva lista.
+    ///
+    /// # Retornos
+    /// - `SinglyLinkedList<U>`: Una lista nueva con la misma longitud; la lista original no se modifica.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let list = SinglyLinkedList::from(&["Venus", "Marte"]);
+    /// let lengths = list.map(|planet| planet.len());
+    ///
+    /// assert_eq!(lengths.to_vec(), vec![5, 5]);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`) y enlaza los nodos directamente, sin pasar por un `Vec` intermedio.
+    pub fn map<U: Clone, F: Fn(&T) -> U>(&self, f: F) -> SinglyLinkedList<U> {
+        SinglyLinkedList::build(self.values().map(f))
+    }
+
+    /// Construye una nueva lista con los elementos que cumplen el predicado, en el mismo orden.
+    ///
+    /// # Parámetros
+    /// - `predicate`: La condición que deben cumplir los elementos para copiarse a la nueva lista.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let list = SinglyLinkedList::from(&[1, 2, 3, 4, 5]);
+    /// let odd = list.filter(|value| value % 2 == 1);
+    ///
+    /// assert_eq!(odd.to_vec(), vec![1, 3, 5]);
+    /// assert_eq!(list.len(), 5); // La lista original no se modifica.
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`) y clona solo los elementos que se conservan. Para filtrar en el lugar, utiliza `retain`.
+    #[must_use]
+    pub fn filter<F: Fn(&T) -> bool>(&self, predicate: F) -> Self {
+        Self::build(self.values().filter(|value| predicate(value)).cloned())
+    }
+
+    /// Combina dos listas en una lista de pares, elemento a elemento, hasta la longitud de la más corta.
+    ///
+    /// # Parámetros
+    /// - `other`: La lista cuyos elementos ocupan la segunda posición de cada par.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let planets = SinglyLinkedList::from(&["Mercurio", "Venus", "Tierra"]);
+    /// let positions = SinglyLinkedList::from(&[1, 2]);
+    ///
+    /// let pairs = planets.zip(&positions);
+    ///
+    /// assert_eq!(pairs.len(), 2);
+    /// assert_eq!(pairs.to_vec(), vec![("Mercurio", 1), ("Venus", 2)]);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal en la longitud de la lista más corta y clona cada elemento combinado.
+    #[must_use]
+    pub fn zip<U: Clone>(&self, other: &SinglyLinkedList<U>) -> SinglyLinkedList<(T, U)> {
+        self.zip_with(other, |a, b| (a.clone(), b.clone()))
+    }
+
+    /// Combina dos listas elemento a elemento con una función, hasta la longitud de la más corta.
+    ///
+    /// # Parámetros
+    /// - `other`: La segunda lista.
+    /// - `f`: La función que combina un elemento de cada lista en el valor de la nueva lista.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let a = SinglyLinkedList::from(&[1, 2, 3]);
+    /// let b = SinglyLinkedList::from(&[10, 20, 30, 40]);
+    ///
+    /// let sums = a.zip_with(&b, |x, y| x + y);
+    ///
+    /// assert_eq!(sums.to_vec(), vec![11, 22, 33]);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal en la longitud de la lista más corta y enlaza los nodos directamente, sin pasar por un `Vec` intermedio.
+    pub fn zip_with<U: Clone, V: Clone, F: Fn(&T, &U) -> V>(
+        &self,
+        other: &SinglyLinkedList<U>,
+        f: F,
+    ) -> SinglyLinkedList<V> {
+        SinglyLinkedList::build(self.values().zip(other.values()).map(|(a, b)| f(a, b)))
+    }
+
+    fn build<I: IntoIterator<Item = T>>(values: I) -> Self {
+        let mut list: Self = Self::new();
+        let mut tail: &mut Option<Box<Node<T>>> = &mut list.head;
+        for value in values {
+            tail = tail.insert(Box::new(Node::new(value))).get_next_mut();
+            list.len += 1;
+        }
+        list
+    }
+
+    /// Devuelve un iterador que recorre los valores de la lista por referencia, desde la cabeza hasta el final.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let list = SinglyLinkedList::from(vec![String::from("Venus"), String::from("Marte")]);
+    ///
+    /// assert_eq!(list.values().map(String::len).sum::<usize>(), 10);
+    /// ```
+    ///
+    /// # Notas
+    /// - A diferencia de `iter`, no clona los nodos restantes en cada paso: recorrer la lista completa cuesta `O(n)`.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        successors(self.head.as_deref(), |node| node.get_next().as_deref()).map(Node::get)
+    }
+
+    fn last_link(&mut self) -> &mut Option<Box<Node<T>>> {
+        let mut link: &mut Option<Box<Node<T>>> = &mut self.head;
+        while let Some(node) = link {
+            link = node.get_next_mut();
+        }
+        link
+    }
+
+    fn merge_links<F: FnMut(&T, &T) -> Ordering>(
+        mut left: Option<Box<Node<T>>>,
+        mut right: Option<Box<Node<T>>>,
+        compare: &mut F,
+    ) -> Option<Box<Node<T>>> {
+        let mut head: Option<Box<Node<T>>> = None;
+        let mut tail: &mut Option<Box<Node<T>>> = &mut head;
+        loop {
+            let node = match (left.take(), right.take()) {
+                (Some(mut l), Some(mut r)) => {
+                    if compare(l.get(), r.get()) == Ordering::Greater {
+                        right = r.get_next_mut().take();
+                        left = Some(l);
+                        r
+                    } else {
+                        left = l.get_next_mut().take();
+                        right = Some(r);
+                        l
+                    }
+                }
+                (rest, None) | (None, rest) => {
+                    *tail = rest;
+                    return head;
+                }
+            };
+            tail = tail.insert(node).get_next_mut();
+        }
+    }
+}
+
+impl<T: Clone + PartialEq> SinglyLinkedList<T> {
+    /// Verifica si la lista contiene un elemento igual al valor especificado.
+    ///
+    /// # Parámetros
+    /// - `value`: Una referencia al valor que se desea buscar.
+    ///
+    /// # Retornos
+    /// - `true`: Si algún nodo de la lista contiene un valor igual a `value`.
+    /// - `false`: En caso contrario.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let list = SinglyLinkedList::from(&[10, 20, 30]);
+    ///
+    /// assert!(list.contains(&20));
+    /// assert!(!list.contains(&40));
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`) y no clona ningún valor.
+    /// - Este método está marcado como `#[must_use]`, lo que indica que su valor de retorno debe ser utilizado; de lo contrario, se generará una advertencia.
+    #[must_use]
+    pub fn contains(&self, value: &T) -> bool {
+        self.find(|current| current == value).is_some()
+    }
+
+    /// Elimina los elementos consecutivos repetidos. Equivalente a `dedup_by(|a, b| a == b)`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let mut list = SinglyLinkedList::from(&[1, 1, 2, 3, 3, 3, 1]);
+    ///
+    /// list.dedup();
+    ///
+    /// assert_eq!(list.len(), 4);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![1, 2, 3, 1]);
+    /// ```
+    ///
+    /// # Notas
+    /// - Solo se eliminan los duplicados consecutivos; para eliminar todos los duplicados, ordena la lista primero con `sort`.
+    pub fn dedup(&mut self) {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Cuenta cuántos elementos de la lista son iguales al valor especificado.
+    ///
+    /// # Parámetros
+    /// - `value`: Una referencia al valor que se desea contar.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let list = SinglyLinkedList::from(&[1, 2, 1, 3, 1]);
+    ///
+    /// assert_eq!(list.count_value(&1), 3);
+    /// assert_eq!(list.count_value(&4), 0);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`) y no clona ningún valor.
+    #[must_use]
+    pub fn count_value(&self, value: &T) -> usize {
+        self.values().filter(|current| *current == value).count()
+    }
+
+    /// Verifica si la lista comienza con los elementos de otra lista, en el mismo orden.
+    ///
+    /// # Parámetros
+    /// - `prefix`: La lista que se espera encontrar al inicio.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let list = SinglyLinkedList::from(&[1, 2, 3, 4]);
+    ///
+    /// assert!(list.starts_with(&SinglyLinkedList::from(&[1, 2])));
+    /// assert!(!list.starts_with(&SinglyLinkedList::from(&[2, 3])));
+    /// assert!(!list.starts_with(&SinglyLinkedList::from(&[1, 2, 3, 4, 5])));
+    ///
+    /// // La lista vacía es prefijo de cualquier lista.
+    /// assert!(list.starts_with(&SinglyLinkedList::new()));
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal en la longitud de `prefix` (`O(m)`).
+    #[must_use]
+    pub fn starts_with(&self, prefix: &Self) -> bool {
+        prefix.len <= self.len && self.values().zip(prefix.values()).all(|(a, b)| a == b)
+    }
+
+    /// Verifica si la lista termina con los elementos de otra lista, en el mismo orden.
+    ///
+    /// # Parámetros
+    /// - `suffix`: La lista que se espera encontrar al final.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let list = SinglyLinkedList::from(&[1, 2, 3, 4]);
+    ///
+    /// assert!(list.ends_with(&SinglyLinkedList::from(&[3, 4])));
+    /// assert!(!list.ends_with(&SinglyLinkedList::from(&[2, 3])));
+    ///
+    /// // La lista vacía es sufijo de cualquier lista.
+    /// assert!(list.ends_with(&SinglyLinkedList::new()));
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`), ya que debe avanzar hasta el inicio del sufijo.
+    #[must_use]
+    pub fn ends_with(&self, suffix: &Self) -> bool {
+        suffix.len <= self.len
+            && self
+                .values()
+                .skip(self.len - suffix.len)
+                .zip(suffix.values())
+                .all(|(a, b)| a == b)
+    }
+}
+
+impl<T: Clone + Hash + Eq> SinglyLinkedList<T> {
+    /// Cuenta las apariciones de cada valor distinto de la lista en una tabla hash.
+    ///
+    /// # Retornos
+    /// - `SeparateChainingHashTable<T, usize>`: Una tabla cuyas claves son los valores distintos de la lista y cuyos valores son la cantidad de apariciones.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let list = SinglyLinkedList::from(&["Venus", "Marte", "Venus"]);
+    /// let frequencies = list.frequencies();
+    ///
+    /// assert_eq!(frequencies.entries_len(), 2);
+    /// assert_eq!(frequencies.get("Venus"), Ok(&2));
+    /// assert_eq!(frequencies.get("Marte"), Ok(&1));
+    /// assert!(frequencies.get("Tierra").is_err());
+    /// ```
+    ///
+    /// # Notas
+    /// - La tabla se crea con tantos buckets como elementos tenga la lista (al menos uno).
+    #[must_use]
+    pub fn frequencies(&self) -> SeparateChainingHashTable<T, usize> {
+        let mut table = SeparateChainingHashTable::new(self.len.max(1));
+        for value in self.values() {
+            *table.get_or_insert_with(value.clone(), || 0) += 1;
+        }
+        table
+    }
+}
+
+impl<T: Clone + Ord> SinglyLinkedList<T> {
+    /// Ordena la lista de forma ascendente. Equivalente a `sort_by(T::cmp)`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let mut list = SinglyLinkedList::from(&[5, 3, 9, 1, 3, 7]);
+    ///
+    /// list.sort();
+    ///
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![1, 3, 3, 5, 7, 9]);
+    /// assert_eq!(list.len(), 6);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo de `O(n log n)` y no utiliza memoria auxiliar.
+    pub fn sort(&mut self) {
+        self.sort_by(T::cmp);
+    }
+
+    /// Combina dos listas ordenadas en una sola lista ordenada, reutilizando los nodos de ambas.
+    ///
+    /// # Parámetros
+    /// - `other`: Otra lista ordenada de forma ascendente.
+    ///
+    /// # Retornos
+    /// - Una nueva lista ordenada de forma ascendente con todos los elementos de `self` y `other`.
+    ///
+    /// # Comportamiento
+    /// - Compara los primeros nodos de cada lista y enlaza el menor al final del resultado, repitiendo hasta agotar una de ellas.
+    /// - Los nodos restantes de la otra lista se enlazan directamente al final.
+    /// - Ante elementos iguales, los de `self` quedan antes que los de `other` (la combinación es estable).
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let left = SinglyLinkedList::from(&[1, 4, 7]);
+    /// let right = SinglyLinkedList::from(&[2, 3, 8, 9]);
+    ///
+    /// let merged = left.merge_sorted(right);
+    ///
+    /// assert_eq!(merged.len(), 7);
+    /// assert_eq!(merged.iter().collect::<Vec<_>>(), vec![1, 2, 3, 4, 7, 8, 9]);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n + m)`) y no clona ningún valor: solo reasigna los enlaces entre nodos.
+    /// - Si alguna de las listas no está ordenada, el resultado tampoco lo estará.
+    #[must_use]
+    pub fn merge_sorted(mut self, mut other: Self) -> Self {
+        let head = Self::merge_links(self.head.take(), other.head.take(), &mut T::cmp);
+        Self {
+            head,
+            len: self.len + other.len,
+        }
+    }
+
+    /// Inserta un valor en su posición ordenada, suponiendo que la lista ya está ordenada de forma ascendente.
+    ///
+    /// # Parámetros
+    /// - `value`: El valor que se desea insertar.
+    ///
+    /// # Comportamiento
+    /// - Avanza mientras los elementos sean menores o iguales que `value` y enlaza el nuevo nodo antes del primer elemento mayor.
+    /// - Ante elementos iguales, el nuevo valor queda después de los existentes, por lo que las inserciones repetidas conservan el orden de llegada.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let mut list = SinglyLinkedList::new();
+    /// for value in [5, 1, 4, 2, 3] {
+    ///     list.insert_sorted(value);
+    /// }
+    ///
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+    /// assert!(list.is_sorted());
+    ///
+    /// // Usada como cola de prioridad: el mínimo siempre está al frente.
+    /// assert_eq!(list.shift(), Ok(1));
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`) y realiza un único recorrido de la lista.
+    /// - Si la lista no está ordenada, el valor se inserta antes del primer elemento mayor que encuentre.
+    pub fn insert_sorted(&mut self, value: T) {
+        let mut link: &mut Option<Box<Node<T>>> = &mut self.head;
+        while link.as_ref().is_some_and(|node| *node.get() <= value) {
+            if let Some(node) = link {
+                link = node.get_next_mut();
+            }
+        }
+        let mut new_node = Node::new(value);
+        new_node.set_next(link.take());
+        *link = Some(Box::new(new_node));
+        self.len += 1;
+    }
+
+    /// Verifica si la lista está ordenada de forma ascendente.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// assert!(SinglyLinkedList::from(&[1, 2, 2, 3]).is_sorted());
+    /// assert!(!SinglyLinkedList::from(&[2, 1]).is_sorted());
+    ///
+    /// // Una lista vacía se considera ordenada.
+    /// assert!(SinglyLinkedList::<i32>::new().is_sorted());
+    /// ```
+    #[must_use]
+    pub fn is_sorted(&self) -> bool {
+        self.values().is_sorted()
+    }
+}
+
+impl<T: Copy + PartialEq> SinglyLinkedList<T> {
+    /// Inserta un nuevo elemento después del primer nodo que contiene el valor especificado.
+    ///
+    /// # Parámetros
+    /// - `pred_value`: El valor del nodo predecesor del cual se insertará el nuevo elemento.
+    /// - `value`: El valor que se desea insertar en la lista.
+    ///
+    /// # Retornos
+    /// - `Ok(())`: Si se encuentra el nodo con `pred_value` y se inserta el nuevo valor correctamente.
+    /// - `Err(Exceptions::NoSuchElement)`: Si no se encuentra ningún nodo con el valor `pred_value`.
+    ///
+    /// # Comportamiento
+    /// - Recorre la lista en busca del primer nodo que contenga el valor `pred_value`.
+    /// - Crea un nuevo nodo con `value` y ajusta los enlaces para colocarlo inmediatamente después del nodo encontrado.
+    /// - Incrementa la longitud de la lista (`len`) en 1.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # #![allow(deprecated)]
+    /// # use linked_list::SinglyLinkedList;
+    /// # use exceptions::Exceptions;
+    /// let mut list = SinglyLinkedList::from(&[10, 20]);
+    ///
+    /// // Insertar un elemento después del nodo con valor 10.
+    /// assert_eq!(list.insert_after(10, 15), Ok(()));
+    /// assert_eq!(list.get(1), Ok(&15));
+    /// assert_eq!(list.len(), 3);
+    ///
+    /// // Intentar insertar después de un valor que no existe.
+    /// assert!(list.insert_after(4, 30).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si no se encuentra ningún nodo con el valor `pred_value`.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`), ya que requiere recorrer la lista para buscar el nodo especificado.
+    /// - Es útil para listas donde es necesario insertar elementos relativos a un valor específico.
+    /// - Obsoleto: la restricción `T: Copy` impide usarlo con listas de `String`; `insert_after_by` cubre el mismo caso para cualquier `T: Clone`.
+    #[deprecated(note = "usa `insert_after_by`, que no requiere `T: Copy`")]
+    pub fn insert_after(&mut self, pred_value: T, value: T) -> Result<(), Exceptions> {
+        self.insert_after_by(|current| *current == pred_value, value)
+    }
+
+    /// Inserta un nuevo elemento antes del primer nodo que contiene el valor especificado.
+    ///
+    /// # Parámetros
+    /// - `succ_value`: El valor del nodo sucesor antes del cual se insertará el nuevo elemento.
+    /// - `value`: El valor que se desea insertar en la lista.
+    ///
+    /// # Retornos
+    /// - `Ok(())`: Si se encuentra el nodo con `succ_value` y se inserta el nuevo valor correctamente.
+    /// - `Err(Exceptions::NoSuchElement)`: Si no se encuentra ningún nodo con el valor `succ_value`.
+    ///
+    /// # Comportamiento
+    /// - Recorre los enlaces de la lista hasta encontrar el que apunta al primer nodo con `succ_value`.
+    /// - El nuevo nodo se coloca en ese enlace y apunta al nodo encontrado; si el nodo encontrado es la cabeza (`head`), el nuevo nodo pasa a ser la cabeza.
+    /// - Incrementa la longitud de la lista (`len`) en 1.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # #![allow(deprecated)]
+    /// # use linked_list::SinglyLinkedList;
+    /// # use exceptions::Exceptions;
+    /// let mut list = SinglyLinkedList::from(&[10, 20]);
+    ///
+    /// // Insertar un elemento antes del nodo con valor 20.
+    /// assert_eq!(list.insert_before(20, 15), Ok(()));
+    /// assert_eq!(list.get(1), Ok(&15));
+    ///
+    /// // Insertar antes de la cabeza.
+    /// assert_eq!(list.insert_before(10, 5), Ok(()));
+    /// assert_eq!(list.get(0), Ok(&5));
+    /// assert_eq!(list.len(), 4);
+    ///
+    /// // Intentar insertar antes de un valor que no existe.
+    /// assert!(list.insert_before(4, 30).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si no se encuentra ningún nodo con el valor `succ_value`.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`) y realiza un único recorrido de la lista.
+    /// - Obsoleto: la restricción `T: Copy` impide usarlo con listas de `String`; `insert_before_by` cubre el mismo caso para cualquier `T: Clone`.
+    #[deprecated(note = "usa `insert_before_by`, que no requiere `T: Copy`")]
+    pub fn insert_before(&mut self, succ_value: T, value: T) -> Result<(), Exceptions> {
+        self.insert_before_by(|current| *current == succ_value, value)
+    }
+}
+
+impl<T: Clone, const N: usize> From<&[T; N]> for SinglyLinkedList<T> {
+    fn from(values: &[T; N]) -> Self {
+        let mut list: Self = Self::default();
+        for value in values.iter().rev() {
+            list.unshift(value.to_owned());
+        }
+        list
+    }
+}
+
+impl<T: Clone> From<&[T]> for SinglyLinkedList<T> {
+    fn from(values: &[T]) -> Self {
+        let mut list: Self = Self::default();
+        for value in values.iter().rev() {
+            list.unshift(value.to_owned());
+        }
+        list
     }
 }
 
@@ -746,12 +1811,79 @@ impl<T: Clone> From<Vec<T>> for SinglyLinkedList<T> {
     }
 }
 
+impl<T: Clone> From<SinglyLinkedList<T>> for Vec<T> {
+    /// Consume la lista y mueve sus elementos a un `Vec`, sin clonarlos.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let list = SinglyLinkedList::from(vec![String::from("Venus"), String::from("Marte")]);
+    ///
+    /// let planets: Vec<String> = Vec::from(list);
+    /// assert_eq!(planets, vec![String::from("Venus"), String::from("Marte")]);
+    /// ```
+    fn from(list: SinglyLinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
 impl<T: Clone> Default for SinglyLinkedList<T> {
     fn default() -> Self {
         Self::new()
     }
 }
 
+impl<T: Clone> Drop for SinglyLinkedList<T> {
+    /// Libera los nodos de la lista uno a uno, de forma iterativa.
+    ///
+    /// # Comportamiento
+    /// - Cada nodo se desenlaza de su sucesor antes de liberarse, por lo que el destructor de `Box<Node<T>>` nunca recorre la cadena de forma recursiva.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let mut list = SinglyLinkedList::new();
+    /// for value in 0..1_000_000 {
+    ///     list.unshift(value);
+    /// }
+    /// assert_eq!(list.len(), 1_000_000);
+    ///
+    /// // Liberar una lista muy larga no desborda la pila.
+    /// drop(list);
+    /// ```
+    fn drop(&mut self) {
+        let mut link: Option<Box<Node<T>>> = self.head.take();
+        while let Some(mut node) = link {
+            link = node.get_next_mut().take();
+        }
+    }
+}
+
+impl<T: Clone> IntoIterator for SinglyLinkedList<T> {
+    type Item = T;
+    type IntoIter = SinglyLinkedListIntoIter<T>;
+
+    /// Consume la lista y devuelve un iterador que entrega los valores por propiedad.
+    ///
+    /// # Comportamiento
+    /// - Cada llamada a `next` desenlaza el primer nodo, devuelve su valor sin clonarlo y libera el nodo.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let list = SinglyLinkedList::from(vec![String::from("a"), String::from("b")]);
+    ///
+    /// let mut values: Vec<String> = Vec::new();
+    /// for value in list {
+    ///     values.push(value);
+    /// }
+    /// assert_eq!(values, vec![String::from("a"), String::from("b")]);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        SinglyLinkedListIntoIter::new(self)
+    }
+}
+
 impl<T: Clone> IntoIterator for &SinglyLinkedList<T> {
     type Item = T;
     type IntoIter = SinglyLinkedListIterator<T>;
@@ -761,6 +1893,79 @@ impl<T: Clone> IntoIterator for &SinglyLinkedList<T> {
     }
 }
 
+impl<'a, T: Clone> IntoIterator for &'a mut SinglyLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = SinglyLinkedListIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: Clone + PartialEq> PartialEq for SinglyLinkedList<T> {
+    /// Compara dos listas elemento a elemento.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let list = SinglyLinkedList::from(&[1, 2, 3]);
+    ///
+    /// assert_eq!(list, SinglyLinkedList::from(vec![1, 2, 3]));
+    /// assert_ne!(list, SinglyLinkedList::from(&[1, 2]));
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.values().eq(other.values())
+    }
+}
+
+impl<T: Clone + Eq> Eq for SinglyLinkedList<T> {}
+
+impl<T: Clone + PartialOrd> PartialOrd for SinglyLinkedList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.values().partial_cmp(other.values())
+    }
+}
+
+impl<T: Clone + Ord> Ord for SinglyLinkedList<T> {
+    /// Compara dos listas en orden lexicográfico, igual que los slices.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// let short = SinglyLinkedList::from(&[1, 2]);
+    /// let long = SinglyLinkedList::from(&[1, 2, 0]);
+    /// let big = SinglyLinkedList::from(&[1, 3]);
+    ///
+    /// assert!(short < long);
+    /// assert!(long < big);
+    /// assert_eq!(short.cmp(&short.clone()), std::cmp::Ordering::Equal);
+    /// ```
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.values().cmp(other.values())
+    }
+}
+
+impl<T: Clone + Hash> Hash for SinglyLinkedList<T> {
+    /// Calcula el hash a partir de la longitud y de cada elemento, de forma consistente con `PartialEq`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SinglyLinkedList;
+    /// # use std::collections::HashMap;
+    /// let mut routes: HashMap<SinglyLinkedList<&str>, u32> = HashMap::new();
+    /// routes.insert(SinglyLinkedList::from(&["Tierra", "Marte"]), 225);
+    ///
+    /// assert_eq!(routes.get(&SinglyLinkedList::from(&["Tierra", "Marte"])), Some(&225));
+    /// assert_eq!(routes.get(&SinglyLinkedList::from(&["Marte", "Tierra"])), None);
+    /// ```
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for value in self.values() {
+            value.hash(state);
+        }
+    }
+}
+
 impl<T: Clone + Debug> Debug for SinglyLinkedList<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
         write!(f, "[")?;
@@ -26,6 +26,14 @@ impl<T: Clone> Node<T> {
         &mut self.next
     }
 
+    pub fn get_parts_mut(&mut self) -> (&mut T, &mut Option<Box<Self>>) {
+        (&mut self.data, &mut self.next)
+    }
+
+    pub fn into_data(self) -> T {
+        self.data
+    }
+
     pub fn set(&mut self, data: T) {
         self.data = data;
     }
@@ -0,0 +1,38 @@
+use super::node::Node;
+use std::ops::Bound;
+
+pub struct SkipListIterator<'a, T: Clone + Ord> {
+    nodes: &'a [Node<T>],
+    current: Option<usize>,
+    end: Bound<T>,
+}
+
+impl<'a, T: Clone + Ord> SkipListIterator<'a, T> {
+    pub const fn new(nodes: &'a [Node<T>], current: Option<usize>, end: Bound<T>) -> Self {
+        Self {
+            nodes,
+            current,
+            end,
+        }
+    }
+}
+
+impl<'a, T: Clone + Ord> Iterator for SkipListIterator<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.nodes.get(self.current?)?;
+        let value = node.get()?;
+        let in_range = match &self.end {
+            Bound::Included(end) => value <= end,
+            Bound::Excluded(end) => value < end,
+            Bound::Unbounded => true,
+        };
+        if !in_range {
+            self.current = None;
+            return None;
+        }
+        self.current = node.get_next(0);
+        Some(value)
+    }
+}
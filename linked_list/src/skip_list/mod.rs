@@ -0,0 +1,428 @@
+mod iterator;
+mod node;
+
+use exceptions::Exceptions;
+use iterator::SkipListIterator;
+use node::Node;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+use std::ops::{Bound, RangeBounds};
+
+const MAX_LEVEL: usize = 16;
+const DEFAULT_SEED: u64 = 0x2545_F491_4F6C_DD1D;
+
+#[derive(Clone)]
+pub struct SkipList<T: Clone + Ord> {
+    nodes: Vec<Node<T>>,
+    head: Vec<Option<usize>>,
+    free: Vec<usize>,
+    level: usize,
+    len: usize,
+    state: u64,
+}
+
+impl<T: Clone + Ord> SkipList<T> {
+    /// Crea una nueva skip list vacía.
+    ///
+    /// # Retornos
+    /// - Devuelve una instancia vacía de `SkipList` sin niveles en uso y con longitud inicial de `0`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SkipList;
+    /// let list: SkipList<i32> = SkipList::new();
+    ///
+    /// assert!(list.is_empty());
+    /// assert_eq!(list.height(), 0);
+    /// ```
+    ///
+    /// # Notas
+    /// - Los niveles se sortean con un generador pseudoaleatorio con semilla fija, por lo que la estructura resultante es reproducible. Para otra distribución, utiliza `with_seed`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_seed(DEFAULT_SEED)
+    }
+
+    /// Crea una nueva skip list vacía cuyo generador de niveles parte de la semilla indicada.
+    ///
+    /// # Parámetros
+    /// - `seed`: La semilla del generador pseudoaleatorio (`xorshift64`). Una semilla `0` se reemplaza por la semilla por defecto.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SkipList;
+    /// let mut a = SkipList::with_seed(7);
+    /// let mut b = SkipList::with_seed(7);
+    /// for value in 0..50 {
+    ///     a.insert(value).unwrap();
+    ///     b.insert(value).unwrap();
+    /// }
+    ///
+    /// // La misma semilla produce la misma estructura de niveles.
+    /// assert_eq!(a.levels(), b.levels());
+    /// ```
+    #[must_use]
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            nodes: Vec::new(),
+            head: vec![None; MAX_LEVEL],
+            free: Vec::new(),
+            level: 0,
+            len: 0,
+            state: if seed == 0 { DEFAULT_SEED } else { seed },
+        }
+    }
+
+    /// Inserta un valor en su posición ordenada.
+    ///
+    /// # Parámetros
+    /// - `value`: El valor que se desea insertar.
+    ///
+    /// # Retornos
+    /// - `Ok(())`: Si el valor no existía y se insertó correctamente.
+    /// - `Err(Exceptions::DuplicateKey)`: Si la lista ya contiene un valor igual.
+    ///
+    /// # Comportamiento
+    /// - Desciende desde el nivel más alto registrando, en cada nivel, el último nodo menor que `value`.
+    /// - Sortea la altura del nuevo nodo: cada nivel adicional tiene probabilidad `1/2`, hasta un máximo de `16` niveles.
+    /// - Enlaza el nuevo nodo en cada nivel de su altura, justo después del predecesor registrado.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SkipList;
+    /// # use exceptions::Exceptions;
+    /// let mut list = SkipList::new();
+    ///
+    /// assert_eq!(list.insert(30), Ok(()));
+    /// assert_eq!(list.insert(10), Ok(()));
+    /// assert_eq!(list.insert(20), Ok(()));
+    /// assert_eq!(list.insert(20), Err(Exceptions::DuplicateKey));
+    ///
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&10, &20, &30]);
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::DuplicateKey` si la lista ya contiene un valor igual a `value`.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo esperado logarítmico (`O(log n)`).
+    pub fn insert(&mut self, value: T) -> Result<(), Exceptions> {
+        let mut update = self.predecessors(&value);
+        if self.value_after(update[0], 0) == Some(&value) {
+            return Err(Exceptions::DuplicateKey);
+        }
+        let height = self.random_level();
+        if height > self.level {
+            for link in update.iter_mut().take(height).skip(self.level) {
+                *link = None;
+            }
+            self.level = height;
+        }
+        let index = self.allocate(value, height);
+        for (level, pred) in update.iter().enumerate().take(height) {
+            let next = self.next(*pred, level);
+            self.nodes[index].set_next(level, next);
+            self.set_next(*pred, level, Some(index));
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Elimina el valor especificado y lo devuelve.
+    ///
+    /// # Parámetros
+    /// - `value`: Una referencia al valor que se desea eliminar.
+    ///
+    /// # Retornos
+    /// - `Ok(T)`: El valor eliminado.
+    /// - `Err(Exceptions::NoSuchElement)`: Si la lista no contiene el valor.
+    ///
+    /// # Comportamiento
+    /// - Desenlaza el nodo en cada nivel donde aparece y reduce la cantidad de niveles en uso si los superiores quedan vacíos.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SkipList;
+    /// let mut list = SkipList::from(&[5, 1, 3]);
+    ///
+    /// assert_eq!(list.remove(&3), Ok(3));
+    /// assert!(list.remove(&3).is_err());
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &5]);
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si la lista no contiene ningún valor igual a `value`.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo esperado logarítmico (`O(log n)`).
+    /// - El espacio del nodo eliminado se reutiliza en inserciones posteriores.
+    pub fn remove(&mut self, value: &T) -> Result<T, Exceptions> {
+        let update = self.predecessors(value);
+        let target = self
+            .next(update[0], 0)
+            .filter(|&index| self.nodes[index].get() == Some(value))
+            .ok_or_else(|| Exceptions::NoSuchElement(String::from("Element not found")))?;
+        for (level, pred) in update.iter().enumerate().take(self.level) {
+            if self.next(*pred, level) == Some(target) {
+                let next = self.nodes[target].get_next(level);
+                self.set_next(*pred, level, next);
+            }
+        }
+        while self.level > 0 && self.head[self.level - 1].is_none() {
+            self.level -= 1;
+        }
+        let data = self.nodes[target]
+            .take()
+            .ok_or_else(|| Exceptions::NoSuchElement(String::from("Element not found")))?;
+        self.free.push(target);
+        self.len -= 1;
+        Ok(data)
+    }
+
+    /// Verifica si la skip list contiene el valor especificado.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SkipList;
+    /// let list = SkipList::from(&[2, 4, 6]);
+    ///
+    /// assert!(list.contains(&4));
+    /// assert!(!list.contains(&5));
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo esperado logarítmico (`O(log n)`).
+    #[must_use]
+    pub fn contains(&self, value: &T) -> bool {
+        let update = self.predecessors(value);
+        self.value_after(update[0], 0) == Some(value)
+    }
+
+    /// Devuelve la cantidad de elementos almacenados en la skip list.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Verifica si la skip list está vacía.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Devuelve la cantidad de niveles actualmente en uso.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SkipList;
+    /// let list: SkipList<u32> = (0..1000).collect::<Vec<_>>().into();
+    ///
+    /// // Con 1000 elementos se esperan alrededor de log2(1000) ≈ 10 niveles.
+    /// assert!(list.height() >= 5 && list.height() <= 16);
+    /// ```
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.level
+    }
+
+    /// Crea un iterador que recorre todos los elementos en orden ascendente.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SkipList;
+    /// let list = SkipList::from(&[3, 1, 2]);
+    ///
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// ```
+    #[must_use]
+    pub fn iter(&self) -> SkipListIterator<'_, T> {
+        self.range(..)
+    }
+
+    /// Crea un iterador que recorre en orden ascendente los elementos contenidos en el rango especificado.
+    ///
+    /// # Parámetros
+    /// - `range`: Cualquier rango de valores (`a..b`, `a..=b`, `a..`, `..b`, `..`).
+    ///
+    /// # Comportamiento
+    /// - Localiza el primer elemento del rango descendiendo por los niveles (`O(log n)` esperado) y luego avanza por el nivel `0` hasta salir del rango.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SkipList;
+    /// let list: SkipList<i32> = SkipList::from(&[1, 3, 5, 7, 9, 11]);
+    ///
+    /// assert_eq!(list.range(3..9).collect::<Vec<_>>(), vec![&3, &5, &7]);
+    /// assert_eq!(list.range(4..=9).collect::<Vec<_>>(), vec![&5, &7, &9]);
+    /// assert_eq!(list.range(10..).collect::<Vec<_>>(), vec![&11]);
+    /// assert_eq!(list.range(..2).collect::<Vec<_>>(), vec![&1]);
+    /// ```
+    ///
+    /// # Notas
+    /// - Recorrer un rango con `k` elementos tiene un costo esperado de `O(log n + k)`.
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> SkipListIterator<'_, T> {
+        let start = match range.start_bound() {
+            Bound::Included(start) => {
+                let update = self.predecessors(start);
+                self.next(update[0], 0)
+            }
+            Bound::Excluded(start) => {
+                let update = self.predecessors(start);
+                let first = self.next(update[0], 0);
+                match first {
+                    Some(index) if self.nodes[index].get() == Some(start) => {
+                        self.nodes[index].get_next(0)
+                    }
+                    _ => first,
+                }
+            }
+            Bound::Unbounded => self.head[0],
+        };
+        SkipListIterator::new(&self.nodes, start, range.end_bound().cloned())
+    }
+
+    /// Devuelve los elementos presentes en cada nivel, desde el nivel `0` (todos los elementos) hasta el más alto.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::SkipList;
+    /// let list = SkipList::from(&[4, 8, 15, 16, 23, 42]);
+    /// let levels = list.levels();
+    ///
+    /// // El nivel 0 contiene todos los elementos y cada nivel es un subconjunto del anterior.
+    /// assert_eq!(levels[0], vec![&4, &8, &15, &16, &23, &42]);
+    /// for pair in levels.windows(2) {
+    ///     assert!(pair[1].iter().all(|value| pair[0].contains(value)));
+    /// }
+    /// ```
+    ///
+    /// # Notas
+    /// - Es útil para visualizar la estructura de la skip list con fines didácticos.
+    #[must_use]
+    pub fn levels(&self) -> Vec<Vec<&T>> {
+        (0..self.level)
+            .map(|level| {
+                let mut values: Vec<&T> = Vec::new();
+                let mut current = self.head[level];
+                while let Some(index) = current {
+                    if let Some(value) = self.nodes[index].get() {
+                        values.push(value);
+                    }
+                    current = self.nodes[index].get_next(level);
+                }
+                values
+            })
+            .collect()
+    }
+
+    fn predecessors(&self, value: &T) -> [Option<usize>; MAX_LEVEL] {
+        let mut update: [Option<usize>; MAX_LEVEL] = [None; MAX_LEVEL];
+        let mut current: Option<usize> = None;
+        for level in (0..self.level).rev() {
+            while let Some(next) = self.next(current, level) {
+                if self.nodes[next]
+                    .get()
+                    .is_some_and(|next_value| next_value < value)
+                {
+                    current = Some(next);
+                } else {
+                    break;
+                }
+            }
+            update[level] = current;
+        }
+        update
+    }
+
+    fn next(&self, from: Option<usize>, level: usize) -> Option<usize> {
+        match from {
+            Some(index) => self.nodes[index].get_next(level),
+            None => self.head[level],
+        }
+    }
+
+    fn value_after(&self, from: Option<usize>, level: usize) -> Option<&T> {
+        self.next(from, level)
+            .and_then(|index| self.nodes[index].get())
+    }
+
+    fn set_next(&mut self, from: Option<usize>, level: usize, next: Option<usize>) {
+        match from {
+            Some(index) => self.nodes[index].set_next(level, next),
+            None => self.head[level] = next,
+        }
+    }
+
+    fn allocate(&mut self, value: T, height: usize) -> usize {
+        if let Some(index) = self.free.pop() {
+            self.nodes[index].reset(value, height);
+            index
+        } else {
+            self.nodes.push(Node::new(value, height));
+            self.nodes.len() - 1
+        }
+    }
+
+    fn random_level(&mut self) -> usize {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        let height = (self.state.trailing_ones() as usize) + 1;
+        height.min(MAX_LEVEL)
+    }
+}
+
+impl<T: Clone + Ord, const N: usize> From<&[T; N]> for SkipList<T> {
+    fn from(values: &[T; N]) -> Self {
+        Self::from(values.as_slice())
+    }
+}
+
+impl<T: Clone + Ord> From<&[T]> for SkipList<T> {
+    fn from(values: &[T]) -> Self {
+        let mut list: Self = Self::default();
+        for value in values {
+            let _ = list.insert(value.to_owned());
+        }
+        list
+    }
+}
+
+impl<T: Clone + Ord> From<Vec<T>> for SkipList<T> {
+    fn from(values: Vec<T>) -> Self {
+        let mut list: Self = Self::default();
+        for value in values {
+            let _ = list.insert(value);
+        }
+        list
+    }
+}
+
+impl<T: Clone + Ord> Default for SkipList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T: Clone + Ord> IntoIterator for &'a SkipList<T> {
+    type Item = &'a T;
+    type IntoIter = SkipListIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Clone + Ord + Debug> Debug for SkipList<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "[")?;
+        for (index, value) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{value:?}")?;
+        }
+        write!(f, "]")
+    }
+}
@@ -0,0 +1,42 @@
+#[derive(Debug, Clone)]
+pub struct Node<T: Clone> {
+    data: Option<T>,
+    forward: Vec<Option<usize>>,
+}
+
+impl<T: Clone> Node<T> {
+    pub fn new(data: T, height: usize) -> Self {
+        Self {
+            data: Some(data),
+            forward: vec![None; height],
+        }
+    }
+
+    pub const fn get(&self) -> Option<&T> {
+        self.data.as_ref()
+    }
+
+    pub fn get_next(&self, level: usize) -> Option<usize> {
+        self.forward.get(level).copied().flatten()
+    }
+
+    pub fn height(&self) -> usize {
+        self.forward.len()
+    }
+
+    pub fn set_next(&mut self, level: usize, next: Option<usize>) {
+        if let Some(link) = self.forward.get_mut(level) {
+            *link = next;
+        }
+    }
+
+    pub fn take(&mut self) -> Option<T> {
+        self.forward.clear();
+        self.data.take()
+    }
+
+    pub fn reset(&mut self, data: T, height: usize) {
+        self.data = Some(data);
+        self.forward = vec![None; height];
+    }
+}
@@ -0,0 +1,40 @@
+use super::node::Node;
+
+pub struct UnrolledLinkedListIterator<'a, T: Clone> {
+    current: Option<&'a Node<T>>,
+    position: usize,
+    remaining: usize,
+}
+
+impl<'a, T: Clone> UnrolledLinkedListIterator<'a, T> {
+    pub const fn new(head_node: Option<&'a Node<T>>, len: usize) -> Self {
+        Self {
+            current: head_node,
+            position: 0,
+            remaining: len,
+        }
+    }
+}
+
+impl<T: Clone> Iterator for UnrolledLinkedListIterator<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.current {
+            if let Some(value) = node.get(self.position) {
+                self.position += 1;
+                self.remaining -= 1;
+                return Some(value.to_owned());
+            }
+            self.current = node.get_next().as_deref();
+            self.position = 0;
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for UnrolledLinkedListIterator<'_, T> {}
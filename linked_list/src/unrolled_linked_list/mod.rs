@@ -0,0 +1,511 @@
+mod iterator;
+mod node;
+
+use exceptions::Exceptions;
+use iterator::UnrolledLinkedListIterator;
+use node::Node;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+
+#[derive(Clone)]
+pub struct UnrolledLinkedList<T: Clone, const N: usize = 4> {
+    head: Option<Box<Node<T>>>,
+    len: usize,
+}
+
+impl<T: Clone, const N: usize> UnrolledLinkedList<T, N> {
+    /// Crea una nueva lista desenrollada vacía, con capacidad para `N` elementos por nodo.
+    ///
+    /// # Retornos
+    /// - Devuelve una instancia vacía de `UnrolledLinkedList` sin nodos y con longitud inicial de `0`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::UnrolledLinkedList;
+    /// let list: UnrolledLinkedList<i32> = UnrolledLinkedList::new();
+    /// assert!(list.is_empty());
+    ///
+    /// // La capacidad por nodo se elige con el segundo parámetro genérico (por defecto `4`).
+    /// let list: UnrolledLinkedList<i32, 8> = UnrolledLinkedList::new();
+    /// assert_eq!(list.node_capacity(), 8);
+    /// ```
+    ///
+    /// # Notas
+    /// - `N` debe ser al menos `2`, para que un nodo lleno pueda dividirse en dos mitades no vacías; de lo contrario, la compilación falla.
+    #[must_use]
+    pub const fn new() -> Self {
+        const {
+            assert!(
+                N >= 2,
+                "an unrolled linked list needs at least two elements per node"
+            )
+        };
+        Self { head: None, len: 0 }
+    }
+
+    /// Crea una nueva lista desenrollada que contiene un único elemento.
+    ///
+    /// # Parámetros
+    /// - `data`: El valor inicial que se almacenará en el primer nodo.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::UnrolledLinkedList;
+    /// let list: UnrolledLinkedList<i32> = UnrolledLinkedList::with_data(42);
+    ///
+    /// assert_eq!(list.len(), 1);
+    /// assert_eq!(list.get(0), Ok(&42));
+    /// ```
+    #[must_use]
+    pub fn with_data(data: T) -> Self {
+        let mut list = Self::new();
+        list.push(data);
+        list
+    }
+
+    /// Obtiene una referencia inmutable al elemento en el índice especificado.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del elemento que se desea obtener. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Retornos
+    /// - `Ok(&T)`: Si el índice es válido, devuelve una referencia inmutable al elemento en ese índice.
+    /// - `Err(Exceptions::IndexOutOfBounds)`: Si el índice está fuera del rango válido de la lista.
+    ///
+    /// # Comportamiento
+    /// - Recorre los nodos descontando la cantidad de elementos de cada uno hasta encontrar el nodo que contiene el índice.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::UnrolledLinkedList;
+    /// let list: UnrolledLinkedList<i32, 2> = UnrolledLinkedList::from(&[10, 20, 30]);
+    ///
+    /// assert_eq!(list.get(2), Ok(&30));
+    /// assert!(list.get(3).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::IndexOutOfBounds` si `index` es mayor o igual a la longitud de la lista (`len`).
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal en la cantidad de nodos (`O(n / N)`), no en la cantidad de elementos.
+    pub fn get(&self, index: usize) -> Result<&T, Exceptions> {
+        let mut index = index;
+        let mut link = self.head.as_deref();
+        while let Some(node) = link {
+            if index < node.len() {
+                return node.get(index).ok_or(Exceptions::IndexOutOfBounds);
+            }
+            index -= node.len();
+            link = node.get_next().as_deref();
+        }
+        Err(Exceptions::IndexOutOfBounds)
+    }
+
+    /// Obtiene una referencia mutable al elemento en el índice especificado.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del elemento que se desea obtener. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::UnrolledLinkedList;
+    /// let mut list: UnrolledLinkedList<i32> = UnrolledLinkedList::from(&[10, 20]);
+    ///
+    /// if let Ok(value) = list.get_mut(1) {
+    ///     *value = 25;
+    /// }
+    /// assert_eq!(list.get(1), Ok(&25));
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::IndexOutOfBounds` si `index` es mayor o igual a la longitud de la lista (`len`).
+    pub fn get_mut(&mut self, index: usize) -> Result<&mut T, Exceptions> {
+        let mut index = index;
+        let mut link = self.head.as_deref_mut();
+        while let Some(node) = link {
+            if index < node.len() {
+                return node.get_mut(index).ok_or(Exceptions::IndexOutOfBounds);
+            }
+            index -= node.len();
+            link = node.get_next_mut().as_deref_mut();
+        }
+        Err(Exceptions::IndexOutOfBounds)
+    }
+
+    /// Establece el valor de un elemento en el índice especificado.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del elemento que se desea modificar. Debe estar en el rango `0..self.len`.
+    /// - `value`: El nuevo valor que se asignará al elemento.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::UnrolledLinkedList;
+    /// let mut list: UnrolledLinkedList<i32> = UnrolledLinkedList::from(&[1, 2, 3]);
+    ///
+    /// assert_eq!(list.set(1, 20), Ok(()));
+    /// assert_eq!(list.get(1), Ok(&20));
+    /// assert!(list.set(3, 40).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::IndexOutOfBounds` si `index` es mayor o igual a la longitud de la lista (`len`).
+    pub fn set(&mut self, index: usize, value: T) -> Result<(), Exceptions> {
+        *self.get_mut(index)? = value;
+        Ok(())
+    }
+
+    /// Inserta un nuevo elemento al inicio de la lista.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::UnrolledLinkedList;
+    /// let mut list: UnrolledLinkedList<i32> = UnrolledLinkedList::with_data(2);
+    /// list.unshift(1);
+    ///
+    /// assert_eq!(list.get(0), Ok(&1));
+    /// ```
+    ///
+    /// # Notas
+    /// - Si el primer nodo está lleno, se divide en dos antes de insertar.
+    pub fn unshift(&mut self, value: T) {
+        self.insert_unchecked(0, value);
+    }
+
+    /// Inserta un nuevo elemento al final de la lista.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::UnrolledLinkedList;
+    /// let mut list: UnrolledLinkedList<i32> = UnrolledLinkedList::new();
+    /// list.push(1);
+    /// list.push(2);
+    ///
+    /// assert_eq!(list.get(1), Ok(&2));
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método recorre los nodos hasta el último (`O(n / N)`).
+    pub fn push(&mut self, value: T) {
+        self.insert_unchecked(self.len, value);
+    }
+
+    /// Inserta un nuevo elemento en el índice especificado.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice donde se desea insertar el nuevo elemento. Debe estar en el rango `0..=self.len`.
+    /// - `value`: El valor que se desea insertar.
+    ///
+    /// # Retornos
+    /// - `Ok(())`: Si el índice es válido, el valor se inserta correctamente.
+    /// - `Err(Exceptions::IndexOutOfBounds)`: Si el índice está fuera del rango permitido.
+    ///
+    /// # Comportamiento
+    /// - Localiza el nodo que cubre el índice y desplaza los elementos dentro de su arreglo.
+    /// - Si el nodo está lleno, se divide: la segunda mitad de sus elementos pasa a un nodo nuevo enlazado a continuación, y el valor se inserta en la mitad que corresponda.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::UnrolledLinkedList;
+    /// let mut list: UnrolledLinkedList<i32, 2> = UnrolledLinkedList::from(&[1, 3]);
+    ///
+    /// // El único nodo está lleno, por lo que se divide en dos.
+    /// assert_eq!(list.insert(1, 2), Ok(()));
+    /// assert_eq!(list.nodes(), vec![&[1, 2][..], &[3][..]]);
+    ///
+    /// assert!(list.insert(5, 4).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::IndexOutOfBounds` si `index` es mayor que `self.len`.
+    pub fn insert(&mut self, index: usize, value: T) -> Result<(), Exceptions> {
+        if index > self.len {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        self.insert_unchecked(index, value);
+        Ok(())
+    }
+
+    /// Elimina y devuelve el primer elemento de la lista.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::UnrolledLinkedList;
+    /// let mut list: UnrolledLinkedList<i32> = UnrolledLinkedList::with_data(42);
+    ///
+    /// assert_eq!(list.shift(), Ok(42));
+    /// assert!(list.shift().is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si la lista está vacía.
+    pub fn shift(&mut self) -> Result<T, Exceptions> {
+        self.remove(0)
+    }
+
+    /// Elimina y devuelve el último elemento de la lista.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::UnrolledLinkedList;
+    /// let mut list: UnrolledLinkedList<i32> = UnrolledLinkedList::from(&[1, 2]);
+    ///
+    /// assert_eq!(list.pop(), Ok(2));
+    /// assert_eq!(list.pop(), Ok(1));
+    /// assert!(list.pop().is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si la lista está vacía.
+    pub fn pop(&mut self) -> Result<T, Exceptions> {
+        if self.len == 0 {
+            return Err(Exceptions::NoSuchElement(String::from("The list is empty")));
+        }
+        self.remove(self.len - 1)
+    }
+
+    /// Elimina y devuelve el elemento en el índice especificado.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del elemento que se desea eliminar. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Retornos
+    /// - `Ok(T)`: El valor eliminado.
+    /// - `Err(Exceptions::NoSuchElement)`: Si la lista está vacía.
+    /// - `Err(Exceptions::IndexOutOfBounds)`: Si el índice está fuera del rango válido.
+    ///
+    /// # Comportamiento
+    /// - Si el nodo queda con menos de `N / 2` elementos, toma el primer elemento del nodo siguiente o, si ambos caben en uno solo, los fusiona.
+    /// - Los nodos que quedan vacíos se desenlazan.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::UnrolledLinkedList;
+    /// let mut list: UnrolledLinkedList<i32, 4> = UnrolledLinkedList::from(&[1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(list.nodes(), vec![&[1, 2][..], &[3, 4, 5, 6][..]]);
+    ///
+    /// // El primer nodo queda con un solo elemento y absorbe uno del siguiente.
+    /// assert_eq!(list.remove(0), Ok(1));
+    /// assert_eq!(list.nodes(), vec![&[2, 3][..], &[4, 5, 6][..]]);
+    ///
+    /// assert!(list.remove(5).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si la lista está vacía.
+    /// - `Exceptions::IndexOutOfBounds` si `index` es mayor o igual a la longitud de la lista (`len`).
+    pub fn remove(&mut self, index: usize) -> Result<T, Exceptions> {
+        if self.len == 0 {
+            return Err(Exceptions::NoSuchElement(String::from("The list is empty")));
+        }
+        if index >= self.len {
+            return Err(Exceptions::IndexOutOfBounds);
+        }
+        let mut index = index;
+        let mut link: &mut Option<Box<Node<T>>> = &mut self.head;
+        while link.as_ref().is_some_and(|node| index >= node.len()) {
+            if let Some(node) = link {
+                index -= node.len();
+                link = node.get_next_mut();
+            }
+        }
+        let Some(node) = link else {
+            return Err(Exceptions::IndexOutOfBounds);
+        };
+        let value = node.get_elements_mut().remove(index);
+        Self::rebalance(node);
+        if node.len() == 0 {
+            let next = node.get_next_mut().take();
+            *link = next;
+        }
+        self.len -= 1;
+        Ok(value)
+    }
+
+    /// Devuelve la cantidad de elementos almacenados en la lista.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Verifica si la lista está vacía.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Devuelve la cantidad máxima de elementos que puede almacenar cada nodo (`N`).
+    #[must_use]
+    pub const fn node_capacity(&self) -> usize {
+        N
+    }
+
+    /// Devuelve la cantidad de nodos enlazados en la lista.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::UnrolledLinkedList;
+    /// let list: UnrolledLinkedList<i32, 4> = (0..10).collect::<Vec<_>>().into();
+    ///
+    /// assert_eq!(list.len(), 10);
+    /// assert_eq!(list.node_count(), 4);
+    /// ```
+    #[must_use]
+    pub fn node_count(&self) -> usize {
+        self.node_refs().count()
+    }
+
+    /// Devuelve una vista de la ocupación de cada nodo: un slice con los elementos que almacena, en orden.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::UnrolledLinkedList;
+    /// let list: UnrolledLinkedList<i32, 4> = UnrolledLinkedList::from(&[1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(list.nodes(), vec![&[1, 2][..], &[3, 4, 5][..]]);
+    /// ```
+    ///
+    /// # Notas
+    /// - Está pensado como herramienta didáctica para observar cómo se dividen y fusionan los nodos.
+    #[must_use]
+    pub fn nodes(&self) -> Vec<&[T]> {
+        self.node_refs().map(Node::get_elements).collect()
+    }
+
+    /// Devuelve un iterador que recorre los elementos de la lista en orden.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::UnrolledLinkedList;
+    /// let list: UnrolledLinkedList<i32, 2> = UnrolledLinkedList::from(&[1, 2, 3]);
+    ///
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// ```
+    #[must_use]
+    pub fn iter(&self) -> UnrolledLinkedListIterator<'_, T> {
+        UnrolledLinkedListIterator::new(self.head.as_deref(), self.len)
+    }
+
+    fn node_refs(&self) -> impl Iterator<Item = &Node<T>> {
+        std::iter::successors(self.head.as_deref(), |node| node.get_next().as_deref())
+    }
+
+    fn insert_unchecked(&mut self, index: usize, value: T) {
+        let mut index = index;
+        let mut link = self.head.as_deref_mut();
+        while let Some(node) = link {
+            if index <= node.len() {
+                if node.len() == N {
+                    let mut new_node = Node::new(N);
+                    let (elements, next) = node.get_parts_mut();
+                    new_node.get_elements_mut().extend(elements.drain(N / 2..));
+                    new_node.set_next(next.take());
+                    if index > N / 2 {
+                        new_node.get_elements_mut().insert(index - N / 2, value);
+                    } else {
+                        elements.insert(index, value);
+                    }
+                    node.set_next(Some(Box::new(new_node)));
+                } else {
+                    node.get_elements_mut().insert(index, value);
+                }
+                self.len += 1;
+                return;
+            }
+            index -= node.len();
+            link = node.get_next_mut().as_deref_mut();
+        }
+        let mut node = Node::new(N);
+        node.get_elements_mut().push(value);
+        self.head = Some(Box::new(node));
+        self.len += 1;
+    }
+
+    fn rebalance(node: &mut Node<T>) {
+        if node.len() >= N / 2 {
+            return;
+        }
+        let (elements, next) = node.get_parts_mut();
+        let Some(next_node) = next else {
+            return;
+        };
+        if elements.len() + next_node.len() <= N {
+            let (next_elements, next_next) = next_node.get_parts_mut();
+            elements.append(next_elements);
+            let rest = next_next.take();
+            *next = rest;
+        } else {
+            elements.push(next_node.get_elements_mut().remove(0));
+        }
+    }
+}
+
+impl<T: Clone, const N: usize, const M: usize> From<&[T; M]> for UnrolledLinkedList<T, N> {
+    fn from(slice: &[T; M]) -> Self {
+        Self::from(&slice[..])
+    }
+}
+
+impl<T: Clone, const N: usize> From<&[T]> for UnrolledLinkedList<T, N> {
+    fn from(slice: &[T]) -> Self {
+        let mut list = Self::new();
+        for value in slice {
+            list.push(value.clone());
+        }
+        list
+    }
+}
+
+impl<T: Clone, const N: usize> From<Vec<T>> for UnrolledLinkedList<T, N> {
+    fn from(vec: Vec<T>) -> Self {
+        let mut list = Self::new();
+        for value in vec {
+            list.push(value);
+        }
+        list
+    }
+}
+
+impl<T: Clone, const N: usize> Default for UnrolledLinkedList<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const N: usize> Drop for UnrolledLinkedList<T, N> {
+    fn drop(&mut self) {
+        let mut link: Option<Box<Node<T>>> = self.head.take();
+        while let Some(mut node) = link {
+            link = node.get_next_mut().take();
+        }
+    }
+}
+
+impl<'a, T: Clone, const N: usize> IntoIterator for &'a UnrolledLinkedList<T, N> {
+    type Item = T;
+    type IntoIter = UnrolledLinkedListIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Clone + Debug, const N: usize> Debug for UnrolledLinkedList<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "[")?;
+        for (index, value) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{value:?}")?;
+        }
+        write!(f, "]")
+    }
+}
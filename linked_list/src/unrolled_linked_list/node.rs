@@ -0,0 +1,51 @@
+#[derive(Debug, Clone)]
+pub struct Node<T: Clone> {
+    elements: Vec<T>,
+    next: Option<Box<Node<T>>>,
+}
+
+impl<T: Clone> Node<T> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            elements: Vec::with_capacity(capacity),
+            next: None,
+        }
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.elements.get(index)
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.elements.get_mut(index)
+    }
+
+    pub fn get_elements(&self) -> &[T] {
+        &self.elements
+    }
+
+    pub fn get_elements_mut(&mut self) -> &mut Vec<T> {
+        &mut self.elements
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    #[allow(clippy::ref_option)]
+    pub const fn get_next(&self) -> &Option<Box<Self>> {
+        &self.next
+    }
+
+    pub fn get_next_mut(&mut self) -> &mut Option<Box<Self>> {
+        &mut self.next
+    }
+
+    pub fn get_parts_mut(&mut self) -> (&mut Vec<T>, &mut Option<Box<Self>>) {
+        (&mut self.elements, &mut self.next)
+    }
+
+    pub fn set_next(&mut self, next: Option<Box<Self>>) {
+        self.next = next;
+    }
+}
@@ -0,0 +1,67 @@
+use super::node::Node;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+pub struct XorLinkedListIterator<'a, T: Clone> {
+    front: Option<NonNull<Node<T>>>,
+    before_front: Option<NonNull<Node<T>>>,
+    back: Option<NonNull<Node<T>>>,
+    after_back: Option<NonNull<Node<T>>>,
+    remaining: usize,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+impl<T: Clone> XorLinkedListIterator<'_, T> {
+    pub const fn new(
+        head_node: Option<NonNull<Node<T>>>,
+        tail_node: Option<NonNull<Node<T>>>,
+        len: usize,
+    ) -> Self {
+        Self {
+            front: head_node,
+            before_front: None,
+            back: tail_node,
+            after_back: None,
+            remaining: len,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone> Iterator for XorLinkedListIterator<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let current = self.front?;
+        // SAFETY: el iterador toma prestada la lista, por lo que sus nodos siguen vivos.
+        let node = unsafe { current.as_ref() };
+        self.front = node.neighbor(self.before_front);
+        self.before_front = Some(current);
+        self.remaining -= 1;
+        Some(node.get().to_owned())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for XorLinkedListIterator<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let current = self.back?;
+        // SAFETY: el iterador toma prestada la lista, por lo que sus nodos siguen vivos.
+        let node = unsafe { current.as_ref() };
+        self.back = node.neighbor(self.after_back);
+        self.after_back = Some(current);
+        self.remaining -= 1;
+        Some(node.get().to_owned())
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for XorLinkedListIterator<'_, T> {}
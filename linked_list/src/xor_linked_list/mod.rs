@@ -0,0 +1,432 @@
+mod iterator;
+mod node;
+
+use exceptions::Exceptions;
+use iterator::XorLinkedListIterator;
+use node::Node;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+use std::marker::PhantomData;
+use std::mem;
+use std::ptr::NonNull;
+
+pub struct XorLinkedList<T: Clone> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<Box<Node<T>>>,
+}
+
+impl<T: Clone> XorLinkedList<T> {
+    /// Crea una nueva lista XOR vacía.
+    ///
+    /// # Retornos
+    /// - Devuelve una instancia vacía de `XorLinkedList` sin nodos y con longitud inicial de `0`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::XorLinkedList;
+    /// let list: XorLinkedList<i32> = XorLinkedList::new();
+    ///
+    /// assert!(list.is_empty());
+    /// ```
+    ///
+    /// # Notas
+    /// - Cada nodo guarda un único enlace: la dirección del anterior combinada con la del siguiente mediante XOR (`prev ⊕ next`). Conociendo uno de los vecinos, el otro se recupera con un XOR más, por lo que la lista se recorre en ambos sentidos con la memoria de una lista simple.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            head: None,
+            tail: None,
+            len: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Crea una nueva lista XOR con un único nodo inicializado con el valor proporcionado.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::XorLinkedList;
+    /// let list = XorLinkedList::with_data(42);
+    ///
+    /// assert_eq!(list.len(), 1);
+    /// assert_eq!(list.get(0), Ok(&42));
+    /// ```
+    #[must_use]
+    pub fn with_data(data: T) -> Self {
+        let mut list = Self::new();
+        list.push_back(data);
+        list
+    }
+
+    /// Obtiene una referencia inmutable al elemento en el índice especificado.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del elemento que se desea obtener. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Retornos
+    /// - `Ok(&T)`: Si el índice es válido, devuelve una referencia inmutable al elemento en ese índice.
+    /// - `Err(Exceptions::IndexOutOfBounds)`: Si el índice está fuera del rango válido de la lista.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::XorLinkedList;
+    /// let list = XorLinkedList::from(&[10, 20, 30]);
+    ///
+    /// assert_eq!(list.get(1), Ok(&20));
+    /// assert!(list.get(3).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::IndexOutOfBounds` si `index` es mayor o igual a la longitud de la lista (`len`).
+    ///
+    /// # Notas
+    /// - Este método tiene un costo lineal (`O(n)`), aunque solo recorre desde el extremo más cercano al índice.
+    pub fn get(&self, index: usize) -> Result<&T, Exceptions> {
+        let node = self.node_at(index).ok_or(Exceptions::IndexOutOfBounds)?;
+        // SAFETY: `node_at` solo devuelve nodos vivos de la lista, prestados junto con `self`.
+        Ok(unsafe { node.as_ref() }.get())
+    }
+
+    /// Obtiene una referencia mutable al elemento en el índice especificado.
+    ///
+    /// # Parámetros
+    /// - `index`: El índice del elemento que se desea obtener. Debe estar en el rango `0..self.len`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::XorLinkedList;
+    /// let mut list = XorLinkedList::from(&[10, 20]);
+    ///
+    /// if let Ok(value) = list.get_mut(1) {
+    ///     *value = 25;
+    /// }
+    /// assert_eq!(list.get(1), Ok(&25));
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::IndexOutOfBounds` si `index` es mayor o igual a la longitud de la lista (`len`).
+    pub fn get_mut(&mut self, index: usize) -> Result<&mut T, Exceptions> {
+        let mut node = self.node_at(index).ok_or(Exceptions::IndexOutOfBounds)?;
+        // SAFETY: `node_at` solo devuelve nodos vivos; `&mut self` garantiza acceso exclusivo.
+        Ok(unsafe { node.as_mut() }.get_mut())
+    }
+
+    /// Inserta un nuevo elemento al inicio de la lista.
+    ///
+    /// # Comportamiento
+    /// - El enlace del nuevo nodo es la dirección de la antigua cabeza, ya que no tiene anterior.
+    /// - En la antigua cabeza, el vecino anterior (vacío) se sustituye por el nuevo nodo.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::XorLinkedList;
+    /// let mut list = XorLinkedList::with_data(2);
+    /// list.push_front(1);
+    ///
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![1, 2]);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante (`O(1)`).
+    pub fn push_front(&mut self, value: T) {
+        let node = Self::link_at_end(self.head, value);
+        if self.head.is_none() {
+            self.tail = Some(node);
+        }
+        self.head = Some(node);
+        self.len += 1;
+    }
+
+    /// Inserta un nuevo elemento al final de la lista.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::XorLinkedList;
+    /// let mut list = XorLinkedList::with_data(1);
+    /// list.push_back(2);
+    ///
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![1, 2]);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante (`O(1)`).
+    pub fn push_back(&mut self, value: T) {
+        let node = Self::link_at_end(self.tail, value);
+        if self.tail.is_none() {
+            self.head = Some(node);
+        }
+        self.tail = Some(node);
+        self.len += 1;
+    }
+
+    /// Elimina y devuelve el primer elemento de la lista.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::XorLinkedList;
+    /// let mut list = XorLinkedList::from(&[1, 2]);
+    ///
+    /// assert_eq!(list.pop_front(), Ok(1));
+    /// assert_eq!(list.pop_front(), Ok(2));
+    /// assert!(list.pop_front().is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si la lista está vacía.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante (`O(1)`).
+    pub fn pop_front(&mut self) -> Result<T, Exceptions> {
+        let head = self
+            .head
+            .ok_or_else(|| Exceptions::NoSuchElement(String::from("The list is empty")))?;
+        let (value, next) = Self::unlink_end(head);
+        self.head = next;
+        if next.is_none() {
+            self.tail = None;
+        }
+        self.len -= 1;
+        Ok(value)
+    }
+
+    /// Elimina y devuelve el último elemento de la lista.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::XorLinkedList;
+    /// let mut list = XorLinkedList::from(&[1, 2]);
+    ///
+    /// assert_eq!(list.pop_back(), Ok(2));
+    /// assert_eq!(list.pop_back(), Ok(1));
+    /// assert!(list.pop_back().is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si la lista está vacía.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante (`O(1)`).
+    pub fn pop_back(&mut self) -> Result<T, Exceptions> {
+        let tail = self
+            .tail
+            .ok_or_else(|| Exceptions::NoSuchElement(String::from("The list is empty")))?;
+        let (value, prev) = Self::unlink_end(tail);
+        self.tail = prev;
+        if prev.is_none() {
+            self.head = None;
+        }
+        self.len -= 1;
+        Ok(value)
+    }
+
+    /// Invierte el orden de la lista.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::XorLinkedList;
+    /// let mut list = XorLinkedList::from(&[1, 2, 3]);
+    /// list.reverse();
+    ///
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![3, 2, 1]);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante (`O(1)`): como `prev ⊕ next` es simétrico, basta con intercambiar la cabeza y la cola, sin tocar ningún nodo.
+    pub const fn reverse(&mut self) {
+        mem::swap(&mut self.head, &mut self.tail);
+    }
+
+    /// Devuelve la cantidad de elementos almacenados en la lista.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Verifica si la lista está vacía.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Devuelve un iterador que recorre la lista desde la cabeza, y que también puede recorrerse desde la cola con `rev`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use linked_list::XorLinkedList;
+    /// let list = XorLinkedList::from(&[1, 2, 3]);
+    ///
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// assert_eq!(list.iter().rev().collect::<Vec<_>>(), vec![3, 2, 1]);
+    /// ```
+    #[must_use]
+    pub const fn iter(&self) -> XorLinkedListIterator<'_, T> {
+        XorLinkedListIterator::new(self.head, self.tail, self.len)
+    }
+
+    fn node_at(&self, index: usize) -> Option<NonNull<Node<T>>> {
+        if index >= self.len {
+            return None;
+        }
+        let (mut current, steps) = if index < self.len / 2 {
+            (self.head, index)
+        } else {
+            (self.tail, self.len - 1 - index)
+        };
+        let mut previous: Option<NonNull<Node<T>>> = None;
+        for _ in 0..steps {
+            let node = current?;
+            // SAFETY: `index < len`, por lo que todos los nodos recorridos existen.
+            current = unsafe { node.as_ref() }.neighbor(previous);
+            previous = Some(node);
+        }
+        current
+    }
+
+    fn link_at_end(end: Option<NonNull<Node<T>>>, value: T) -> NonNull<Node<T>> {
+        let mut node = Box::new(Node::new(value));
+        node.replace_neighbor(None, end);
+        let node = NonNull::from(Box::leak(node));
+        if let Some(mut end) = end {
+            // SAFETY: `end` es un extremo vivo de la lista, cuyo vecino exterior es vacío.
+            unsafe { end.as_mut() }.replace_neighbor(None, Some(node));
+        }
+        node
+    }
+
+    fn unlink_end(end: NonNull<Node<T>>) -> (T, Option<NonNull<Node<T>>>) {
+        // SAFETY: `end` es un extremo de la lista creado con `Box::leak` y se desenlaza aquí, una sola vez.
+        let node = unsafe { Box::from_raw(end.as_ptr()) };
+        let inner = node.neighbor(None);
+        if let Some(mut inner) = inner {
+            // SAFETY: el vecino interior sigue vivo y deja de apuntar al nodo liberado.
+            unsafe { inner.as_mut() }.replace_neighbor(Some(end), None);
+        }
+        (node.into_data(), inner)
+    }
+}
+
+impl<T: Clone, const N: usize> From<&[T; N]> for XorLinkedList<T> {
+    fn from(values: &[T; N]) -> Self {
+        Self::from(values.as_slice())
+    }
+}
+
+impl<T: Clone> From<&[T]> for XorLinkedList<T> {
+    fn from(values: &[T]) -> Self {
+        let mut list: Self = Self::default();
+        for value in values {
+            list.push_back(value.to_owned());
+        }
+        list
+    }
+}
+
+impl<T: Clone> From<Vec<T>> for XorLinkedList<T> {
+    fn from(values: Vec<T>) -> Self {
+        let mut list: Self = Self::default();
+        for value in values {
+            list.push_back(value);
+        }
+        list
+    }
+}
+
+impl<T: Clone> Default for XorLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for XorLinkedList<T> {
+    fn clone(&self) -> Self {
+        let mut list: Self = Self::default();
+        for value in self {
+            list.push_back(value);
+        }
+        list
+    }
+}
+
+impl<T: Clone> Drop for XorLinkedList<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_ok() {}
+    }
+}
+
+// SAFETY: la lista es dueña exclusiva de sus nodos, igual que un `Box<Node<T>>`.
+unsafe impl<T: Clone + Send> Send for XorLinkedList<T> {}
+// SAFETY: los accesos a través de `&XorLinkedList` solo producen `&T`.
+unsafe impl<T: Clone + Sync> Sync for XorLinkedList<T> {}
+
+impl<'a, T: Clone> IntoIterator for &'a XorLinkedList<T> {
+    type Item = T;
+    type IntoIter = XorLinkedListIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Clone + Debug> Debug for XorLinkedList<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "[")?;
+        for (index, value) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{value:?}")?;
+        }
+        write!(f, "]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::XorLinkedList;
+
+    #[test]
+    fn mixed_operations_keep_links_consistent() {
+        let mut list: XorLinkedList<String> = XorLinkedList::new();
+        for value in 0..6 {
+            if value % 2 == 0 {
+                list.push_back(value.to_string());
+            } else {
+                list.push_front(value.to_string());
+            }
+        }
+        assert_eq!(
+            list.iter().collect::<Vec<_>>(),
+            ["5", "3", "1", "0", "2", "4"]
+        );
+        list.reverse();
+        assert_eq!(list.pop_front(), Ok(String::from("4")));
+        assert_eq!(list.pop_back(), Ok(String::from("5")));
+        list.push_back(String::from("6"));
+        assert_eq!(list.iter().collect::<Vec<_>>(), ["2", "0", "1", "3", "6"]);
+        assert_eq!(
+            list.iter().rev().collect::<Vec<_>>(),
+            ["6", "3", "1", "0", "2"]
+        );
+        for index in 0..list.len() {
+            assert_eq!(list.get(index), Ok(&list.iter().nth(index).unwrap()));
+        }
+    }
+
+    #[test]
+    fn emptying_from_both_ends_resets_head_and_tail() {
+        let mut list = XorLinkedList::from(&[1, 2, 3]);
+        assert_eq!(list.pop_back(), Ok(3));
+        assert_eq!(list.pop_front(), Ok(1));
+        assert_eq!(list.pop_back(), Ok(2));
+        assert!(list.is_empty());
+        assert!(list.pop_front().is_err());
+        list.push_front(7);
+        assert_eq!(list.iter().collect::<Vec<_>>(), [7]);
+        assert_eq!(list.clone().pop_back(), Ok(7));
+    }
+}
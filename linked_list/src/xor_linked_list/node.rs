@@ -0,0 +1,41 @@
+use std::ptr::{self, NonNull};
+
+#[derive(Debug)]
+pub struct Node<T: Clone> {
+    data: T,
+    link: usize,
+}
+
+impl<T: Clone> Node<T> {
+    pub const fn new(data: T) -> Self {
+        Self { data, link: 0 }
+    }
+
+    pub const fn get(&self) -> &T {
+        &self.data
+    }
+
+    pub const fn get_mut(&mut self) -> &mut T {
+        &mut self.data
+    }
+
+    pub fn into_data(self) -> T {
+        self.data
+    }
+
+    pub fn neighbor(&self, other: Option<NonNull<Self>>) -> Option<NonNull<Self>> {
+        Self::from_addr(self.link ^ Self::addr(other))
+    }
+
+    pub fn replace_neighbor(&mut self, old: Option<NonNull<Self>>, new: Option<NonNull<Self>>) {
+        self.link ^= Self::addr(old) ^ Self::addr(new);
+    }
+
+    fn addr(node: Option<NonNull<Self>>) -> usize {
+        node.map_or(0, |node| node.as_ptr().expose_provenance())
+    }
+
+    fn from_addr(addr: usize) -> Option<NonNull<Self>> {
+        NonNull::new(ptr::with_exposed_provenance_mut(addr))
+    }
+}
@@ -0,0 +1,132 @@
+use array::DynamicArray;
+use exceptions::Exceptions;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+
+pub struct ArrayStack<T: Clone> {
+    // El tope de la pila es el último elemento del arreglo.
+    array: DynamicArray<T>,
+}
+
+impl<T: Clone> ArrayStack<T> {
+    /// Crea una nueva pila vacía respaldada por un arreglo dinámico.
+    ///
+    /// # Parámetros
+    /// - `capacity`: La capacidad inicial del arreglo subyacente. La pila crece automáticamente al superarla.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use stack::ArrayStack;
+    /// let stack: ArrayStack<i32> = ArrayStack::new(4);
+    ///
+    /// assert!(stack.is_empty());
+    /// assert_eq!(stack.capacity(), 4);
+    /// ```
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            array: DynamicArray::new(capacity),
+        }
+    }
+
+    /// Apila un valor, que pasa a ser el nuevo tope de la pila.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use stack::ArrayStack;
+    /// let mut stack: ArrayStack<i32> = ArrayStack::new(1);
+    /// stack.push(1);
+    /// stack.push(2);
+    ///
+    /// assert_eq!(stack.len(), 2);
+    /// assert_eq!(stack.peek(), Ok(&2));
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante amortizado (`O(1)`), ya que solo redimensiona el arreglo cuando está lleno.
+    pub fn push(&mut self, value: T) {
+        self.array.push(value);
+    }
+
+    /// Desapila y devuelve el valor del tope de la pila.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use stack::ArrayStack;
+    /// let mut stack: ArrayStack<i32> = ArrayStack::new(2);
+    /// stack.push(1);
+    /// stack.push(2);
+    ///
+    /// assert_eq!(stack.pop(), Ok(2));
+    /// assert_eq!(stack.pop(), Ok(1));
+    /// assert!(stack.pop().is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si la pila está vacía.
+    pub fn pop(&mut self) -> Result<T, Exceptions> {
+        if self.is_empty() {
+            return Err(Exceptions::NoSuchElement(String::from(
+                "The stack is empty",
+            )));
+        }
+        self.array.pop()
+    }
+
+    /// Obtiene una referencia al valor del tope de la pila sin desapilarlo.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use stack::ArrayStack;
+    /// let mut stack: ArrayStack<&str> = ArrayStack::new(2);
+    /// assert!(stack.peek().is_err());
+    ///
+    /// stack.push("a");
+    /// stack.push("b");
+    /// assert_eq!(stack.peek(), Ok(&"b"));
+    /// assert_eq!(stack.len(), 2);
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si la pila está vacía.
+    pub fn peek(&self) -> Result<&T, Exceptions> {
+        match self.array.len() {
+            0 => Err(Exceptions::NoSuchElement(String::from(
+                "The stack is empty",
+            ))),
+            len => self.array.get(len - 1),
+        }
+    }
+
+    /// Devuelve la cantidad de elementos apilados.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.array.len()
+    }
+
+    /// Devuelve la capacidad actual del arreglo subyacente.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.array.capacity()
+    }
+
+    /// Verifica si la pila está vacía.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.array.is_empty()
+    }
+}
+
+impl<T: Clone> Default for ArrayStack<T> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<T: Clone + Debug> Debug for ArrayStack<T> {
+    /// Muestra los elementos desde la base hasta el tope de la pila.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "{:?}", self.array)
+    }
+}
@@ -0,0 +1,77 @@
+pub mod array_stack;
+pub mod linked_stack;
+pub mod two_stack_queue;
+
+pub use array_stack::ArrayStack;
+use exceptions::Exceptions;
+pub use linked_stack::LinkedStack;
+pub use two_stack_queue::TwoStackQueue;
+
+pub fn st_implementation() -> Result<(), Exceptions> {
+    println!("Pila (arreglo dinámico y lista enlazada)");
+    let mut array_stack: ArrayStack<&str> = ArrayStack::new(2);
+    let mut linked_stack: LinkedStack<&str> = LinkedStack::new();
+    println!("  1. Creación en blanco:\n    {array_stack:?}\n    {linked_stack:?}");
+    for planet in ["Mercurio", "Venus", "Tierra", "Marte"] {
+        array_stack.push(planet);
+        linked_stack.push(planet);
+    }
+    let capacity = array_stack.capacity();
+    println!("  2. Apilar (capacidad del arreglo: {capacity}):\n    {array_stack:?}\n    {linked_stack:?}");
+    let (array_top, linked_top) = (array_stack.peek()?, linked_stack.peek()?);
+    println!("  3. Consultar el tope: {array_top:?} / {linked_top:?}");
+    let (array_popped, linked_popped) = (array_stack.pop()?, linked_stack.pop()?);
+    println!("  4. Desapilar ({array_popped:?} / {linked_popped:?}):\n    {array_stack:?}\n    {linked_stack:?}");
+    let mut balanced = true;
+    let mut open: LinkedStack<char> = LinkedStack::new();
+    let expression = "{[(a + b) * c] - (d / e)}";
+    for symbol in expression.chars() {
+        match symbol {
+            '(' | '[' | '{' => open.push(symbol),
+            ')' | ']' | '}' => {
+                let expected = match symbol {
+                    ')' => '(',
+                    ']' => '[',
+                    _ => '{',
+                };
+                if open.pop().ok() != Some(expected) {
+                    balanced = false;
+                }
+            }
+            _ => {}
+        }
+    }
+    let balanced = balanced && open.is_empty();
+    println!("  5. Paréntesis balanceados en {expression:?}: {balanced}");
+    Ok(())
+}
+
+pub fn tsq_implementation() -> Result<(), Exceptions> {
+    println!("Cola con dos pilas");
+    let mut queue: TwoStackQueue<u32> = TwoStackQueue::new();
+    for value in 1..=4 {
+        queue.enqueue(value);
+    }
+    println!("  1. Encolar 1..=4:\n    {queue:?}");
+    let front = queue.dequeue()?;
+    let transfers = queue.transfers();
+    println!("  2. Desencolar ({front}, traslados acumulados: {transfers}):\n    {queue:?}");
+    queue.enqueue(5);
+    queue.enqueue(6);
+    let front = queue.dequeue()?;
+    let transfers = queue.transfers();
+    println!("  3. Encolar 5 y 6, desencolar ({front}, traslados acumulados: {transfers}):\n    {queue:?}");
+    let (enqueued, mut dequeued) = (6, 2);
+    while !queue.is_empty() {
+        queue.dequeue()?;
+        dequeued += 1;
+    }
+    let transfers = queue.transfers();
+    // Cada elemento se apila, se traslada y se desapila una sola vez: a lo sumo 3 movimientos por elemento.
+    let (operations, moves) = (enqueued + dequeued, enqueued + transfers + dequeued);
+    println!(
+        "  4. Análisis amortizado: {operations} operaciones, {transfers} traslados, {moves} movimientos de pila ({:.2} por operación)",
+        moves as f64 / operations as f64
+    );
+    Ok(())
+}
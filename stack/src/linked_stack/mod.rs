@@ -0,0 +1,122 @@
+use exceptions::Exceptions;
+use linked_list::SinglyLinkedList;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+
+pub struct LinkedStack<T: Clone> {
+    // El tope de la pila es la cabeza de la lista, donde insertar y eliminar cuesta `O(1)`.
+    list: SinglyLinkedList<T>,
+}
+
+impl<T: Clone> LinkedStack<T> {
+    /// Crea una nueva pila vacía respaldada por una lista enlazada simple.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use stack::LinkedStack;
+    /// let stack: LinkedStack<i32> = LinkedStack::new();
+    ///
+    /// assert!(stack.is_empty());
+    /// assert_eq!(stack.len(), 0);
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            list: SinglyLinkedList::new(),
+        }
+    }
+
+    /// Apila un valor, que pasa a ser el nuevo tope de la pila.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use stack::LinkedStack;
+    /// let mut stack: LinkedStack<i32> = LinkedStack::new();
+    /// stack.push(1);
+    /// stack.push(2);
+    ///
+    /// assert_eq!(stack.len(), 2);
+    /// assert_eq!(stack.peek(), Ok(&2));
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante (`O(1)`), ya que solo crea un nodo al inicio de la lista.
+    pub fn push(&mut self, value: T) {
+        self.list.unshift(value);
+    }
+
+    /// Desapila y devuelve el valor del tope de la pila.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use stack::LinkedStack;
+    /// let mut stack: LinkedStack<i32> = LinkedStack::new();
+    /// stack.push(1);
+    /// stack.push(2);
+    ///
+    /// assert_eq!(stack.pop(), Ok(2));
+    /// assert_eq!(stack.pop(), Ok(1));
+    /// assert!(stack.pop().is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si la pila está vacía.
+    pub fn pop(&mut self) -> Result<T, Exceptions> {
+        if self.is_empty() {
+            return Err(Exceptions::NoSuchElement(String::from(
+                "The stack is empty",
+            )));
+        }
+        self.list.shift()
+    }
+
+    /// Obtiene una referencia al valor del tope de la pila sin desapilarlo.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use stack::LinkedStack;
+    /// let mut stack: LinkedStack<&str> = LinkedStack::new();
+    /// assert!(stack.peek().is_err());
+    ///
+    /// stack.push("a");
+    /// stack.push("b");
+    /// assert_eq!(stack.peek(), Ok(&"b"));
+    /// assert_eq!(stack.len(), 2);
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si la pila está vacía.
+    pub fn peek(&self) -> Result<&T, Exceptions> {
+        self.list
+            .front()
+            .ok_or_else(|| Exceptions::NoSuchElement(String::from("The stack is empty")))
+    }
+
+    /// Devuelve la cantidad de elementos apilados.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Verifica si la pila está vacía.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+}
+
+impl<T: Clone> Default for LinkedStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone + Debug> Debug for LinkedStack<T> {
+    /// Muestra los elementos desde la base hasta el tope de la pila, igual que `ArrayStack`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        let mut values: Vec<T> = self.list.iter().collect();
+        values.reverse();
+        write!(f, "{values:?}")
+    }
+}
@@ -0,0 +1,164 @@
+use crate::array_stack::ArrayStack;
+use exceptions::Exceptions;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+
+pub struct TwoStackQueue<T: Clone> {
+    // Los elementos entran por `inbox` y salen por `outbox`, que guarda el frente de la cola en su tope.
+    inbox: ArrayStack<T>,
+    outbox: ArrayStack<T>,
+    transfers: usize,
+}
+
+impl<T: Clone> TwoStackQueue<T> {
+    /// Crea una nueva cola vacía formada por dos pilas.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use stack::TwoStackQueue;
+    /// let queue: TwoStackQueue<i32> = TwoStackQueue::new();
+    ///
+    /// assert!(queue.is_empty());
+    /// assert_eq!(queue.transfers(), 0);
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            inbox: ArrayStack::default(),
+            outbox: ArrayStack::default(),
+            transfers: 0,
+        }
+    }
+
+    /// Agrega un valor al final de la cola.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use stack::TwoStackQueue;
+    /// let mut queue: TwoStackQueue<i32> = TwoStackQueue::new();
+    /// queue.enqueue(1);
+    /// queue.enqueue(2);
+    ///
+    /// assert_eq!(queue.len(), 2);
+    /// ```
+    ///
+    /// # Notas
+    /// - Este método tiene un costo constante amortizado (`O(1)`): solo apila el valor en la pila de entrada.
+    pub fn enqueue(&mut self, value: T) {
+        self.inbox.push(value);
+    }
+
+    /// Extrae y devuelve el valor del frente de la cola.
+    ///
+    /// # Comportamiento
+    /// - Si la pila de salida está vacía, se vuelca en ella la pila de entrada completa, lo que invierte su orden
+    ///   y deja el elemento más antiguo en el tope.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use stack::TwoStackQueue;
+    /// let mut queue: TwoStackQueue<i32> = TwoStackQueue::new();
+    /// queue.enqueue(1);
+    /// queue.enqueue(2);
+    ///
+    /// assert_eq!(queue.dequeue(), Ok(1));
+    /// queue.enqueue(3);
+    /// assert_eq!(queue.dequeue(), Ok(2));
+    /// assert_eq!(queue.dequeue(), Ok(3));
+    /// assert!(queue.dequeue().is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si la cola está vacía.
+    ///
+    /// # Notas
+    /// - Un volcado cuesta `O(n)`, pero cada elemento se traslada una sola vez en toda su vida, así que el costo
+    ///   amortizado por operación es constante (`O(1)`).
+    pub fn dequeue(&mut self) -> Result<T, Exceptions> {
+        self.refill();
+        self.outbox
+            .pop()
+            .map_err(|_| Exceptions::NoSuchElement(String::from("The queue is empty")))
+    }
+
+    /// Obtiene una referencia al valor del frente de la cola sin extraerlo.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use stack::TwoStackQueue;
+    /// let mut queue: TwoStackQueue<&str> = TwoStackQueue::new();
+    /// assert!(queue.peek().is_err());
+    ///
+    /// queue.enqueue("a");
+    /// queue.enqueue("b");
+    /// assert_eq!(queue.peek(), Ok(&"a"));
+    /// assert_eq!(queue.len(), 2);
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::NoSuchElement` si la cola está vacía.
+    ///
+    /// # Notas
+    /// - Requiere `&mut self` porque, igual que `dequeue`, puede volcar la pila de entrada en la de salida.
+    pub fn peek(&mut self) -> Result<&T, Exceptions> {
+        self.refill();
+        self.outbox
+            .peek()
+            .map_err(|_| Exceptions::NoSuchElement(String::from("The queue is empty")))
+    }
+
+    /// Devuelve la cantidad de elementos en la cola.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.inbox.len() + self.outbox.len()
+    }
+
+    /// Verifica si la cola está vacía.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Devuelve la cantidad total de elementos trasladados de la pila de entrada a la de salida.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use stack::TwoStackQueue;
+    /// let mut queue: TwoStackQueue<i32> = TwoStackQueue::new();
+    /// for value in 0..4 {
+    ///     queue.enqueue(value);
+    /// }
+    /// queue.dequeue().unwrap();
+    /// queue.dequeue().unwrap();
+    ///
+    /// // El primer `dequeue` trasladó los cuatro elementos; el segundo no trasladó ninguno.
+    /// assert_eq!(queue.transfers(), 4);
+    /// ```
+    #[must_use]
+    pub const fn transfers(&self) -> usize {
+        self.transfers
+    }
+
+    fn refill(&mut self) {
+        if self.outbox.is_empty() {
+            while let Ok(value) = self.inbox.pop() {
+                self.outbox.push(value);
+                self.transfers += 1;
+            }
+        }
+    }
+}
+
+impl<T: Clone> Default for TwoStackQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone + Debug> Debug for TwoStackQueue<T> {
+    /// Muestra ambas pilas desde su base hasta su tope.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "entrada: {:?}, salida: {:?}", self.inbox, self.outbox)
+    }
+}
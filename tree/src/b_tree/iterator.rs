@@ -0,0 +1,45 @@
+use super::node::Node;
+use std::ops::Bound;
+
+pub struct BTreeIterator<'a, K: Clone + Ord, V: Clone> {
+    // Cada marco guarda un nodo y la posición de la próxima clave que falta visitar en él.
+    stack: Vec<(&'a Node<K, V>, usize)>,
+    end: Bound<K>,
+}
+
+impl<'a, K: Clone + Ord, V: Clone> BTreeIterator<'a, K, V> {
+    pub const fn new(stack: Vec<(&'a Node<K, V>, usize)>, end: Bound<K>) -> Self {
+        Self { stack, end }
+    }
+}
+
+impl<'a, K: Clone + Ord, V: Clone> Iterator for BTreeIterator<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node, index) = self.stack.last_mut()?;
+            let (node, current_index) = (*node, *index);
+            let Some((key, value)) = node.entries().get(current_index) else {
+                self.stack.pop();
+                continue;
+            };
+            *index += 1;
+            let in_range = match &self.end {
+                Bound::Included(end) => key <= end,
+                Bound::Excluded(end) => key < end,
+                Bound::Unbounded => true,
+            };
+            if !in_range {
+                self.stack.clear();
+                return None;
+            }
+            let mut current = node.child(current_index + 1);
+            while let Some(child) = current {
+                self.stack.push((child, 0));
+                current = child.child(0);
+            }
+            return Some((key, value));
+        }
+    }
+}
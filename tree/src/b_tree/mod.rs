@@ -0,0 +1,380 @@
+mod iterator;
+mod node;
+
+use exceptions::Exceptions;
+pub use iterator::BTreeIterator;
+use node::Node;
+use std::borrow::Borrow;
+use std::fmt::{Debug, Formatter, Result as fmtResult, Write};
+use std::ops::{Bound, RangeBounds};
+
+#[derive(Clone)]
+pub struct BTree<K: Clone + Ord, V: Clone> {
+    // Cada nodo, salvo la raíz, guarda entre `min_degree - 1` y `2 * min_degree - 1` claves.
+    root: Node<K, V>,
+    min_degree: usize,
+    len: usize,
+}
+
+impl<K: Clone + Ord, V: Clone> BTree<K, V> {
+    /// Crea un nuevo árbol B vacío con el grado mínimo especificado.
+    ///
+    /// # Parámetros
+    /// - `min_degree`: El grado mínimo `t`. Cada nodo distinto de la raíz guarda entre `t - 1` y `2t - 1` claves, y un nodo
+    ///   interno tiene una clave más que hijos menos uno.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use tree::BTree;
+    /// let tree: BTree<u32, &str> = BTree::new(3);
+    ///
+    /// assert!(tree.is_empty());
+    /// assert_eq!(tree.min_degree(), 3);
+    /// assert_eq!(tree.max_keys(), 5);
+    /// ```
+    ///
+    /// # Panics
+    /// Entra en pánico si `min_degree` es menor que `2`.
+    #[must_use]
+    pub fn new(min_degree: usize) -> Self {
+        assert!(
+            min_degree >= 2,
+            "a B-tree needs a minimum degree of at least 2"
+        );
+        Self {
+            root: Node::new(),
+            min_degree,
+            len: 0,
+        }
+    }
+
+    /// Obtiene una referencia al valor asociado a la clave especificada.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use tree::BTree;
+    /// # use exceptions::Exceptions;
+    /// let mut tree: BTree<String, u32> = BTree::new(2);
+    /// tree.insert("uno".to_string(), 1).unwrap();
+    ///
+    /// assert_eq!(tree.get("uno"), Ok(&1));
+    /// assert_eq!(tree.get("dos"), Err(Exceptions::KeyNotInitialized));
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::KeyNotInitialized` si el árbol no contiene la clave.
+    ///
+    /// # Notas
+    /// - Visita `O(log_t n)` nodos y hace una búsqueda binaria en cada uno.
+    pub fn get<Q: ?Sized + Ord>(&self, key: &Q) -> Result<&V, Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        self.root.find(key).ok_or(Exceptions::KeyNotInitialized)
+    }
+
+    /// Obtiene una referencia mutable al valor asociado a la clave especificada.
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::KeyNotInitialized` si el árbol no contiene la clave.
+    pub fn get_mut<Q: ?Sized + Ord>(&mut self, key: &Q) -> Result<&mut V, Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        self.root.find_mut(key).ok_or(Exceptions::KeyNotInitialized)
+    }
+
+    /// Verifica si el árbol contiene la clave especificada.
+    pub fn contains_key<Q: ?Sized + Ord>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.root.find(key).is_some()
+    }
+
+    /// Inserta un nuevo par clave-valor.
+    ///
+    /// # Comportamiento
+    /// - Si la raíz está llena, se divide y el árbol crece un nivel: es la única forma en que aumenta su altura.
+    /// - Al descender, cada hijo lleno se divide antes de entrar en él, por lo que la inserción nunca necesita volver hacia arriba.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use tree::BTree;
+    /// # use exceptions::Exceptions;
+    /// let mut tree = BTree::new(2);
+    /// for key in [10, 20, 30] {
+    ///     tree.insert(key, key * 2).unwrap();
+    /// }
+    ///
+    /// // La raíz de un árbol con t = 2 admite tres claves: la cuarta provoca la primera división.
+    /// assert_eq!(tree.height(), 1);
+    /// tree.insert(40, 80).unwrap();
+    /// assert_eq!(tree.height(), 2);
+    /// assert_eq!(tree.insert(10, 0), Err(Exceptions::DuplicateKey));
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::DuplicateKey` si el árbol ya contiene la clave.
+    pub fn insert(&mut self, key: K, value: V) -> Result<(), Exceptions> {
+        if self.contains_key(&key) {
+            return Err(Exceptions::DuplicateKey);
+        }
+        if self.root.is_full(self.min_degree) {
+            self.root.grow(self.min_degree);
+        }
+        self.root.insert_non_full(key, value, self.min_degree);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Inserta un par clave-valor, sobrescribiendo el valor anterior si la clave ya existía.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use tree::BTree;
+    /// let mut tree = BTree::new(2);
+    ///
+    /// assert_eq!(tree.put(1, "uno"), None);
+    /// assert_eq!(tree.put(1, "one"), Some("uno"));
+    /// assert_eq!(tree.len(), 1);
+    /// ```
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        if let Ok(current) = self.get_mut(&key) {
+            return Some(std::mem::replace(current, value));
+        }
+        let _ = self.insert(key, value);
+        None
+    }
+
+    /// Elimina la clave especificada y devuelve el valor que tenía asociado.
+    ///
+    /// # Comportamiento
+    /// - Antes de descender a un hijo con el mínimo de claves, se le presta una clave desde un hermano o se fusiona con él.
+    /// - Una clave de un nodo interno se reemplaza por su predecesor o sucesor, que siempre está en una hoja.
+    /// - Si la raíz se queda sin claves, su único hijo pasa a ser la nueva raíz y el árbol pierde un nivel.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use tree::BTree;
+    /// # use exceptions::Exceptions;
+    /// let mut tree: BTree<u32, u32> = (0..20).map(|key| (key, key * key)).collect();
+    ///
+    /// assert_eq!(tree.remove(&7), Ok(49));
+    /// assert_eq!(tree.remove(&7), Err(Exceptions::KeyNotInitialized));
+    /// assert_eq!(tree.len(), 19);
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::KeyNotInitialized` si el árbol no contiene la clave.
+    pub fn remove<Q: ?Sized + Ord>(&mut self, key: &Q) -> Result<V, Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        let removed = self.root.remove(key, self.min_degree);
+        self.root.shrink();
+        let (_, value) = removed.ok_or(Exceptions::KeyNotInitialized)?;
+        self.len -= 1;
+        Ok(value)
+    }
+
+    /// Devuelve la cantidad de pares almacenados en el árbol.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Verifica si el árbol está vacío.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Devuelve el grado mínimo `t` del árbol.
+    #[must_use]
+    pub const fn min_degree(&self) -> usize {
+        self.min_degree
+    }
+
+    /// Devuelve la cantidad máxima de claves por nodo (`2t - 1`).
+    #[must_use]
+    pub const fn max_keys(&self) -> usize {
+        2 * self.min_degree - 1
+    }
+
+    /// Devuelve la cantidad de niveles del árbol; todas las hojas están a la misma profundidad.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use tree::BTree;
+    /// let binary_like: BTree<u32, ()> = (0..1000).map(|key| (key, ())).collect();
+    /// let mut wide: BTree<u32, ()> = BTree::new(16);
+    /// for key in 0..1000 {
+    ///     wide.insert(key, ()).unwrap();
+    /// }
+    ///
+    /// // Un grado mínimo mayor reduce la altura, y con ella los nodos (bloques de disco) visitados por búsqueda.
+    /// assert!(wide.height() < binary_like.height());
+    /// ```
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.root.height()
+    }
+
+    /// Crea un iterador que recorre todos los pares en orden ascendente de clave.
+    #[must_use]
+    pub fn iter(&self) -> BTreeIterator<'_, K, V> {
+        self.range(..)
+    }
+
+    /// Crea un iterador que recorre en orden ascendente los pares cuya clave está contenida en el rango especificado.
+    ///
+    /// # Comportamiento
+    /// - Desciende desde la raíz apilando, en cada nodo, la posición de la primera clave que cumple el límite inferior.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use tree::BTree;
+    /// let tree: BTree<u32, u32> = (1..=21).step_by(2).map(|key| (key, key * 10)).collect();
+    /// let keys = |range: Vec<(&u32, &u32)>| range.into_iter().map(|(key, _)| *key).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(keys(tree.range(3..9).collect()), vec![3, 5, 7]);
+    /// assert_eq!(keys(tree.range(4..=9).collect()), vec![5, 7, 9]);
+    /// assert_eq!(keys(tree.range(18..).collect()), vec![19, 21]);
+    /// assert_eq!(keys(tree.range(..2).collect()), vec![1]);
+    /// ```
+    ///
+    /// # Notas
+    /// - Recorrer un rango con `k` pares tiene un costo de `O(log n + k)`.
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> BTreeIterator<'_, K, V> {
+        let mut stack = Vec::new();
+        let mut current = Some(&self.root);
+        while let Some(node) = current {
+            let index = node
+                .entries()
+                .partition_point(|(key, _)| match range.start_bound() {
+                    Bound::Included(start) => key < start,
+                    Bound::Excluded(start) => key <= start,
+                    Bound::Unbounded => false,
+                });
+            stack.push((node, index));
+            current = node.child(index);
+        }
+        BTreeIterator::new(stack, range.end_bound().cloned())
+    }
+}
+
+impl<K: Clone + Ord + Debug, V: Clone> BTree<K, V> {
+    /// Dibuja el árbol nivel por nivel, con las claves de cada nodo y su ocupación respecto de `2t - 1`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use tree::BTree;
+    /// let mut tree = BTree::new(2);
+    /// for key in 1..=6 {
+    ///     tree.insert(key, ()).unwrap();
+    /// }
+    ///
+    /// assert_eq!(tree.pretty(), "nivel 0: [2, 4] (2/3)\nnivel 1: [1] (1/3) [3] (1/3) [5, 6] (2/3)\n");
+    /// ```
+    #[must_use]
+    pub fn pretty(&self) -> String {
+        let mut output = String::new();
+        let mut level: Vec<&Node<K, V>> = vec![&self.root];
+        let mut depth = 0;
+        while !level.is_empty() && !level[0].entries().is_empty() {
+            let _ = write!(output, "nivel {depth}:");
+            for node in &level {
+                let keys: Vec<&K> = node.entries().iter().map(|(key, _)| key).collect();
+                let _ = write!(output, " {keys:?} ({}/{})", keys.len(), self.max_keys());
+            }
+            output.push('\n');
+            level = level.iter().flat_map(|node| node.children()).collect();
+            depth += 1;
+        }
+        output
+    }
+}
+
+impl<K: Clone + Ord, V: Clone> Default for BTree<K, V> {
+    /// Crea un árbol vacío con grado mínimo `2`, es decir, un árbol 2-3-4.
+    fn default() -> Self {
+        Self::new(2)
+    }
+}
+
+impl<K: Clone + Ord, V: Clone> FromIterator<(K, V)> for BTree<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut tree = Self::default();
+        for (key, value) in iter {
+            tree.put(key, value);
+        }
+        tree
+    }
+}
+
+impl<'a, K: Clone + Ord, V: Clone> IntoIterator for &'a BTree<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = BTreeIterator<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: Clone + Ord + Debug, V: Clone + Debug> Debug for BTree<K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "{{")?;
+        for (index, (key, value)) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{key:?}: {value:?}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BTree;
+    use std::collections::BTreeMap;
+
+    fn matches_the_standard_map(min_degree: usize) {
+        let mut tree: BTree<u32, u32> = BTree::new(min_degree);
+        let mut model: BTreeMap<u32, u32> = BTreeMap::new();
+        let mut seed: u32 = 0x9e37_79b9;
+        for step in 0..4000 {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            let key = seed % 500;
+            if step % 3 == 0 {
+                assert_eq!(tree.remove(&key).ok(), model.remove(&key));
+            } else {
+                assert_eq!(tree.put(key, step), model.insert(key, step));
+            }
+            tree.root.check(min_degree, true);
+            assert_eq!(tree.len(), model.len());
+        }
+        assert!(tree.iter().eq(model.iter()));
+        assert!(tree.range(100..=300).eq(model.range(100..=300)));
+        for key in model.keys() {
+            assert!(tree.remove(key).is_ok());
+            tree.root.check(min_degree, true);
+        }
+        assert!(tree.is_empty());
+        assert_eq!(tree.height(), 0);
+    }
+
+    #[test]
+    fn random_operations_keep_the_invariants() {
+        for min_degree in [2, 3, 5, 8] {
+            matches_the_standard_map(min_degree);
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use std::borrow::Borrow;
+use std::mem;
+
+#[derive(Debug, Clone)]
+pub struct Node<K: Clone + Ord, V: Clone> {
+    // Las claves del hijo `i` son menores que `entries[i]` y mayores que `entries[i - 1]`.
+    entries: Vec<(K, V)>,
+    children: Vec<Node<K, V>>,
+}
+
+impl<K: Clone + Ord, V: Clone> Node<K, V> {
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    pub fn entries(&self) -> &[(K, V)] {
+        &self.entries
+    }
+
+    pub fn children(&self) -> &[Self] {
+        &self.children
+    }
+
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    pub fn is_full(&self, min_degree: usize) -> bool {
+        self.entries.len() == 2 * min_degree - 1
+    }
+
+    pub fn child(&self, index: usize) -> Option<&Self> {
+        self.children.get(index)
+    }
+
+    pub fn find<Q: ?Sized + Ord>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        let mut node = self;
+        loop {
+            match node.search(key) {
+                Ok(index) => return Some(&node.entries[index].1),
+                Err(index) => node = node.children.get(index)?,
+            }
+        }
+    }
+
+    pub fn find_mut<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        let mut node = self;
+        loop {
+            match node.search(key) {
+                Ok(index) => return Some(&mut node.entries[index].1),
+                Err(index) => node = node.children.get_mut(index)?,
+            }
+        }
+    }
+
+    // Convierte a la raíz llena en el único hijo de una nueva raíz y la divide.
+    pub fn grow(&mut self, min_degree: usize) {
+        let old_root = mem::replace(self, Self::new());
+        self.children.push(old_root);
+        self.split_child(0, min_degree);
+    }
+
+    // Inserta una clave que no existe en el subárbol, dividiendo de antemano cada hijo lleno del camino.
+    pub fn insert_non_full(&mut self, key: K, value: V, min_degree: usize) {
+        let mut node = self;
+        loop {
+            let Err(mut index) = node.search(&key) else {
+                unreachable!("the key is not in the tree");
+            };
+            if node.is_leaf() {
+                node.entries.insert(index, (key, value));
+                return;
+            }
+            if node.children[index].is_full(min_degree) {
+                node.split_child(index, min_degree);
+                if key > node.entries[index].0 {
+                    index += 1;
+                }
+            }
+            node = &mut node.children[index];
+        }
+    }
+
+    // Elimina la clave del subárbol, garantizando antes de descender que el hijo visitado tenga al menos `min_degree` claves.
+    pub fn remove<Q: ?Sized + Ord>(&mut self, key: &Q, min_degree: usize) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        match self.search(key) {
+            Ok(index) if self.is_leaf() => Some(self.entries.remove(index)),
+            Ok(index) => {
+                if self.children[index].entries.len() >= min_degree {
+                    let predecessor = self.children[index].max_key();
+                    let entry = self.children[index].remove::<K>(&predecessor, min_degree)?;
+                    Some(mem::replace(&mut self.entries[index], entry))
+                } else if self.children[index + 1].entries.len() >= min_degree {
+                    let successor = self.children[index + 1].min_key();
+                    let entry = self.children[index + 1].remove::<K>(&successor, min_degree)?;
+                    Some(mem::replace(&mut self.entries[index], entry))
+                } else {
+                    self.merge_children(index);
+                    self.children[index].remove(key, min_degree)
+                }
+            }
+            Err(_) if self.is_leaf() => None,
+            Err(index) => {
+                let index = self.fill_child(index, min_degree);
+                self.children[index].remove(key, min_degree)
+            }
+        }
+    }
+
+    // Reemplaza una raíz sin claves por su único hijo, si lo tiene.
+    pub fn shrink(&mut self) {
+        if self.entries.is_empty() {
+            if let Some(child) = self.children.pop() {
+                *self = child;
+            }
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        if self.entries.is_empty() {
+            return 0;
+        }
+        let mut height = 1;
+        let mut node = self;
+        while let Some(child) = node.children.first() {
+            height += 1;
+            node = child;
+        }
+        height
+    }
+
+    fn search<Q: ?Sized + Ord>(&self, key: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>,
+    {
+        self.entries
+            .binary_search_by(|(current, _)| current.borrow().cmp(key))
+    }
+
+    fn max_key(&self) -> K {
+        let mut node = self;
+        while let Some(child) = node.children.last() {
+            node = child;
+        }
+        node.entries[node.entries.len() - 1].0.clone()
+    }
+
+    fn min_key(&self) -> K {
+        let mut node = self;
+        while let Some(child) = node.children.first() {
+            node = child;
+        }
+        node.entries[0].0.clone()
+    }
+
+    // Divide el hijo lleno `index` en dos nodos de `min_degree - 1` claves y sube la mediana a este nodo.
+    fn split_child(&mut self, index: usize, min_degree: usize) {
+        let child = &mut self.children[index];
+        let right = Self {
+            entries: child.entries.split_off(min_degree),
+            children: if child.is_leaf() {
+                Vec::new()
+            } else {
+                child.children.split_off(min_degree)
+            },
+        };
+        let median = child.entries.pop().expect("a full node has a median");
+        self.entries.insert(index, median);
+        self.children.insert(index + 1, right);
+    }
+
+    // Une el hijo `index + 1` y la clave que los separa al hijo `index`.
+    fn merge_children(&mut self, index: usize) {
+        let right = self.children.remove(index + 1);
+        let separator = self.entries.remove(index);
+        let child = &mut self.children[index];
+        child.entries.push(separator);
+        child.entries.extend(right.entries);
+        child.children.extend(right.children);
+    }
+
+    // Asegura que el hijo `index` tenga al menos `min_degree` claves, pidiéndolas a un hermano o fusionándolo con él.
+    // Devuelve la posición final del hijo, que cambia si se fusiona con su hermano izquierdo.
+    fn fill_child(&mut self, index: usize, min_degree: usize) -> usize {
+        if self.children[index].entries.len() >= min_degree {
+            return index;
+        }
+        if index > 0 && self.children[index - 1].entries.len() >= min_degree {
+            let (left, right) = self.children.split_at_mut(index);
+            let (left, child) = (&mut left[index - 1], &mut right[0]);
+            let borrowed = left.entries.pop().expect("the sibling has spare keys");
+            let separator = mem::replace(&mut self.entries[index - 1], borrowed);
+            child.entries.insert(0, separator);
+            if let Some(grandchild) = left.children.pop() {
+                child.children.insert(0, grandchild);
+            }
+            index
+        } else if index + 1 < self.children.len()
+            && self.children[index + 1].entries.len() >= min_degree
+        {
+            let (left, right) = self.children.split_at_mut(index + 1);
+            let (child, right) = (&mut left[index], &mut right[0]);
+            let borrowed = right.entries.remove(0);
+            let separator = mem::replace(&mut self.entries[index], borrowed);
+            child.entries.push(separator);
+            if !right.is_leaf() {
+                child.children.push(right.children.remove(0));
+            }
+            index
+        } else if index + 1 < self.children.len() {
+            self.merge_children(index);
+            index
+        } else {
+            self.merge_children(index - 1);
+            index - 1
+        }
+    }
+
+    #[cfg(test)]
+    pub fn check(&self, min_degree: usize, is_root: bool) -> usize {
+        let len = self.entries.len();
+        assert!(len < 2 * min_degree);
+        assert!(is_root || len >= min_degree - 1);
+        assert!(self.entries.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        if self.is_leaf() {
+            return 1;
+        }
+        assert_eq!(self.children.len(), len + 1);
+        for (index, child) in self.children.iter().enumerate() {
+            if index > 0 {
+                assert!(child.entries[0].0 > self.entries[index - 1].0);
+            }
+            if index < len {
+                assert!(child.entries[child.entries.len() - 1].0 < self.entries[index].0);
+            }
+        }
+        let depths: Vec<usize> = self
+            .children
+            .iter()
+            .map(|child| child.check(min_degree, false))
+            .collect();
+        assert!(depths.windows(2).all(|pair| pair[0] == pair[1]));
+        depths[0] + 1
+    }
+}
@@ -0,0 +1,73 @@
+pub mod b_tree;
+pub mod tree_map;
+
+pub use b_tree::{BTree, BTreeIterator};
+use exceptions::Exceptions;
+pub use tree_map::{TreeMap, TreeMapIterator};
+
+pub fn tm_implementation() -> Result<(), Exceptions> {
+    println!("Mapa ordenado (árbol AVL)");
+    let mut map: TreeMap<String, u32> = TreeMap::new();
+    println!(
+        "  1. Inicialización (está vacío: {0}):\n    {map:?}",
+        map.is_empty()
+    );
+    for (planet, moons) in [
+        ("Tierra", 1),
+        ("Marte", 2),
+        ("Jupiter", 95),
+        ("Saturno", 146),
+        ("Urano", 28),
+        ("Neptuno", 16),
+        ("Mercurio", 0),
+        ("Venus", 0),
+    ] {
+        map.insert(planet.to_string(), moons)?;
+    }
+    println!(
+        "  2. Insertar (altura: {0}, elementos: {1}):\n    {map:?}",
+        map.height(),
+        map.len()
+    );
+    let moons = map.get("Jupiter")?;
+    println!("  3. Buscar (clave: \"Jupiter\", lunas: {moons})");
+    let range: Vec<(&String, &u32)> = map.range(String::from("M")..String::from("T")).collect();
+    println!("  4. Rango (\"M\"..\"T\"):\n    {range:?}");
+    let moons = map.remove("Saturno")?;
+    println!(
+        "  5. Eliminar (clave: \"Saturno\", lunas: {moons}, altura: {0}):\n    {map:?}",
+        map.height()
+    );
+    Ok(())
+}
+
+pub fn bt_implementation() -> Result<(), Exceptions> {
+    println!("Árbol B");
+    let mut tree: BTree<u32, String> = BTree::new(2);
+    for key in [10, 20, 5, 6, 12, 30, 7, 17, 3, 4, 25, 40, 1] {
+        tree.insert(key, format!("bloque {key}"))?;
+    }
+    let (height, len) = (tree.height(), tree.len());
+    println!("  1. Insertar (t = 2, altura: {height}, claves: {len}):");
+    print!("{}", indent(&tree.pretty()));
+    let removed = tree.remove(&6)?;
+    let height = tree.height();
+    println!("  2. Eliminar 6 ({removed:?}, altura: {height}):");
+    print!("{}", indent(&tree.pretty()));
+    let range: Vec<&u32> = tree.range(5..=20).map(|(key, _)| key).collect();
+    println!("  3. Rango 5..=20:\n    {range:?}");
+    println!("  4. Altura según el grado mínimo (10000 claves):");
+    for min_degree in [2, 4, 16, 64] {
+        let mut tree: BTree<u32, ()> = BTree::new(min_degree);
+        for key in 0..10_000 {
+            tree.insert(key, ())?;
+        }
+        let height = tree.height();
+        println!("    t = {min_degree:>2}: altura {height}");
+    }
+    Ok(())
+}
+
+fn indent(text: &str) -> String {
+    text.lines().map(|line| format!("    {line}\n")).collect()
+}
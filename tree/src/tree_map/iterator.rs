@@ -0,0 +1,37 @@
+use super::node::Node;
+use std::ops::Bound;
+
+pub struct TreeMapIterator<'a, K: Clone + Ord, V: Clone> {
+    stack: Vec<&'a Node<K, V>>,
+    end: Bound<K>,
+}
+
+impl<'a, K: Clone + Ord, V: Clone> TreeMapIterator<'a, K, V> {
+    pub const fn new(stack: Vec<&'a Node<K, V>>, end: Bound<K>) -> Self {
+        Self { stack, end }
+    }
+}
+
+impl<'a, K: Clone + Ord, V: Clone> Iterator for TreeMapIterator<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        let key = node.get_key();
+        let in_range = match &self.end {
+            Bound::Included(end) => key <= end,
+            Bound::Excluded(end) => key < end,
+            Bound::Unbounded => true,
+        };
+        if !in_range {
+            self.stack.clear();
+            return None;
+        }
+        let mut current = node.right();
+        while let Some(child) = current {
+            self.stack.push(child);
+            current = child.left();
+        }
+        Some(node.get_entry())
+    }
+}
@@ -0,0 +1,418 @@
+mod iterator;
+mod node;
+
+use exceptions::Exceptions;
+pub use iterator::TreeMapIterator;
+use node::{Link, Node};
+use std::borrow::Borrow;
+use std::fmt::{Debug, Formatter, Result as fmtResult};
+use std::ops::{Bound, RangeBounds};
+
+#[derive(Clone)]
+pub struct TreeMap<K: Clone + Ord, V: Clone> {
+    root: Link<K, V>,
+    len: usize,
+}
+
+impl<K: Clone + Ord, V: Clone> TreeMap<K, V> {
+    /// Crea un nuevo mapa ordenado vacío.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use tree::TreeMap;
+    /// let map: TreeMap<u32, &str> = TreeMap::new();
+    ///
+    /// assert!(map.is_empty());
+    /// assert_eq!(map.height(), 0);
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+
+    /// Obtiene una referencia al valor asociado a la clave especificada.
+    ///
+    /// # Parámetros
+    /// - `key`: Una referencia a la clave que se desea buscar, o a cualquier forma prestada de ella (por ejemplo `&str` para claves `String`).
+    ///
+    /// # Retornos
+    /// - `Ok(&V)`: Una referencia al valor asociado a la clave.
+    /// - `Err(Exceptions::KeyNotInitialized)`: Si el mapa no contiene la clave.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use tree::TreeMap;
+    /// # use exceptions::Exceptions;
+    /// let map: TreeMap<String, u32> = [("uno".to_string(), 1), ("dos".to_string(), 2)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// assert_eq!(map.get("dos"), Ok(&2));
+    /// assert_eq!(map.get("tres"), Err(Exceptions::KeyNotInitialized));
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::KeyNotInitialized` si el mapa no contiene la clave.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo logarítmico (`O(log n)`).
+    pub fn get<Q: ?Sized + Ord>(&self, key: &Q) -> Result<&V, Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        Node::find(self.root.as_deref(), key).ok_or(Exceptions::KeyNotInitialized)
+    }
+
+    /// Obtiene una referencia mutable al valor asociado a la clave especificada.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use tree::TreeMap;
+    /// let mut map = TreeMap::new();
+    /// map.insert(1, 10).unwrap();
+    ///
+    /// *map.get_mut(&1).unwrap() += 5;
+    /// assert_eq!(map.get(&1), Ok(&15));
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::KeyNotInitialized` si el mapa no contiene la clave.
+    pub fn get_mut<Q: ?Sized + Ord>(&mut self, key: &Q) -> Result<&mut V, Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        Node::find_mut(&mut self.root, key).ok_or(Exceptions::KeyNotInitialized)
+    }
+
+    /// Verifica si el mapa contiene la clave especificada.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use tree::TreeMap;
+    /// let map: TreeMap<u32, u32> = (0..5).map(|key| (key, key)).collect();
+    ///
+    /// assert!(map.contains_key(&3));
+    /// assert!(!map.contains_key(&7));
+    /// ```
+    pub fn contains_key<Q: ?Sized + Ord>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.get(key).is_ok()
+    }
+
+    /// Inserta un nuevo par clave-valor en su posición ordenada.
+    ///
+    /// # Parámetros
+    /// - `key`: La clave del nuevo par.
+    /// - `value`: El valor asociado a la clave.
+    ///
+    /// # Retornos
+    /// - `Ok(())`: Si la clave no existía y se insertó correctamente.
+    /// - `Err(Exceptions::DuplicateKey)`: Si el mapa ya contiene la clave.
+    ///
+    /// # Comportamiento
+    /// - Desciende como en un árbol binario de búsqueda y, al volver, rebalancea cada ancestro con rotaciones AVL para que las alturas de sus subárboles no difieran en más de uno.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use tree::TreeMap;
+    /// # use exceptions::Exceptions;
+    /// let mut map = TreeMap::new();
+    ///
+    /// assert_eq!(map.insert(2, "dos"), Ok(()));
+    /// assert_eq!(map.insert(1, "uno"), Ok(()));
+    /// assert_eq!(map.insert(2, "two"), Err(Exceptions::DuplicateKey));
+    /// assert_eq!(map.get(&2), Ok(&"dos"));
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::DuplicateKey` si el mapa ya contiene la clave.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo logarítmico (`O(log n)`).
+    pub fn insert(&mut self, key: K, value: V) -> Result<(), Exceptions> {
+        if Node::find(self.root.as_deref(), &key).is_some() {
+            return Err(Exceptions::DuplicateKey);
+        }
+        self.put(key, value);
+        Ok(())
+    }
+
+    /// Inserta un par clave-valor, sobrescribiendo el valor anterior si la clave ya existía.
+    ///
+    /// # Retornos
+    /// - `Some(V)`: El valor reemplazado, si la clave ya existía.
+    /// - `None`: Si la clave no existía.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use tree::TreeMap;
+    /// let mut map = TreeMap::new();
+    ///
+    /// assert_eq!(map.put(1, "uno"), None);
+    /// assert_eq!(map.put(1, "one"), Some("uno"));
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        let (root, replaced) = Node::put(self.root.take(), key, value);
+        self.root = Some(root);
+        if replaced.is_none() {
+            self.len += 1;
+        }
+        replaced
+    }
+
+    /// Elimina la clave especificada y devuelve el valor que tenía asociado.
+    ///
+    /// # Comportamiento
+    /// - Si el nodo tiene dos hijos, su sucesor en orden (el mínimo del subárbol derecho) ocupa su lugar.
+    /// - Cada ancestro del nodo eliminado se rebalancea al volver de la recursión.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use tree::TreeMap;
+    /// # use exceptions::Exceptions;
+    /// let mut map: TreeMap<u32, u32> = (0..10).map(|key| (key, key * key)).collect();
+    ///
+    /// assert_eq!(map.remove(&3), Ok(9));
+    /// assert_eq!(map.remove(&3), Err(Exceptions::KeyNotInitialized));
+    /// assert_eq!(map.len(), 9);
+    /// ```
+    ///
+    /// # Errors
+    /// Este método retornará:
+    /// - `Exceptions::KeyNotInitialized` si el mapa no contiene la clave.
+    ///
+    /// # Notas
+    /// - Este método tiene un costo logarítmico (`O(log n)`).
+    pub fn remove<Q: ?Sized + Ord>(&mut self, key: &Q) -> Result<V, Exceptions>
+    where
+        K: Borrow<Q>,
+    {
+        let (root, removed) = Node::remove(self.root.take(), key);
+        self.root = root;
+        let (_, value) = removed.ok_or(Exceptions::KeyNotInitialized)?;
+        self.len -= 1;
+        Ok(value)
+    }
+
+    /// Devuelve el par con la clave más pequeña, si existe.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use tree::TreeMap;
+    /// let map: TreeMap<u32, char> = [(3, 'c'), (1, 'a'), (2, 'b')].into_iter().collect();
+    ///
+    /// assert_eq!(map.first(), Some((&1, &'a')));
+    /// assert_eq!(map.last(), Some((&3, &'c')));
+    /// ```
+    #[must_use]
+    pub fn first(&self) -> Option<(&K, &V)> {
+        let mut node = self.root.as_deref()?;
+        while let Some(left) = node.left() {
+            node = left;
+        }
+        Some(node.get_entry())
+    }
+
+    /// Devuelve el par con la clave más grande, si existe.
+    #[must_use]
+    pub fn last(&self) -> Option<(&K, &V)> {
+        let mut node = self.root.as_deref()?;
+        while let Some(right) = node.right() {
+            node = right;
+        }
+        Some(node.get_entry())
+    }
+
+    /// Devuelve la cantidad de pares almacenados en el mapa.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Verifica si el mapa está vacío.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Devuelve la altura del árbol, es decir, la cantidad de nodos del camino más largo desde la raíz.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use tree::TreeMap;
+    /// let map: TreeMap<u32, u32> = (0..1000).map(|key| (key, key)).collect();
+    ///
+    /// // Un árbol AVL nunca supera 1.44 * log2(n + 2) niveles.
+    /// assert!(map.height() <= 14);
+    /// ```
+    #[must_use]
+    pub fn height(&self) -> usize {
+        Node::height(&self.root)
+    }
+
+    /// Crea un iterador que recorre todos los pares en orden ascendente de clave.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use tree::TreeMap;
+    /// let map: TreeMap<u32, char> = [(2, 'b'), (3, 'c'), (1, 'a')].into_iter().collect();
+    ///
+    /// assert_eq!(map.iter().collect::<Vec<_>>(), vec![(&1, &'a'), (&2, &'b'), (&3, &'c')]);
+    /// ```
+    #[must_use]
+    pub fn iter(&self) -> TreeMapIterator<'_, K, V> {
+        self.range(..)
+    }
+
+    /// Crea un iterador que recorre en orden ascendente los pares cuya clave está contenida en el rango especificado.
+    ///
+    /// # Parámetros
+    /// - `range`: Cualquier rango de claves (`a..b`, `a..=b`, `a..`, `..b`, `..`).
+    ///
+    /// # Comportamiento
+    /// - Desciende desde la raíz apilando los nodos que cumplen el límite inferior, de modo que el primer par del rango queda en la cima de la pila.
+    /// - Avanza en orden hasta encontrar una clave fuera del límite superior.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # use tree::TreeMap;
+    /// let map: TreeMap<u32, u32> = (1..=11).step_by(2).map(|key| (key, key * 10)).collect();
+    /// let keys = |range: Vec<(&u32, &u32)>| range.into_iter().map(|(key, _)| *key).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(keys(map.range(3..9).collect()), vec![3, 5, 7]);
+    /// assert_eq!(keys(map.range(4..=9).collect()), vec![5, 7, 9]);
+    /// assert_eq!(keys(map.range(10..).collect()), vec![11]);
+    /// assert_eq!(keys(map.range(..2).collect()), vec![1]);
+    /// ```
+    ///
+    /// # Notas
+    /// - Recorrer un rango con `k` pares tiene un costo de `O(log n + k)`.
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> TreeMapIterator<'_, K, V> {
+        let mut stack: Vec<&Node<K, V>> = Vec::new();
+        let mut current = self.root.as_deref();
+        while let Some(node) = current {
+            let after_start = match range.start_bound() {
+                Bound::Included(start) => node.get_key() >= start,
+                Bound::Excluded(start) => node.get_key() > start,
+                Bound::Unbounded => true,
+            };
+            if after_start {
+                stack.push(node);
+                current = node.left();
+            } else {
+                current = node.right();
+            }
+        }
+        TreeMapIterator::new(stack, range.end_bound().cloned())
+    }
+}
+
+impl<K: Clone + Ord, V: Clone> Default for TreeMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone + Ord, V: Clone> FromIterator<(K, V)> for TreeMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (key, value) in iter {
+            map.put(key, value);
+        }
+        map
+    }
+}
+
+impl<'a, K: Clone + Ord, V: Clone> IntoIterator for &'a TreeMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = TreeMapIterator<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: Clone + Ord + Debug, V: Clone + Debug> Debug for TreeMap<K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmtResult {
+        write!(f, "{{")?;
+        for (index, (key, value)) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{key:?}: {value:?}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TreeMap;
+
+    #[test]
+    fn stays_balanced_under_sorted_insertions_and_removals() {
+        let mut map: TreeMap<u32, u32> = TreeMap::new();
+        for key in 0..1024 {
+            map.insert(key, key).unwrap();
+        }
+        assert_eq!(map.height(), 11);
+
+        for key in (0..1024).filter(|key| key % 4 != 0) {
+            assert_eq!(map.remove(&key), Ok(key));
+        }
+        assert_eq!(map.len(), 256);
+        assert!(map.height() <= 12);
+        let keys: Vec<u32> = map.iter().map(|(key, _)| *key).collect();
+        assert_eq!(keys, (0..1024).step_by(4).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn removes_nodes_with_two_children() {
+        let mut map: TreeMap<u32, &str> = [
+            (4, "d"),
+            (2, "b"),
+            (6, "f"),
+            (1, "a"),
+            (3, "c"),
+            (5, "e"),
+            (7, "g"),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(map.remove(&4), Ok("d"));
+        assert_eq!(map.remove(&2), Ok("b"));
+        assert_eq!(
+            format!("{map:?}"),
+            r#"{1: "a", 3: "c", 5: "e", 6: "f", 7: "g"}"#
+        );
+        assert_eq!(map.first(), Some((&1, &"a")));
+        assert_eq!(map.last(), Some((&7, &"g")));
+    }
+
+    #[test]
+    fn range_with_excluded_start_and_missing_bounds() {
+        let map: TreeMap<u32, u32> = (0..20).step_by(2).map(|key| (key, key)).collect();
+        let keys: Vec<u32> = map
+            .range((std::ops::Bound::Excluded(4), std::ops::Bound::Included(11)))
+            .map(|(key, _)| *key)
+            .collect();
+        assert_eq!(keys, vec![6, 8, 10]);
+        assert_eq!(map.range(100..).next(), None);
+        assert_eq!(map.range(..0).next(), None);
+    }
+
+    #[test]
+    fn empty_map() {
+        let mut map: TreeMap<String, u32> = TreeMap::default();
+        assert!(map.is_empty());
+        assert_eq!(map.first(), None);
+        assert!(map.remove("nada").is_err());
+        assert_eq!(format!("{map:?}"), "{}");
+    }
+}
@@ -0,0 +1,202 @@
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::mem;
+
+pub type Link<K, V> = Option<Box<Node<K, V>>>;
+
+#[derive(Debug, Clone)]
+pub struct Node<K: Clone + Ord, V: Clone> {
+    key: K,
+    value: V,
+    height: usize,
+    left: Link<K, V>,
+    right: Link<K, V>,
+}
+
+impl<K: Clone + Ord, V: Clone> Node<K, V> {
+    pub const fn new(key: K, value: V) -> Self {
+        Self {
+            key,
+            value,
+            height: 1,
+            left: None,
+            right: None,
+        }
+    }
+
+    pub const fn get_key(&self) -> &K {
+        &self.key
+    }
+
+    pub const fn get_entry(&self) -> (&K, &V) {
+        (&self.key, &self.value)
+    }
+
+    pub fn left(&self) -> Option<&Self> {
+        self.left.as_deref()
+    }
+
+    pub fn right(&self) -> Option<&Self> {
+        self.right.as_deref()
+    }
+
+    pub fn height(link: &Link<K, V>) -> usize {
+        link.as_ref().map_or(0, |node| node.height)
+    }
+
+    pub fn find<'a, Q: ?Sized + Ord>(mut current: Option<&'a Self>, key: &Q) -> Option<&'a V>
+    where
+        K: Borrow<Q>,
+    {
+        while let Some(node) = current {
+            current = match key.cmp(node.key.borrow()) {
+                Ordering::Less => node.left(),
+                Ordering::Greater => node.right(),
+                Ordering::Equal => return Some(&node.value),
+            };
+        }
+        None
+    }
+
+    pub fn find_mut<'a, Q: ?Sized + Ord>(link: &'a mut Link<K, V>, key: &Q) -> Option<&'a mut V>
+    where
+        K: Borrow<Q>,
+    {
+        let node = link.as_mut()?;
+        match key.cmp(node.key.borrow()) {
+            Ordering::Less => Self::find_mut(&mut node.left, key),
+            Ordering::Greater => Self::find_mut(&mut node.right, key),
+            Ordering::Equal => Some(&mut node.value),
+        }
+    }
+
+    pub fn put(link: Link<K, V>, key: K, value: V) -> (Box<Self>, Option<V>) {
+        let Some(mut node) = link else {
+            return (Box::new(Self::new(key, value)), None);
+        };
+        let replaced = match key.cmp(&node.key) {
+            Ordering::Less => {
+                let (left, replaced) = Self::put(node.left.take(), key, value);
+                node.left = Some(left);
+                replaced
+            }
+            Ordering::Greater => {
+                let (right, replaced) = Self::put(node.right.take(), key, value);
+                node.right = Some(right);
+                replaced
+            }
+            Ordering::Equal => {
+                let replaced = mem::replace(&mut node.value, value);
+                return (node, Some(replaced));
+            }
+        };
+        (Self::rebalance(node), replaced)
+    }
+
+    pub fn remove<Q: ?Sized + Ord>(link: Link<K, V>, key: &Q) -> (Link<K, V>, Option<(K, V)>)
+    where
+        K: Borrow<Q>,
+    {
+        let Some(mut node) = link else {
+            return (None, None);
+        };
+        match key.cmp(node.key.borrow()) {
+            Ordering::Less => {
+                let (left, removed) = Self::remove(node.left.take(), key);
+                node.left = left;
+                (Some(Self::rebalance(node)), removed)
+            }
+            Ordering::Greater => {
+                let (right, removed) = Self::remove(node.right.take(), key);
+                node.right = right;
+                (Some(Self::rebalance(node)), removed)
+            }
+            Ordering::Equal => {
+                let Self {
+                    key,
+                    value,
+                    left,
+                    right,
+                    ..
+                } = *node;
+                let replacement = match (left, right) {
+                    (None, right) => right,
+                    (left, None) => left,
+                    (Some(left), Some(right)) => {
+                        // El sucesor en orden ocupa el lugar del nodo eliminado.
+                        let (right, mut successor) = Self::remove_min(right);
+                        successor.left = Some(left);
+                        successor.right = right;
+                        Some(Self::rebalance(successor))
+                    }
+                };
+                (replacement, Some((key, value)))
+            }
+        }
+    }
+
+    fn remove_min(mut node: Box<Self>) -> (Link<K, V>, Box<Self>) {
+        match node.left.take() {
+            Some(left) => {
+                let (left, min) = Self::remove_min(left);
+                node.left = left;
+                (Some(Self::rebalance(node)), min)
+            }
+            None => (node.right.take(), node),
+        }
+    }
+
+    fn update_height(&mut self) {
+        self.height = 1 + Self::height(&self.left).max(Self::height(&self.right));
+    }
+
+    fn rebalance(mut node: Box<Self>) -> Box<Self> {
+        node.update_height();
+        let left = Self::height(&node.left);
+        let right = Self::height(&node.right);
+        if left > right + 1 {
+            if let Some(child) = node.left.take() {
+                // Caso izquierda-derecha: se reduce primero a izquierda-izquierda.
+                node.left = Some(if Self::height(&child.right) > Self::height(&child.left) {
+                    Self::rotate_left(child)
+                } else {
+                    child
+                });
+            }
+            Self::rotate_right(node)
+        } else if right > left + 1 {
+            if let Some(child) = node.right.take() {
+                node.right = Some(if Self::height(&child.left) > Self::height(&child.right) {
+                    Self::rotate_right(child)
+                } else {
+                    child
+                });
+            }
+            Self::rotate_left(node)
+        } else {
+            node
+        }
+    }
+
+    fn rotate_right(mut node: Box<Self>) -> Box<Self> {
+        let Some(mut pivot) = node.left.take() else {
+            return node;
+        };
+        node.left = pivot.right.take();
+        node.update_height();
+        pivot.right = Some(node);
+        pivot.update_height();
+        pivot
+    }
+
+    fn rotate_left(mut node: Box<Self>) -> Box<Self> {
+        let Some(mut pivot) = node.right.take() else {
+            return node;
+        };
+        node.right = pivot.left.take();
+        node.update_height();
+        pivot.left = Some(node);
+        pivot.update_height();
+        pivot
+    }
+}